@@ -0,0 +1,54 @@
+//! Generate foxchain chain metadata from external registries
+//!
+//! ```text
+//! foxchain-metadata cosmos-registry <chain-registry checkout> <output directory>
+//! ```
+//!
+//! The output directory can be loaded with `foxchain_id::load_chain_directory`.
+
+use foxchain_id::import_cosmos_chain_registry;
+use std::path::Path;
+use std::process::ExitCode;
+
+const USAGE: &str =
+    "Usage: foxchain-metadata cosmos-registry <chain-registry checkout> <output directory>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args.as_slice() {
+        ["cosmos-registry", checkout, output] => {
+            cosmos_registry(Path::new(checkout), Path::new(output))
+        }
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Write `<output>/<chain_name>.json` for every convertible chain of the checkout
+fn cosmos_registry(checkout: &Path, output: &Path) -> Result<(), String> {
+    let chains = import_cosmos_chain_registry(checkout).map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(output)
+        .map_err(|e| format!("Cannot create {}: {}", output.display(), e))?;
+
+    let mut written = 0;
+    for chain in chains {
+        match chain.metadata {
+            Ok(metadata) => {
+                let path = output.join(format!("{}.json", chain.name));
+                std::fs::write(&path, metadata)
+                    .map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+                written += 1;
+            }
+            Err(e) => eprintln!("Skipped {}: {}", chain.name, e),
+        }
+    }
+    println!("Wrote {} chains to {}", written, output.display());
+    Ok(())
+}
@@ -0,0 +1,267 @@
+//! Receive and change addresses of account-level extended public keys
+
+use crate::bip32::addresses::{chain_address, DerivedAddress, MAX_RANGE_SIZE};
+use crate::bip32::extended_key::{ExtendedPublicKey, HARDENED};
+use crate::registry::{ChainMetadata, Network, Registry};
+use crate::Error;
+use std::ops::Range;
+
+/// Account path template for chains without one in their metadata (BIP-44)
+const DEFAULT_ACCOUNT_PATH: &str = "m/44'/{coin_type}'/{account}'";
+/// SLIP-44 coin type shared by all testnets
+const TESTNET_COIN_TYPE: u32 = 1;
+
+/// Branch of an account (BIP-44 `change` level)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressBranch {
+    /// External chain (`0`), addresses handed out to receive funds
+    Receive,
+    /// Internal chain (`1`), addresses receiving change
+    Change,
+}
+
+impl AddressBranch {
+    /// Child index of the branch under the account key
+    pub fn index(self) -> u32 {
+        match self {
+            AddressBranch::Receive => 0,
+            AddressBranch::Change => 1,
+        }
+    }
+}
+
+/// Addresses derived from an account-level extended public key for a chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountAddresses {
+    /// Target chain id
+    pub chain: String,
+    /// Account path expected for the key on the chain (e.g. "m/84'/0'/0'")
+    pub account_path: String,
+    /// Whether the key's depth and child number match `account_path`
+    ///
+    /// When false the key was exported at another level of the tree, and
+    /// wallets for the chain will likely show different addresses.
+    pub depth_matches: bool,
+    /// Derived addresses; paths are absolute when the depth matches and
+    /// relative to the key otherwise
+    pub addresses: Vec<DerivedAddress>,
+}
+
+/// Fill a chain's account path template
+///
+/// Testnet keys use coin type 1 in place of the chain's SLIP-44 coin type.
+pub(crate) fn fill_account_path(
+    chain: &str,
+    purpose: u32,
+    network: Network,
+    account: u32,
+) -> Result<String, Error> {
+    let config = Registry::get()
+        .get_chain_config(chain)
+        .ok_or_else(|| Error::InvalidInput(format!("Unknown chain: {}", chain)))?;
+    let slip44 = config
+        .slip44
+        .ok_or_else(|| Error::InvalidInput(format!("Chain {} has no SLIP-44 coin type", chain)))?;
+    let coin_type = match network {
+        Network::Mainnet => slip44,
+        Network::Testnet => TESTNET_COIN_TYPE,
+    };
+    Ok(config
+        .derivation_path
+        .as_deref()
+        .unwrap_or(DEFAULT_ACCOUNT_PATH)
+        .replace("{purpose}", &purpose.to_string())
+        .replace("{coin_type}", &coin_type.to_string())
+        .replace("{account}", &account.to_string()))
+}
+
+/// Fill the chain's account path template for a key
+///
+/// Returns the path and whether the key sits at that path's depth with a
+/// hardened account index.
+fn account_path(key: &ExtendedPublicKey, chain: &ChainMetadata) -> Result<(String, bool), Error> {
+    let purpose = key
+        .declared_format(chain)
+        .map(|format| format.script.purpose())
+        .unwrap_or(44);
+    let hardened = key.child_number >= HARDENED;
+    let account = if hardened {
+        key.child_number - HARDENED
+    } else {
+        0
+    };
+
+    let path = fill_account_path(&chain.id, purpose, key.network, account)?;
+    let depth = path.split('/').skip(1).count();
+    Ok((path, depth == key.depth as usize && hardened))
+}
+
+/// Derive receive or change addresses from an account-level extended public key
+///
+/// Addresses follow the target chain: versions it declares (SLIP-132) use
+/// their script, any other key goes through the chain's address pipeline.
+/// The expected account path comes from the chain's SLIP-44 coin type and
+/// path template; `depth_matches` flags keys exported at another depth.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::{derive_account_addresses, AddressBranch};
+///
+/// let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+/// let account = derive_account_addresses(zpub, "bitcoin", AddressBranch::Receive, 0..2)?;
+/// assert_eq!(account.account_path, "m/84'/0'/0'");
+/// assert!(account.depth_matches);
+/// assert_eq!(account.addresses[0].path, "m/84'/0'/0'/0/0");
+/// assert_eq!(account.addresses[0].address, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn derive_account_addresses(
+    extended_key: &str,
+    chain: &str,
+    branch: AddressBranch,
+    indexes: Range<u32>,
+) -> Result<AccountAddresses, Error> {
+    let key: ExtendedPublicKey = extended_key.parse()?;
+    let chain_metadata = Registry::get()
+        .chains
+        .iter()
+        .find(|c| c.id == chain)
+        .ok_or_else(|| Error::InvalidInput(format!("Unknown chain: {}", chain)))?;
+    if indexes.is_empty() || indexes.len() > MAX_RANGE_SIZE as usize || indexes.end > HARDENED {
+        return Err(Error::InvalidInput(format!(
+            "Range must hold 1 to {} non-hardened indexes",
+            MAX_RANGE_SIZE
+        )));
+    }
+
+    let (account_path, depth_matches) = account_path(&key, chain_metadata)?;
+    let branch_key = key.derive_child(branch.index())?;
+    let addresses = indexes
+        .map(|index| {
+            let child = branch_key.derive_child(index)?;
+            let address = chain_address(&key, &child.public_key, chain_metadata, true)?
+                .ok_or_else(|| {
+                    Error::InvalidInput(format!(
+                        "{} keys ({:?}) do not derive {} addresses",
+                        key.prefix, key.network, chain
+                    ))
+                })?
+                .address;
+            let relative = format!("{}/{}", branch.index(), index);
+            Ok(DerivedAddress {
+                path: if depth_matches {
+                    format!("{}/{}", account_path, relative)
+                } else {
+                    relative
+                },
+                public_key: ::hex::encode(child.public_key),
+                address,
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok(AccountAddresses {
+        chain: chain.to_string(),
+        account_path,
+        depth_matches,
+        addresses,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Account keys of the "abandon ... about" test mnemonic
+    const BIP44_BTC: &str = "xpub6BosfCnifzxcFwrSzQiqu2DBVTshkCXacvNsWGYJVVhhawA7d4R5WSWGFNbi8Aw6ZRc1brxMyWMzG3DSSSSoekkudhUd9yLb6qx39T9nMdj";
+    const BIP44_LTC: &str = "xpub6BnJJjq783EdyBeQPA9P9ao9DTS3fUqyKG5NJDcrCiwwxEkesGoHN94LZRGE7rz1jgcvmmp8j55BNx573KFq1WBwKiemzkdfNKffKx6Mvku";
+    const BIP44_DOGE: &str = "xpub6Bxse8AT19u9HExKtP1EAudLi9CpLxPpxDvanL2fFtM7UFE2Q7TTWRg4bnMnmT4KcyN6GQkSgZmPWDtyUywSii3MDpMNfXSTuzH7gvZywLU";
+    const BIP44_ETH: &str = "xpub6DCoCpSuQZB2jawqnGMEPS63ePKWkwWPH4TU45Q7LPXWuNd8TMtVxRrgjtEshuqpK3mdhaWHPFsBngh5GFZaM6si3yZdUsT8ddYM3PwnATt";
+    const BIP84_BTC: &str = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+
+    #[test]
+    fn test_account_addresses_per_chain() {
+        let account =
+            derive_account_addresses(BIP44_BTC, "bitcoin", AddressBranch::Receive, 0..1).unwrap();
+        assert_eq!(account.account_path, "m/44'/0'/0'");
+        assert!(account.depth_matches);
+        assert_eq!(account.addresses[0].path, "m/44'/0'/0'/0/0");
+        assert_eq!(
+            account.addresses[0].address,
+            "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"
+        );
+
+        let account =
+            derive_account_addresses(BIP44_LTC, "litecoin", AddressBranch::Receive, 0..1).unwrap();
+        assert_eq!(account.account_path, "m/44'/2'/0'");
+        assert_eq!(
+            account.addresses[0].address,
+            "LUWPbpM43E2p7ZSh8cyTBEkvpHmr3cB8Ez"
+        );
+
+        let account =
+            derive_account_addresses(BIP44_DOGE, "dogecoin", AddressBranch::Receive, 0..1).unwrap();
+        assert_eq!(account.account_path, "m/44'/3'/0'");
+        assert_eq!(
+            account.addresses[0].address,
+            "DBus3bamQjgJULBJtYXpEzDWQRwF5iwxgC"
+        );
+
+        let account =
+            derive_account_addresses(BIP44_ETH, "ethereum", AddressBranch::Receive, 0..1).unwrap();
+        assert_eq!(account.account_path, "m/44'/60'/0'");
+        assert!(account.depth_matches);
+        assert_eq!(
+            account.addresses[0].address,
+            "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+        );
+    }
+
+    #[test]
+    fn test_account_addresses_change_branch() {
+        let account =
+            derive_account_addresses(BIP84_BTC, "bitcoin", AddressBranch::Change, 0..3).unwrap();
+        assert_eq!(account.account_path, "m/84'/0'/0'");
+        assert_eq!(account.addresses.len(), 3);
+        assert_eq!(account.addresses[0].path, "m/84'/0'/0'/1/0");
+        assert_eq!(
+            account.addresses[0].address,
+            "bc1q8c6fshw2dlwun7ekn9qwf37cu2rn755upcp6el"
+        );
+    }
+
+    #[test]
+    fn test_account_addresses_depth_mismatch() {
+        // BIP-32 test vector 1 m/0H: depth 1, not an account-level key
+        let xpub = "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw";
+        let account =
+            derive_account_addresses(xpub, "ethereum", AddressBranch::Receive, 0..1).unwrap();
+        assert!(!account.depth_matches);
+        assert_eq!(account.addresses[0].path, "0/0");
+
+        // The coin type is not part of the key, so only the depth is checked
+        let account =
+            derive_account_addresses(BIP44_ETH, "tron", AddressBranch::Receive, 0..1).unwrap();
+        assert_eq!(account.account_path, "m/44'/195'/0'");
+        assert!(account.depth_matches);
+        assert!(account.addresses[0].address.starts_with('T'));
+    }
+
+    #[test]
+    fn test_account_addresses_invalid() {
+        assert!(
+            derive_account_addresses(BIP44_BTC, "solana", AddressBranch::Receive, 0..1).is_err()
+        );
+        assert!(
+            derive_account_addresses(BIP44_BTC, "bitcoin", AddressBranch::Receive, 0..0).is_err()
+        );
+        assert!(
+            derive_account_addresses(BIP44_BTC, "bitcoin", AddressBranch::Receive, 0..5000)
+                .is_err()
+        );
+        assert!(
+            derive_account_addresses(BIP44_BTC, "unknown", AddressBranch::Receive, 0..1).is_err()
+        );
+    }
+}
@@ -0,0 +1,269 @@
+//! Address derivation for child keys of an extended public key
+
+use crate::bip32::extended_key::{ExtendedPublicKey, HARDENED};
+use crate::descriptor::Descriptor;
+use crate::pipelines::addresses::derive_chain_address;
+use crate::registry::{ChainMetadata, EncodingType, KeyScript, Registry};
+use crate::shared::checksum::eip55;
+use crate::Error;
+
+/// BIP-32 mainnet `xpub` version, which non-Bitcoin wallets use for any secp256k1 chain
+pub(crate) const BIP32_MAINNET_VERSION: [u8; 4] = [0x04, 0x88, 0xb2, 0x1e];
+/// Maximum number of addresses derived in one call
+pub(crate) const MAX_RANGE_SIZE: u32 = 1000;
+
+/// An address derived from an extended public key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivedAddress {
+    /// Path relative to the extended key (e.g. "0/5"), or absolute for
+    /// account addresses at the expected depth
+    pub path: String,
+    /// Hex compressed child public key
+    pub public_key: String,
+    /// Address on the requested chain
+    pub address: String,
+}
+
+/// How a child key's address was derived on a chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum AddressSource {
+    /// Script declared for the key version by the chain metadata
+    Script(KeyScript),
+    /// The chain's address pipeline, for plain BIP-32 `xpub` keys
+    Pipeline(String),
+}
+
+/// An address for a child key on a chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ChainAddress {
+    pub address: String,
+    pub encoding: EncodingType,
+    pub source: AddressSource,
+}
+
+/// Derive the address of a child public key on a chain
+///
+/// Chains declaring the key's version use the declared script. Other
+/// secp256k1 chains go through their address pipeline: for any version when
+/// the chain is the explicit target (`any_version`), otherwise only for plain
+/// `xpub` keys on chains without declared versions. Returns `Ok(None)` when
+/// the key does not apply to the chain.
+pub(crate) fn chain_address(
+    key: &ExtendedPublicKey,
+    child_key: &[u8; 33],
+    chain: &ChainMetadata,
+    any_version: bool,
+) -> Result<Option<ChainAddress>, Error> {
+    if let Some(format) = key.declared_format(chain) {
+        let descriptor = match format.script {
+            KeyScript::P2PKH => Descriptor::Pkh(child_key.to_vec()),
+            KeyScript::NestedP2WPKH => Descriptor::ShWpkh(child_key.to_vec()),
+            KeyScript::P2WPKH => Descriptor::Wpkh(child_key.to_vec()),
+            KeyScript::NestedP2WSH | KeyScript::P2WSH => {
+                return Err(Error::InvalidInput(format!(
+                    "{} keys are multisig cosigner keys; addresses need every cosigner",
+                    key.prefix
+                )))
+            }
+        };
+        return Ok(descriptor.address(&chain.id, key.network)?.map(
+            |(format_metadata, address)| ChainAddress {
+                address,
+                encoding: format_metadata.encoding,
+                source: AddressSource::Script(format.script),
+            },
+        ));
+    }
+
+    let Some(config) = Registry::get().get_chain_config(&chain.id) else {
+        return Ok(None);
+    };
+    let applies = config.curve == "secp256k1"
+        && (any_version
+            || (key.version == BIP32_MAINNET_VERSION && chain.extended_key_formats.is_empty()));
+    if !applies {
+        return Ok(None);
+    }
+    // EVM addresses are returned EIP-55 checksummed, as everywhere else
+    let address = derive_chain_address(&chain.id, child_key).and_then(|address| {
+        if config.address_pipeline == "evm" {
+            eip55::normalize(&address)
+        } else {
+            Ok(address)
+        }
+    });
+    match address {
+        Ok(address) => Ok(Some(ChainAddress {
+            address,
+            encoding: chain.address_formats[0].encoding,
+            source: AddressSource::Pipeline(config.address_pipeline.clone()),
+        })),
+        Err(e) if any_version => Err(e),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Parse a relative path with an optional range in its last component
+///
+/// `"0/5"` is a single path; `"0/0..20"` (exclusive) and `"0/0..=19"`
+/// (inclusive) are ranges over the last index.
+fn parse_path_range(range: &str) -> Result<(Vec<u32>, std::ops::Range<u32>), Error> {
+    let parse_index = |s: &str| -> Result<u32, Error> {
+        if s.ends_with('\'') || s.ends_with('h') || s.ends_with('H') {
+            return Err(Error::InvalidInput(format!(
+                "Hardened index {} cannot be derived from a public key",
+                s
+            )));
+        }
+        s.parse::<u32>()
+            .ok()
+            .filter(|&i| i < HARDENED)
+            .ok_or_else(|| Error::InvalidInput(format!("Invalid child index: {}", s)))
+    };
+
+    let range = range.trim().trim_start_matches("m/");
+    let (prefix, last) = match range.rsplit_once('/') {
+        Some((prefix, last)) => (Some(prefix), last),
+        None => (None, range),
+    };
+    let path = prefix
+        .map(|p| p.split('/').map(parse_index).collect::<Result<Vec<_>, _>>())
+        .transpose()?
+        .unwrap_or_default();
+
+    let indexes = if let Some((start, end)) = last.split_once("..=") {
+        parse_index(start)?..parse_index(end)? + 1
+    } else if let Some((start, end)) = last.split_once("..") {
+        parse_index(start)?..parse_index(end)?
+    } else {
+        let index = parse_index(last)?;
+        index..index + 1
+    };
+    if indexes.is_empty() || indexes.len() > MAX_RANGE_SIZE as usize {
+        return Err(Error::InvalidInput(format!(
+            "Range must hold 1 to {} indexes",
+            MAX_RANGE_SIZE
+        )));
+    }
+    Ok((path, indexes))
+}
+
+/// Derive addresses for a range of non-hardened children of an extended public key
+///
+/// `range` is relative to the key, e.g. `"0/0..20"` for the first 20 receive
+/// addresses of an account-level key or `"1/0..=4"` for change addresses.
+/// Addresses follow the target chain: versions it declares use their script,
+/// any other key goes through the chain's address pipeline.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::derive_addresses;
+///
+/// let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+/// let addresses = derive_addresses(zpub, "bitcoin", "0/0..2")?;
+/// assert_eq!(addresses[0].path, "0/0");
+/// assert_eq!(addresses[0].address, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+/// assert_eq!(addresses[1].address, "bc1qnjg0jd8228aq7egyzacy8cys3knf9xvrerkf9g");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn derive_addresses(
+    extended_key: &str,
+    chain: &str,
+    range: &str,
+) -> Result<Vec<DerivedAddress>, Error> {
+    let key: ExtendedPublicKey = extended_key.parse()?;
+    let chain_metadata = Registry::get()
+        .chains
+        .iter()
+        .find(|c| c.id == chain)
+        .ok_or_else(|| Error::InvalidInput(format!("Unknown chain: {}", chain)))?;
+    let (path, indexes) = parse_path_range(range)?;
+    let parent = key.derive_path(&path)?;
+
+    indexes
+        .map(|index| {
+            let child = parent.derive_child(index)?;
+            let address = chain_address(&key, &child.public_key, chain_metadata, true)?
+                .ok_or_else(|| {
+                    Error::InvalidInput(format!(
+                        "{} keys ({:?}) do not derive {} addresses",
+                        key.prefix, key.network, chain
+                    ))
+                })?
+                .address;
+            let full_path: Vec<String> = path
+                .iter()
+                .chain(std::iter::once(&index))
+                .map(u32::to_string)
+                .collect();
+            Ok(DerivedAddress {
+                path: full_path.join("/"),
+                public_key: ::hex::encode(child.public_key),
+                address,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // BIP-84 test vector account 0
+    const ZPUB: &str = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+    // BIP-49 test vector account 0 (testnet)
+    const UPUB: &str = "upub5EFU65HtV5TeiSHmZZm7FUffBGy8UKeqp7vw43jYbvZPpoVsgU93oac7Wk3u6moKegAEWtGNF8DehrnHtv21XXEMYRUocHqguyjknFHYfgY";
+
+    #[test]
+    fn test_parse_path_range() {
+        assert_eq!(parse_path_range("0/0..20").unwrap(), (vec![0], 0..20));
+        assert_eq!(parse_path_range("1/0..=4").unwrap(), (vec![1], 0..5));
+        assert_eq!(parse_path_range("m/0/7").unwrap(), (vec![0], 7..8));
+        assert_eq!(parse_path_range("3").unwrap(), (vec![], 3..4));
+        assert!(parse_path_range("0'/0..20").is_err());
+        assert!(parse_path_range("0/5..5").is_err());
+        assert!(parse_path_range("0/0..5000").is_err());
+        assert!(parse_path_range("0/x").is_err());
+    }
+
+    #[test]
+    fn test_derive_addresses_bip84() {
+        let addresses = derive_addresses(ZPUB, "bitcoin", "1/0").unwrap();
+        assert_eq!(
+            addresses,
+            vec![DerivedAddress {
+                path: "1/0".to_string(),
+                public_key: "03025324888e429ab8e3dbaf1f7802648b9cd01e9b418485c5fa4c1b9b5700e1a6"
+                    .to_string(),
+                address: "bc1q8c6fshw2dlwun7ekn9qwf37cu2rn755upcp6el".to_string(),
+            }]
+        );
+        assert_eq!(
+            derive_addresses(ZPUB, "bitcoin", "0/0..20").unwrap().len(),
+            20
+        );
+    }
+
+    #[test]
+    fn test_derive_addresses_bip49_testnet() {
+        let addresses = derive_addresses(UPUB, "bitcoin", "0/0").unwrap();
+        assert_eq!(addresses[0].address, "2Mww8dCYPUpKHofjgcXcBCEGmniw9CoaiD2");
+    }
+
+    #[test]
+    fn test_derive_addresses_follow_target_chain() {
+        // An undeclared version uses the target chain's address pipeline
+        let addresses = derive_addresses(ZPUB, "dogecoin", "0/0").unwrap();
+        assert!(addresses[0].address.starts_with('D'));
+        let addresses = derive_addresses(ZPUB, "ethereum", "0/0").unwrap();
+        assert!(addresses[0].address.starts_with("0x"));
+    }
+
+    #[test]
+    fn test_derive_addresses_unsupported() {
+        // Solana is an ed25519 chain
+        assert!(derive_addresses(ZPUB, "solana", "0/0").is_err());
+        assert!(derive_addresses(ZPUB, "unknown", "0/0").is_err());
+    }
+}
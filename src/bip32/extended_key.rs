@@ -0,0 +1,239 @@
+//! BIP-32 extended public keys
+
+use crate::registry::{ChainMetadata, ExtendedKeyMetadata, Network, Registry};
+use crate::shared::checksum::base58check;
+use crate::shared::crypto::hash::{hash160, hmac_sha512};
+use crate::shared::crypto::secp256k1;
+use crate::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// First hardened child index
+pub(crate) const HARDENED: u32 = 0x8000_0000;
+/// Length of a serialized extended key (without checksum)
+const SERIALIZED_LENGTH: usize = 78;
+
+/// A BIP-32 extended public key (xpub, ypub, zpub, tpub, ...)
+///
+/// Parsing accepts the version bytes declared in the chain metadata
+/// (SLIP-132), which also fix the network and the script derived keys are
+/// used in.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::{ExtendedPublicKey, Network};
+///
+/// let key: ExtendedPublicKey = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs".parse()?;
+/// assert_eq!(key.prefix, "zpub");
+/// assert_eq!(key.network, Network::Mainnet);
+/// assert_eq!(key.depth, 3);
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtendedPublicKey {
+    /// Prefix of the serialization (e.g. "zpub")
+    pub prefix: String,
+    /// 4-byte version
+    pub version: [u8; 4],
+    /// Network the version belongs to
+    pub network: Network,
+    /// Depth in the derivation tree (0 for a master key)
+    pub depth: u8,
+    /// Fingerprint of the parent key (zero for a master key)
+    pub parent_fingerprint: [u8; 4],
+    /// Index of this key under its parent (hardened indexes are >= 2^31)
+    pub child_number: u32,
+    /// Chain code
+    pub chain_code: [u8; 32],
+    /// Compressed public key
+    pub public_key: [u8; 33],
+}
+
+impl ExtendedPublicKey {
+    /// Fingerprint of this key: the first 4 bytes of hash160(public key)
+    pub fn fingerprint(&self) -> [u8; 4] {
+        let hash = hash160(&self.public_key);
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+
+    /// Derive a non-hardened child key (CKDpub)
+    pub fn derive_child(&self, index: u32) -> Result<Self, Error> {
+        if index >= HARDENED {
+            return Err(Error::InvalidInput(format!(
+                "Hardened child {}' cannot be derived from a public key",
+                index - HARDENED
+            )));
+        }
+        let depth = self
+            .depth
+            .checked_add(1)
+            .ok_or_else(|| Error::InvalidInput("Maximum derivation depth reached".to_string()))?;
+
+        let data = [&self.public_key[..], &index.to_be_bytes()].concat();
+        let hmac = hmac_sha512(&self.chain_code, &data);
+        let (tweak, chain_code) = hmac.split_at(32);
+        let public_key =
+            secp256k1::tweak_add_public_key(&self.public_key, tweak.try_into().unwrap())?;
+
+        Ok(ExtendedPublicKey {
+            prefix: self.prefix.clone(),
+            version: self.version,
+            network: self.network,
+            depth,
+            parent_fingerprint: self.fingerprint(),
+            child_number: index,
+            chain_code: chain_code.try_into().unwrap(),
+            public_key,
+        })
+    }
+
+    /// Derive a descendant along a relative path of non-hardened indexes
+    pub fn derive_path(&self, path: &[u32]) -> Result<Self, Error> {
+        path.iter()
+            .try_fold(self.clone(), |key, &index| key.derive_child(index))
+    }
+
+    /// Version metadata a chain declares for this key's version bytes
+    pub(crate) fn declared_format<'a>(
+        &self,
+        chain: &'a ChainMetadata,
+    ) -> Option<&'a ExtendedKeyMetadata> {
+        chain
+            .extended_key_formats
+            .iter()
+            .find(|f| f.version == self.version && f.network == self.network)
+    }
+}
+
+/// Check whether an input is a Base58Check string of serialized extended key length
+pub(crate) fn looks_like_extended_key(input: &str) -> bool {
+    input.len() == 111
+        && base58check::decode_data(input).is_some_and(|data| data.len() == SERIALIZED_LENGTH)
+}
+
+impl FromStr for ExtendedPublicKey {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Error> {
+        let data = base58check::decode_data(input.trim())
+            .filter(|data| data.len() == SERIALIZED_LENGTH)
+            .ok_or_else(|| {
+                Error::InvalidInput(format!("Not a serialized extended key: {}", input))
+            })?;
+
+        let version: [u8; 4] = data[0..4].try_into().unwrap();
+        let format = Registry::get()
+            .chains
+            .iter()
+            .flat_map(|chain| chain.extended_key_formats.iter())
+            .find(|f| f.version == version)
+            .ok_or_else(|| {
+                Error::InvalidInput(format!(
+                    "Unknown extended public key version: {}",
+                    ::hex::encode(version)
+                ))
+            })?;
+
+        let depth = data[4];
+        let parent_fingerprint: [u8; 4] = data[5..9].try_into().unwrap();
+        let child_number = u32::from_be_bytes(data[9..13].try_into().unwrap());
+        if depth == 0 && (parent_fingerprint != [0; 4] || child_number != 0) {
+            return Err(Error::InvalidInput(
+                "Master extended key with a parent fingerprint or child number".to_string(),
+            ));
+        }
+
+        let public_key: [u8; 33] = data[45..78].try_into().unwrap();
+        if !matches!(public_key[0], 0x02 | 0x03) || !secp256k1::is_valid_public_key(&public_key) {
+            return Err(Error::InvalidInput(
+                "Extended key does not hold a valid compressed public key".to_string(),
+            ));
+        }
+
+        Ok(ExtendedPublicKey {
+            prefix: format.prefix.clone(),
+            version,
+            network: format.network,
+            depth,
+            parent_fingerprint,
+            child_number,
+            chain_code: data[13..45].try_into().unwrap(),
+            public_key,
+        })
+    }
+}
+
+impl fmt::Display for ExtendedPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data = [
+            &self.version[..],
+            &[self.depth],
+            &self.parent_fingerprint,
+            &self.child_number.to_be_bytes(),
+            &self.chain_code,
+            &self.public_key,
+        ]
+        .concat();
+        write!(f, "{}", base58check::encode_data(&data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // BIP-32 test vector 1: m/0H and m/0H/1
+    const XPUB_0H: &str = "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw";
+    const XPUB_0H_1: &str = "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ";
+
+    #[test]
+    fn test_parse_extended_public_key() {
+        let key: ExtendedPublicKey = XPUB_0H.parse().unwrap();
+        assert_eq!(key.prefix, "xpub");
+        assert_eq!(key.network, Network::Mainnet);
+        assert_eq!(key.depth, 1);
+        assert_eq!(key.child_number, HARDENED);
+        assert_eq!(::hex::encode(key.parent_fingerprint), "3442193e");
+        assert_eq!(::hex::encode(key.fingerprint()), "5c1bd648");
+        assert_eq!(
+            ::hex::encode(key.chain_code),
+            "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141"
+        );
+        assert_eq!(key.to_string(), XPUB_0H);
+    }
+
+    #[test]
+    fn test_derive_child_bip32_vector() {
+        let key: ExtendedPublicKey = XPUB_0H.parse().unwrap();
+        let child = key.derive_child(1).unwrap();
+        assert_eq!(child.to_string(), XPUB_0H_1);
+        assert_eq!(child.parent_fingerprint, key.fingerprint());
+        assert_eq!(key.derive_path(&[1]).unwrap(), child);
+        assert!(key.derive_child(HARDENED).is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_keys() {
+        // Corrupted checksum
+        let mut corrupted = XPUB_0H.to_string();
+        corrupted.replace_range(110.., "x");
+        assert!(corrupted.parse::<ExtendedPublicKey>().is_err());
+        // Unknown version (xprv is a private key version)
+        let mut data = base58check::decode_data(XPUB_0H).unwrap();
+        data[0..4].copy_from_slice(&[0x04, 0x88, 0xad, 0xe4]);
+        assert!(base58check::encode_data(&data)
+            .parse::<ExtendedPublicKey>()
+            .is_err());
+        // Master key with a non-zero child number
+        let mut data = base58check::decode_data(XPUB_0H).unwrap();
+        data[4] = 0;
+        assert!(base58check::encode_data(&data)
+            .parse::<ExtendedPublicKey>()
+            .is_err());
+        assert!(!looks_like_extended_key(
+            "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"
+        ));
+        assert!(looks_like_extended_key(XPUB_0H));
+    }
+}
@@ -0,0 +1,17 @@
+//! BIP-32 extended public keys
+//!
+//! Parsing of serialized extended public keys, non-hardened child key
+//! derivation, and address derivation for watch-only wallets.
+
+mod account;
+mod addresses;
+mod extended_key;
+
+pub use account::{derive_account_addresses, AccountAddresses, AddressBranch};
+pub use addresses::{derive_addresses, DerivedAddress};
+pub use extended_key::ExtendedPublicKey;
+
+pub(crate) use account::fill_account_path;
+pub(crate) use addresses::{chain_address, AddressSource, BIP32_MAINNET_VERSION, MAX_RANGE_SIZE};
+pub(crate) use extended_key::looks_like_extended_key;
+pub(crate) use extended_key::HARDENED;
@@ -0,0 +1,550 @@
+//! Shelley address parsing (CIP-19)
+//!
+//! A Shelley address is Bech32 over a header byte and its credentials. The
+//! header's high nibble is the address type (base, pointer, enterprise or
+//! reward, each with key or script hash credentials) and its low nibble the
+//! network id: 1 for mainnet, 0 for testnets.
+
+use crate::registry::Network;
+use crate::shared::encoding::bech32 as bech32_encoding;
+use crate::Error;
+use bech32::Variant;
+
+/// Length of a key or script hash credential (Blake2b-224)
+const CREDENTIAL_LEN: usize = 28;
+
+/// Shelley address type (CIP-19 header bits 7-4)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CardanoAddressType {
+    /// Payment and stake credentials (types 0-3)
+    Base,
+    /// Payment credential and a pointer to a stake registration (types 4-5)
+    Pointer,
+    /// Payment credential only (types 6-7)
+    Enterprise,
+    /// Stake credential only, `stake` HRP (types 14-15)
+    Reward,
+}
+
+impl CardanoAddressType {
+    /// Lowercase name (`base`, `pointer`, `enterprise`, `reward`)
+    pub fn name(&self) -> &'static str {
+        match self {
+            CardanoAddressType::Base => "base",
+            CardanoAddressType::Pointer => "pointer",
+            CardanoAddressType::Enterprise => "enterprise",
+            CardanoAddressType::Reward => "reward",
+        }
+    }
+}
+
+/// Payment or stake credential: the Blake2b-224 hash of a key or a script
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CardanoCredential {
+    /// Hash of an Ed25519 verification key
+    KeyHash([u8; 28]),
+    /// Hash of a script
+    ScriptHash([u8; 28]),
+}
+
+impl CardanoCredential {
+    /// The 28-byte hash
+    pub fn hash(&self) -> &[u8; 28] {
+        match self {
+            CardanoCredential::KeyHash(hash) | CardanoCredential::ScriptHash(hash) => hash,
+        }
+    }
+
+    /// Whether the credential is a script hash
+    pub fn is_script(&self) -> bool {
+        matches!(self, CardanoCredential::ScriptHash(_))
+    }
+
+    fn describe(&self) -> &'static str {
+        if self.is_script() {
+            "script hash"
+        } else {
+            "key hash"
+        }
+    }
+}
+
+/// Location of a stake registration certificate, for pointer addresses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CardanoPointer {
+    /// Slot of the block
+    pub slot: u64,
+    /// Index of the transaction in the block
+    pub tx_index: u64,
+    /// Index of the certificate in the transaction
+    pub cert_index: u64,
+}
+
+/// A decoded Shelley address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CardanoAddress {
+    /// Address type
+    pub address_type: CardanoAddressType,
+    /// Network id of the header (1 for mainnet, 0 for testnets)
+    pub network_id: u8,
+    /// Payment credential (`None` for reward addresses)
+    pub payment: Option<CardanoCredential>,
+    /// Stake credential of base and reward addresses
+    pub stake: Option<CardanoCredential>,
+    /// Stake pointer of pointer addresses
+    pub pointer: Option<CardanoPointer>,
+}
+
+impl CardanoAddress {
+    /// Network of the network id (`None` for ids no HRP is defined for)
+    pub fn network(&self) -> Option<Network> {
+        match self.network_id {
+            1 => Some(Network::Mainnet),
+            0 => Some(Network::Testnet),
+            _ => None,
+        }
+    }
+
+    /// HRP of the address type and network (`addr`, `addr_test`, `stake`, `stake_test`)
+    pub fn hrp(&self) -> Option<&'static str> {
+        let reward = self.address_type == CardanoAddressType::Reward;
+        match (self.network()?, reward) {
+            (Network::Mainnet, false) => Some("addr"),
+            (Network::Testnet, false) => Some("addr_test"),
+            (Network::Mainnet, true) => Some("stake"),
+            (Network::Testnet, true) => Some("stake_test"),
+        }
+    }
+
+    /// One-line description: type, credentials and pointer
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(payment) = &self.payment {
+            parts.push(format!("payment {}", payment.describe()));
+        }
+        if let Some(stake) = &self.stake {
+            parts.push(format!("stake {}", stake.describe()));
+        }
+        if let Some(pointer) = &self.pointer {
+            parts.push(format!(
+                "stake pointer {}/{}/{}",
+                pointer.slot, pointer.tx_index, pointer.cert_index
+            ));
+        }
+        format!(
+            "Cardano {} address ({}), network id {}",
+            self.address_type.name(),
+            parts.join(", "),
+            self.network_id
+        )
+    }
+
+    /// Encode the address as Bech32 under its HRP
+    ///
+    /// The inverse of [`parse_cardano_address`]: the credentials and pointer
+    /// present must be the ones the address type carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use foxchain_id::{CardanoAddress, CardanoAddressType, CardanoCredential};
+    ///
+    /// let address = CardanoAddress {
+    ///     address_type: CardanoAddressType::Enterprise,
+    ///     network_id: 0,
+    ///     payment: Some(CardanoCredential::KeyHash([0; 28])),
+    ///     stake: None,
+    ///     pointer: None,
+    /// };
+    /// assert!(address.encode()?.starts_with("addr_test1v"));
+    /// # Ok::<(), foxchain_id::Error>(())
+    /// ```
+    pub fn encode(&self) -> Result<String, Error> {
+        let hrp = self.hrp().ok_or_else(|| {
+            Error::InvalidInput(format!("No Cardano HRP for network id {}", self.network_id))
+        })?;
+        let script = |credential: &CardanoCredential| u8::from(credential.is_script());
+        let (address_kind, body) =
+            match (self.address_type, &self.payment, &self.stake, &self.pointer) {
+                (CardanoAddressType::Base, Some(payment), Some(stake), None) => (
+                    script(payment) | script(stake) << 1,
+                    [&payment.hash()[..], &stake.hash()[..]].concat(),
+                ),
+                (CardanoAddressType::Pointer, Some(payment), None, Some(pointer)) => (
+                    4 | script(payment),
+                    [&payment.hash()[..], &write_pointer(pointer)].concat(),
+                ),
+                (CardanoAddressType::Enterprise, Some(payment), None, None) => {
+                    (6 | script(payment), payment.hash().to_vec())
+                }
+                (CardanoAddressType::Reward, None, Some(stake), None) => {
+                    (14 | script(stake), stake.hash().to_vec())
+                }
+                _ => {
+                    return Err(Error::InvalidInput(format!(
+                        "Credentials do not match a Cardano {} address",
+                        self.address_type.name()
+                    )))
+                }
+            };
+
+        let bytes = [&[address_kind << 4 | self.network_id][..], &body].concat();
+        let data =
+            bech32_encoding::convert_bits(&bytes, 8, 5, true).map_err(Error::InvalidInput)?;
+        bech32_encoding::encode(hrp, &bech32_encoding::bytes_to_u5(&data), Variant::Bech32)
+            .map_err(Error::InvalidInput)
+    }
+}
+
+/// Parse a Shelley address (`addr1...`, `addr_test1...`, `stake1...`, `stake_test1...`)
+///
+/// The header must name a Shelley type, its network id must be the HRP's
+/// (1 for `addr`/`stake`, 0 for `addr_test`/`stake_test`), and reward
+/// addresses must use a `stake` HRP while the others use `addr`.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::{parse_cardano_address, CardanoAddressType};
+///
+/// let address = parse_cardano_address("addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8")?;
+/// assert_eq!(address.address_type, CardanoAddressType::Enterprise);
+/// assert_eq!(address.network_id, 1);
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn parse_cardano_address(address: &str) -> Result<CardanoAddress, Error> {
+    let (hrp, data, variant) = bech32_encoding::decode(address).map_err(Error::InvalidInput)?;
+    if variant != Variant::Bech32 {
+        return Err(Error::InvalidInput(
+            "Cardano addresses use Bech32, not Bech32m".to_string(),
+        ));
+    }
+    let (reward_hrp, network_id) = match hrp.as_str() {
+        "addr" => (false, 1),
+        "addr_test" => (false, 0),
+        "stake" => (true, 1),
+        "stake_test" => (true, 0),
+        _ => {
+            return Err(Error::InvalidInput(format!(
+                "Not a Cardano address HRP: {}",
+                hrp
+            )))
+        }
+    };
+    let data: Vec<u8> = data.iter().map(|u5| u5.to_u8()).collect();
+    let bytes = bech32_encoding::convert_bits(&data, 5, 8, false).map_err(Error::InvalidInput)?;
+    let parsed = parse_shelley_bytes(&bytes)?;
+
+    if (parsed.address_type == CardanoAddressType::Reward) != reward_hrp {
+        return Err(Error::InvalidInput(format!(
+            "{} address under the {} HRP",
+            parsed.address_type.name(),
+            hrp
+        )));
+    }
+    if parsed.network_id != network_id {
+        return Err(Error::InvalidInput(format!(
+            "Header network id {} does not match the {} HRP",
+            parsed.network_id, hrp
+        )));
+    }
+    Ok(parsed)
+}
+
+/// Parse the header and credentials of a Shelley address's bytes
+fn parse_shelley_bytes(bytes: &[u8]) -> Result<CardanoAddress, Error> {
+    let (&header, body) = bytes
+        .split_first()
+        .ok_or_else(|| Error::InvalidInput("Empty Cardano address".to_string()))?;
+    let address_kind = header >> 4;
+    let network_id = header & 0x0f;
+
+    let (address_type, expected_len) = match address_kind {
+        0..=3 => (CardanoAddressType::Base, Some(2 * CREDENTIAL_LEN)),
+        4 | 5 => (CardanoAddressType::Pointer, None),
+        6 | 7 => (CardanoAddressType::Enterprise, Some(CREDENTIAL_LEN)),
+        14 | 15 => (CardanoAddressType::Reward, Some(CREDENTIAL_LEN)),
+        8 => {
+            return Err(Error::InvalidInput(
+                "Byron addresses are not Bech32".to_string(),
+            ))
+        }
+        _ => {
+            return Err(Error::InvalidInput(format!(
+                "Unknown Cardano address type: {}",
+                address_kind
+            )))
+        }
+    };
+    if let Some(expected_len) = expected_len {
+        if body.len() != expected_len {
+            return Err(Error::InvalidInput(format!(
+                "Cardano {} address must have {} bytes of credentials, got {}",
+                address_type.name(),
+                expected_len,
+                body.len()
+            )));
+        }
+    } else if body.len() < CREDENTIAL_LEN {
+        return Err(Error::InvalidInput(format!(
+            "Cardano pointer address is too short: {} bytes",
+            bytes.len()
+        )));
+    }
+
+    // Bit 4 marks a script payment credential, bit 5 a script stake credential
+    let credential = |bytes: &[u8], script: bool| {
+        let hash: [u8; 28] = bytes.try_into().expect("credential length checked");
+        if script {
+            CardanoCredential::ScriptHash(hash)
+        } else {
+            CardanoCredential::KeyHash(hash)
+        }
+    };
+    let first = credential(&body[..CREDENTIAL_LEN], address_kind & 0b0001 != 0);
+    let (payment, stake, pointer) = match address_type {
+        CardanoAddressType::Base => (
+            Some(first),
+            Some(credential(
+                &body[CREDENTIAL_LEN..],
+                address_kind & 0b0010 != 0,
+            )),
+            None,
+        ),
+        CardanoAddressType::Pointer => (
+            Some(first),
+            None,
+            Some(parse_pointer(&body[CREDENTIAL_LEN..])?),
+        ),
+        CardanoAddressType::Enterprise => (Some(first), None, None),
+        CardanoAddressType::Reward => (None, Some(first), None),
+    };
+
+    Ok(CardanoAddress {
+        address_type,
+        network_id,
+        payment,
+        stake,
+        pointer,
+    })
+}
+
+/// Parse the three variable-length naturals of a stake pointer
+fn parse_pointer(bytes: &[u8]) -> Result<CardanoPointer, Error> {
+    let mut rest = bytes;
+    let mut next = || {
+        let (value, used) = read_varint(rest)?;
+        rest = &rest[used..];
+        Ok::<u64, Error>(value)
+    };
+    let pointer = CardanoPointer {
+        slot: next()?,
+        tx_index: next()?,
+        cert_index: next()?,
+    };
+    if !rest.is_empty() {
+        return Err(Error::InvalidInput(format!(
+            "{} trailing bytes after the stake pointer",
+            rest.len()
+        )));
+    }
+    Ok(pointer)
+}
+
+/// Write the three naturals of a stake pointer
+fn write_pointer(pointer: &CardanoPointer) -> Vec<u8> {
+    [pointer.slot, pointer.tx_index, pointer.cert_index]
+        .into_iter()
+        .flat_map(write_varint)
+        .collect()
+}
+
+/// Write a big-endian base-128 natural (high bit set on all bytes but the last)
+fn write_varint(value: u64) -> Vec<u8> {
+    let mut bytes = vec![(value & 0x7f) as u8];
+    let mut rest = value >> 7;
+    while rest > 0 {
+        bytes.push((rest & 0x7f) as u8 | 0x80);
+        rest >>= 7;
+    }
+    bytes.reverse();
+    bytes
+}
+
+/// Read a big-endian base-128 natural (high bit set on all bytes but the last)
+fn read_varint(bytes: &[u8]) -> Result<(u64, usize), Error> {
+    let mut value: u64 = 0;
+    for (i, byte) in bytes.iter().enumerate() {
+        if value > u64::MAX >> 7 {
+            return Err(Error::InvalidInput(
+                "Stake pointer value overflows 64 bits".to_string(),
+            ));
+        }
+        value = (value << 7) | u64::from(byte & 0x7f);
+        if byte & 0x80 == 0 {
+            return Ok((value, i + 1));
+        }
+    }
+    Err(Error::InvalidInput("Truncated stake pointer".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // CIP-19 test vectors
+    const PAYMENT_KEY_HASH: &str = "9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e";
+    const STAKE_KEY_HASH: &str = "337b62cfff6403a06a3acbc34f8c46003c69fe79a3628cefa9c47251";
+    const SCRIPT_HASH: &str = "c37b1b5dc0669f1d3c61a6fddb2e8fde96be87b881c60bce8e8d542f";
+
+    fn key(hash: &str) -> Option<CardanoCredential> {
+        Some(CardanoCredential::KeyHash(
+            ::hex::decode(hash).unwrap().try_into().unwrap(),
+        ))
+    }
+
+    fn script(hash: &str) -> Option<CardanoCredential> {
+        Some(CardanoCredential::ScriptHash(
+            ::hex::decode(hash).unwrap().try_into().unwrap(),
+        ))
+    }
+
+    #[test]
+    fn test_parse_base_addresses() {
+        let cases = [
+            ("addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x", key(PAYMENT_KEY_HASH), key(STAKE_KEY_HASH)),
+            ("addr1z8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gten0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgs9yc0hh", script(SCRIPT_HASH), key(STAKE_KEY_HASH)),
+            ("addr1yx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerkr0vd4msrxnuwnccdxlhdjar77j6lg0wypcc9uar5d2shs2z78ve", key(PAYMENT_KEY_HASH), script(SCRIPT_HASH)),
+            ("addr1x8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gt7r0vd4msrxnuwnccdxlhdjar77j6lg0wypcc9uar5d2shskhj42g", script(SCRIPT_HASH), script(SCRIPT_HASH)),
+        ];
+        for (input, payment, stake) in cases {
+            let address = parse_cardano_address(input).unwrap();
+            assert_eq!(address.address_type, CardanoAddressType::Base, "{}", input);
+            assert_eq!(address.network_id, 1);
+            assert_eq!(address.payment, payment, "{}", input);
+            assert_eq!(address.stake, stake, "{}", input);
+            assert_eq!(address.pointer, None);
+        }
+    }
+
+    #[test]
+    fn test_parse_pointer_enterprise_and_reward_addresses() {
+        let pointer = parse_cardano_address(
+            "addr1gx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer5pnz75xxcrzqf96k",
+        )
+        .unwrap();
+        assert_eq!(pointer.address_type, CardanoAddressType::Pointer);
+        assert_eq!(pointer.payment, key(PAYMENT_KEY_HASH));
+        assert_eq!(
+            pointer.pointer,
+            Some(CardanoPointer {
+                slot: 2498243,
+                tx_index: 27,
+                cert_index: 3
+            })
+        );
+        let script_pointer = parse_cardano_address(
+            "addr128phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtupnz75xxcrtw79hu",
+        )
+        .unwrap();
+        assert_eq!(script_pointer.payment, script(SCRIPT_HASH));
+
+        let enterprise =
+            parse_cardano_address("addr1w8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcyjy7wx")
+                .unwrap();
+        assert_eq!(enterprise.address_type, CardanoAddressType::Enterprise);
+        assert_eq!(enterprise.payment, script(SCRIPT_HASH));
+        assert_eq!(enterprise.stake, None);
+
+        let reward =
+            parse_cardano_address("stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw")
+                .unwrap();
+        assert_eq!(reward.address_type, CardanoAddressType::Reward);
+        assert_eq!(reward.payment, None);
+        assert_eq!(reward.stake, key(STAKE_KEY_HASH));
+        assert_eq!(
+            reward.describe(),
+            "Cardano reward address (stake key hash), network id 1"
+        );
+    }
+
+    #[test]
+    fn test_parse_testnet_addresses() {
+        for input in [
+            "addr_test1qz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgs68faae",
+            "addr_test1gz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer5pnz75xxcrdw5vky",
+            "addr_test1vz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerspjrlsz",
+            "stake_test1uqehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gssrtvn",
+        ] {
+            let address = parse_cardano_address(input).unwrap();
+            assert_eq!(address.network_id, 0, "{}", input);
+            assert_eq!(address.network(), Some(Network::Testnet));
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_mismatched_headers() {
+        let encode = |hrp: &str, bytes: &[u8]| {
+            let data = bech32_encoding::convert_bits(bytes, 8, 5, true).unwrap();
+            bech32_encoding::encode(hrp, &bech32_encoding::bytes_to_u5(&data), Variant::Bech32)
+                .unwrap()
+        };
+        let hash = ::hex::decode(PAYMENT_KEY_HASH).unwrap();
+        let enterprise = |header: u8| [&[header][..], &hash].concat();
+
+        assert!(parse_cardano_address(&encode("addr", &enterprise(0x61))).is_ok());
+        // Testnet header under the mainnet HRP, and the reverse
+        assert!(parse_cardano_address(&encode("addr", &enterprise(0x60))).is_err());
+        assert!(parse_cardano_address(&encode("addr_test", &enterprise(0x61))).is_err());
+        // Reward type under `addr`, enterprise type under `stake`
+        assert!(parse_cardano_address(&encode("addr", &enterprise(0xe1))).is_err());
+        assert!(parse_cardano_address(&encode("stake", &enterprise(0x61))).is_err());
+        // Reserved types and Byron
+        assert!(parse_cardano_address(&encode("addr", &enterprise(0x91))).is_err());
+        assert!(parse_cardano_address(&encode("addr", &enterprise(0x81))).is_err());
+        // Truncated credentials and pointers
+        assert!(parse_cardano_address(&encode("addr", &enterprise(0x61)[..20])).is_err());
+        assert!(
+            parse_cardano_address(&encode("addr", &[&enterprise(0x41)[..], &[0x81]].concat()))
+                .is_err()
+        );
+        // Other HRPs
+        assert!(parse_cardano_address(&encode("cosmos", &enterprise(0x61))).is_err());
+    }
+
+    #[test]
+    fn test_encode_round_trips() {
+        for input in [
+            "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x",
+            "addr1x8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gt7r0vd4msrxnuwnccdxlhdjar77j6lg0wypcc9uar5d2shskhj42g",
+            "addr1gx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer5pnz75xxcrzqf96k",
+            "addr128phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtupnz75xxcrtw79hu",
+            "addr_test1vz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerspjrlsz",
+            "stake178phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcccycj5",
+        ] {
+            assert_eq!(parse_cardano_address(input).unwrap().encode().unwrap(), input);
+        }
+
+        // An enterprise address has no stake credential
+        let mut address =
+            parse_cardano_address("addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8")
+                .unwrap();
+        address.stake = key(STAKE_KEY_HASH);
+        assert!(address.encode().is_err());
+    }
+
+    #[test]
+    fn test_read_varint() {
+        assert_eq!(read_varint(&[0x03]).unwrap(), (3, 1));
+        assert_eq!(
+            read_varint(&[0x81, 0x98, 0xbd, 0x43]).unwrap(),
+            (2498243, 4)
+        );
+        assert!(read_varint(&[0x81]).is_err());
+        assert!(read_varint(&[0xff; 11]).is_err());
+
+        assert_eq!(write_varint(0), vec![0x00]);
+        assert_eq!(write_varint(2498243), vec![0x81, 0x98, 0xbd, 0x43]);
+        assert_eq!(read_varint(&write_varint(u64::MAX)).unwrap().0, u64::MAX);
+    }
+}
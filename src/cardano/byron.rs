@@ -0,0 +1,270 @@
+//! Byron address parsing (legacy `Ae2...` and `DdzFF...` addresses)
+//!
+//! A Byron address is Base58 over the CBOR array `[24(bytes), crc32]`: the
+//! tagged bytes hold `[root, attributes, type]` and the CRC-32 covers them.
+//! The attributes map may carry an encrypted HD derivation path (key 1) and
+//! the protocol magic of a testnet (key 2); mainnet addresses carry no magic.
+
+use crate::registry::Network;
+use crate::shared::checksum::crc32;
+use crate::shared::encoding::base58;
+use crate::shared::encoding::cbor::{self, Value};
+use crate::Error;
+
+/// CBOR tag of embedded CBOR data items
+const TAG_EMBEDDED_CBOR: u64 = 24;
+
+/// Attribute key of the encrypted HD derivation path
+const ATTR_DERIVATION_PATH: u64 = 1;
+
+/// Attribute key of the protocol magic
+const ATTR_PROTOCOL_MAGIC: u64 = 2;
+
+/// Byron address type (spending data the root commits to)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ByronAddressType {
+    /// Ed25519 extended public key (type 0)
+    PublicKey,
+    /// Script (type 1)
+    Script,
+    /// Redemption key of the ADA voucher sale (type 2)
+    Redeem,
+}
+
+impl ByronAddressType {
+    /// Lowercase name (`public key`, `script`, `redeem`)
+    pub fn name(&self) -> &'static str {
+        match self {
+            ByronAddressType::PublicKey => "public key",
+            ByronAddressType::Script => "script",
+            ByronAddressType::Redeem => "redeem",
+        }
+    }
+}
+
+/// A decoded Byron address
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ByronAddress {
+    /// Address root: Blake2b-224 of the SHA3-256 of type, spending data and attributes
+    pub root: [u8; 28],
+    /// Address type
+    pub address_type: ByronAddressType,
+    /// Protocol magic of a testnet address (`None` on mainnet)
+    pub protocol_magic: Option<u32>,
+    /// Encrypted HD derivation path of wallet (`DdzFF...`) addresses
+    pub derivation_path: Option<Vec<u8>>,
+}
+
+impl ByronAddress {
+    /// Network of the address: testnet when it carries a protocol magic
+    pub fn network(&self) -> Network {
+        match self.protocol_magic {
+            Some(_) => Network::Testnet,
+            None => Network::Mainnet,
+        }
+    }
+
+    /// One-line description: type, derivation path and protocol magic
+    pub fn describe(&self) -> String {
+        let path = if self.derivation_path.is_some() {
+            ", HD derivation path"
+        } else {
+            ""
+        };
+        let network = match self.protocol_magic {
+            Some(magic) => format!("protocol magic {}", magic),
+            None => "mainnet".to_string(),
+        };
+        format!(
+            "Cardano Byron address ({}{}), {}",
+            self.address_type.name(),
+            path,
+            network
+        )
+    }
+}
+
+/// Parse a Byron address (`Ae2...`, `DdzFF...`, testnet addresses)
+///
+/// The CRC-32 must match the tagged payload, the payload must be a 28-byte
+/// root, an attributes map and a known address type, and the derivation
+/// path and protocol magic attributes must be well formed.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::{parse_byron_address, ByronAddressType};
+///
+/// let address = parse_byron_address("Ae2tdPwUPEZFRbyhz3cpfC2CumGzNkFBN2L42rcUc2yjQpEkxDbkPodpMAi")?;
+/// assert_eq!(address.address_type, ByronAddressType::PublicKey);
+/// assert_eq!(address.protocol_magic, None);
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn parse_byron_address(address: &str) -> Result<ByronAddress, Error> {
+    let bytes = base58::decode(address).map_err(Error::InvalidInput)?;
+    let payload = match cbor::decode(&bytes).map_err(Error::InvalidInput)? {
+        Value::Array(items) => match items.as_slice() {
+            [Value::Tag(TAG_EMBEDDED_CBOR, payload), Value::Unsigned(checksum)] => {
+                match payload.as_ref() {
+                    Value::Bytes(payload) => {
+                        if u32::try_from(*checksum)
+                            .map_or(true, |checksum| !crc32::validate(payload, checksum))
+                        {
+                            return Err(Error::InvalidInput(
+                                "Byron address CRC-32 mismatch".to_string(),
+                            ));
+                        }
+                        payload.clone()
+                    }
+                    _ => return Err(not_byron()),
+                }
+            }
+            _ => return Err(not_byron()),
+        },
+        _ => return Err(not_byron()),
+    };
+
+    let (root, attributes, address_type) =
+        match cbor::decode(&payload).map_err(Error::InvalidInput)? {
+            Value::Array(items) => match <[Value; 3]>::try_from(items) {
+                Ok([Value::Bytes(root), Value::Map(attributes), Value::Unsigned(address_type)]) => {
+                    (root, attributes, address_type)
+                }
+                _ => return Err(not_byron()),
+            },
+            _ => return Err(not_byron()),
+        };
+    let root: [u8; 28] = root.try_into().map_err(|root: Vec<u8>| {
+        Error::InvalidInput(format!(
+            "Byron address root must be 28 bytes, got {}",
+            root.len()
+        ))
+    })?;
+    let address_type = match address_type {
+        0 => ByronAddressType::PublicKey,
+        1 => ByronAddressType::Script,
+        2 => ByronAddressType::Redeem,
+        _ => {
+            return Err(Error::InvalidInput(format!(
+                "Unknown Byron address type: {}",
+                address_type
+            )))
+        }
+    };
+
+    // Attribute values are themselves CBOR, wrapped in a byte string
+    let mut protocol_magic = None;
+    let mut derivation_path = None;
+    for (key, value) in attributes {
+        let inner = match value {
+            Value::Bytes(inner) => cbor::decode(&inner).map_err(Error::InvalidInput)?,
+            _ => return Err(not_byron()),
+        };
+        match (key, inner) {
+            (Value::Unsigned(ATTR_DERIVATION_PATH), Value::Bytes(path)) => {
+                derivation_path = Some(path)
+            }
+            (Value::Unsigned(ATTR_PROTOCOL_MAGIC), Value::Unsigned(magic)) => {
+                protocol_magic = Some(u32::try_from(magic).map_err(|_| {
+                    Error::InvalidInput(format!("Byron protocol magic out of range: {}", magic))
+                })?)
+            }
+            (Value::Unsigned(ATTR_DERIVATION_PATH | ATTR_PROTOCOL_MAGIC), _) => {
+                return Err(Error::InvalidInput(
+                    "Malformed Byron address attribute".to_string(),
+                ))
+            }
+            // Unknown attributes are carried along by the ledger
+            _ => {}
+        }
+    }
+
+    Ok(ByronAddress {
+        root,
+        address_type,
+        protocol_magic,
+        derivation_path,
+    })
+}
+
+fn not_byron() -> Error {
+    Error::InvalidInput("Not a Byron address structure".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ICARUS: &str = "Ae2tdPwUPEZFRbyhz3cpfC2CumGzNkFBN2L42rcUc2yjQpEkxDbkPodpMAi";
+    const DAEDALUS: &str = "DdzFFzCqrhsrcTVhLygT24QwTnNqQqQ8mZrq5jykUzMveU26sxaH529kMpo7VhPrt5pwW3dXeB2k3EEvKcNBRmzCfcQ7dTkyGzTs658C";
+
+    #[test]
+    fn test_parse_mainnet_addresses() {
+        let icarus = parse_byron_address(ICARUS).unwrap();
+        assert_eq!(
+            ::hex::encode(icarus.root),
+            "ba970ad36654d8dd8f74274b733452ddeab9a62a397746be3c42ccdd"
+        );
+        assert_eq!(icarus.address_type, ByronAddressType::PublicKey);
+        assert_eq!(icarus.protocol_magic, None);
+        assert_eq!(icarus.derivation_path, None);
+        assert_eq!(icarus.network(), Network::Mainnet);
+        assert_eq!(
+            icarus.describe(),
+            "Cardano Byron address (public key), mainnet"
+        );
+
+        let daedalus = parse_byron_address(DAEDALUS).unwrap();
+        assert!(daedalus.derivation_path.is_some());
+        assert_eq!(daedalus.protocol_magic, None);
+        assert_eq!(
+            daedalus.describe(),
+            "Cardano Byron address (public key, HD derivation path), mainnet"
+        );
+    }
+
+    #[test]
+    fn test_parse_testnet_addresses() {
+        let legacy_testnet = parse_byron_address(
+            "2cWKMJemoBakF2dV6Xmxq9JHZRrMGghVJ7fGGrJXt6hJfupmXJMAccTT66GsVzXY8yTJc",
+        )
+        .unwrap();
+        assert_eq!(legacy_testnet.protocol_magic, Some(1097911063));
+        assert_eq!(legacy_testnet.network(), Network::Testnet);
+        assert_eq!(
+            legacy_testnet.describe(),
+            "Cardano Byron address (public key), protocol magic 1097911063"
+        );
+
+        let preprod =
+            parse_byron_address("FHnt4NL7yPYA1L1TQC4GfpGbYUQMTBQsjR3nKtUysJrLJKpEBmym6aLnEjKicff")
+                .unwrap();
+        assert_eq!(preprod.protocol_magic, Some(1));
+        let preview =
+            parse_byron_address("FHnt4NL7yPYA1L1TQC4GfpGbYUQMTBQsjR3nKtUysJrLJKpEBmym6aNyicSn4kL")
+                .unwrap();
+        assert_eq!(preview.protocol_magic, Some(2));
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_checksum_and_structure() {
+        // Last character changed: the CRC no longer matches
+        let tampered = format!("{}j", &ICARUS[..ICARUS.len() - 1]);
+        assert!(parse_byron_address(&tampered).is_err());
+
+        // A Solana account is Base58 but not CBOR
+        assert!(parse_byron_address("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM").is_err());
+
+        // Well-formed envelope and CRC around a payload that is not [root, attributes, type]
+        let payload = [0x82, 0x41, 0x00, 0xa0];
+        let mut envelope = vec![0x82, 0xd8, 0x18, 0x44];
+        envelope.extend_from_slice(&payload);
+        envelope.push(0x1a);
+        envelope.extend_from_slice(&crc32::calculate(&payload).to_be_bytes());
+        let address = ::base58::ToBase58::to_base58(envelope.as_slice());
+        assert!(parse_byron_address(&address)
+            .unwrap_err()
+            .to_string()
+            .contains("Not a Byron address structure"));
+    }
+}
@@ -0,0 +1,170 @@
+//! Shelley addresses of payment and stake keys
+//!
+//! A key hash credential is the Blake2b-224 hash of a 32-byte Ed25519
+//! verification key. A payment key alone gives an enterprise address, a
+//! stake key alone a reward address, and the pair a base address.
+
+use super::address::{CardanoAddress, CardanoAddressType, CardanoCredential};
+use crate::registry::Network;
+use crate::shared::crypto::hash::blake2b_224;
+use crate::shared::encoding::hex;
+use crate::Error;
+
+/// Addresses of a payment key, a stake key or both on one network
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardanoAddresses {
+    /// Network of the addresses (network id 1 on mainnet, 0 on testnets)
+    pub network: Network,
+    /// Base address (payment and stake keys)
+    pub base: Option<String>,
+    /// Enterprise address (payment key)
+    pub enterprise: Option<String>,
+    /// Reward address (stake key)
+    pub reward: Option<String>,
+}
+
+/// Key hash credential of a 32-byte Ed25519 verification key
+pub(crate) fn key_hash_credential(public_key: &[u8]) -> Result<CardanoCredential, Error> {
+    if public_key.len() != 32 {
+        return Err(Error::InvalidInput(format!(
+            "Invalid Ed25519 key length: {} bytes (expected 32)",
+            public_key.len()
+        )));
+    }
+    Ok(CardanoCredential::KeyHash(blake2b_224(public_key)))
+}
+
+/// Derive the base, enterprise and reward addresses of hex Ed25519 keys
+///
+/// Pass the payment key, the stake key or both: the base address needs the
+/// pair, the enterprise address the payment key and the reward address the
+/// stake key. Addresses are returned for mainnet, then testnet.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::{cardano_addresses, Network};
+///
+/// let addresses = cardano_addresses(
+///     Some("73fea80d424276ad0978d4fe5310e8bc2d485f5f6bb3bf87612989f112ad5a7d"),
+///     Some("09ab278d49b7b86a055185c474c4942281ddfa05a54684c7e8a6f230625aee57"),
+/// )?;
+/// assert_eq!(addresses[0].network, Network::Mainnet);
+/// assert_eq!(
+///     addresses[0].reward.as_deref(),
+///     Some("stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw")
+/// );
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn cardano_addresses(
+    payment_key: Option<&str>,
+    stake_key: Option<&str>,
+) -> Result<Vec<CardanoAddresses>, Error> {
+    let credential = |key: Option<&str>| {
+        key.map(|key| key_hash_credential(&hex::decode(key).map_err(Error::InvalidInput)?))
+            .transpose()
+    };
+    let payment = credential(payment_key)?;
+    let stake = credential(stake_key)?;
+    if payment.is_none() && stake.is_none() {
+        return Err(Error::InvalidInput(
+            "A payment key or a stake key is required".to_string(),
+        ));
+    }
+
+    [(Network::Mainnet, 1), (Network::Testnet, 0)]
+        .into_iter()
+        .map(|(network, network_id)| {
+            let encode = |address_type, payment, stake| {
+                CardanoAddress {
+                    address_type,
+                    network_id,
+                    payment,
+                    stake,
+                    pointer: None,
+                }
+                .encode()
+            };
+            Ok(CardanoAddresses {
+                network,
+                base: match (payment, stake) {
+                    (Some(_), Some(_)) => Some(encode(CardanoAddressType::Base, payment, stake)?),
+                    _ => None,
+                },
+                enterprise: payment
+                    .map(|payment| encode(CardanoAddressType::Enterprise, Some(payment), None))
+                    .transpose()?,
+                reward: stake
+                    .map(|stake| encode(CardanoAddressType::Reward, None, Some(stake)))
+                    .transpose()?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // CIP-19 test vector keys (addr_vk1w0l2sr... and stake_vk1px4j0r...)
+    const PAYMENT_KEY: &str = "73fea80d424276ad0978d4fe5310e8bc2d485f5f6bb3bf87612989f112ad5a7d";
+    const STAKE_KEY: &str = "09ab278d49b7b86a055185c474c4942281ddfa05a54684c7e8a6f230625aee57";
+
+    #[test]
+    fn test_key_hash_credential() {
+        let credential = key_hash_credential(&::hex::decode(PAYMENT_KEY).unwrap()).unwrap();
+        assert_eq!(
+            ::hex::encode(credential.hash()),
+            "9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e"
+        );
+        assert!(!credential.is_script());
+        assert!(key_hash_credential(&[0u8; 33]).is_err());
+    }
+
+    #[test]
+    fn test_cardano_addresses_from_key_pair() {
+        let addresses = cardano_addresses(Some(PAYMENT_KEY), Some(STAKE_KEY)).unwrap();
+        assert_eq!(
+            addresses,
+            vec![
+                CardanoAddresses {
+                    network: Network::Mainnet,
+                    base: Some("addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x".to_string()),
+                    enterprise: Some("addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8".to_string()),
+                    reward: Some("stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw".to_string()),
+                },
+                CardanoAddresses {
+                    network: Network::Testnet,
+                    base: Some("addr_test1qz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgs68faae".to_string()),
+                    enterprise: Some("addr_test1vz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerspjrlsz".to_string()),
+                    reward: Some("stake_test1uqehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gssrtvn".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cardano_addresses_from_single_key() {
+        let payment = cardano_addresses(Some(PAYMENT_KEY), None).unwrap();
+        assert_eq!(payment[0].base, None);
+        assert_eq!(
+            payment[0].enterprise.as_deref(),
+            Some("addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8")
+        );
+        assert_eq!(payment[0].reward, None);
+
+        let stake = cardano_addresses(None, Some(&format!("0x{}", STAKE_KEY))).unwrap();
+        assert_eq!(stake[1].enterprise, None);
+        assert_eq!(
+            stake[1].reward.as_deref(),
+            Some("stake_test1uqehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gssrtvn")
+        );
+    }
+
+    #[test]
+    fn test_cardano_addresses_rejects_missing_or_invalid_keys() {
+        assert!(cardano_addresses(None, None).is_err());
+        assert!(cardano_addresses(Some("zz"), None).is_err());
+        assert!(cardano_addresses(Some(&PAYMENT_KEY[..62]), Some(STAKE_KEY)).is_err());
+    }
+}
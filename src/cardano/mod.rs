@@ -0,0 +1,17 @@
+//! Cardano addresses
+//!
+//! Shelley addresses (CIP-19) are parsed into their address type, network id,
+//! credentials and stake pointer; Byron addresses into their root, type,
+//! protocol magic and derivation path. Payment and stake keys derive the
+//! base, enterprise and reward addresses they control.
+
+mod address;
+mod byron;
+mod derive;
+
+pub use address::{
+    parse_cardano_address, CardanoAddress, CardanoAddressType, CardanoCredential, CardanoPointer,
+};
+pub use byron::{parse_byron_address, ByronAddress, ByronAddressType};
+pub(crate) use derive::key_hash_credential;
+pub use derive::{cardano_addresses, CardanoAddresses};
@@ -0,0 +1,165 @@
+//! Bitcoin Cash CashAddr ⇄ legacy Base58Check conversion
+
+use crate::registry::{AddressMetadata, EncodingType, Registry};
+use crate::shared::checksum::base58check;
+use crate::shared::encoding::cashaddr;
+use crate::Error;
+
+/// Chain whose formats define the CashAddr prefixes and legacy version bytes
+const CHAIN_ID: &str = "bitcoin_cash";
+
+fn bitcoin_cash_formats() -> Result<&'static [AddressMetadata], Error> {
+    Registry::get()
+        .chains
+        .iter()
+        .find(|chain| chain.id == CHAIN_ID)
+        .map(|chain| chain.address_formats.as_slice())
+        .ok_or_else(|| Error::InvalidInput(format!("Chain {} is not registered", CHAIN_ID)))
+}
+
+/// Convert a CashAddr address (with or without prefix) to its legacy Base58Check form
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::cashaddr_to_legacy;
+///
+/// let legacy = cashaddr_to_legacy("bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a")?;
+/// assert_eq!(legacy, "1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn cashaddr_to_legacy(address: &str) -> Result<String, Error> {
+    let formats = bitcoin_cash_formats()?;
+
+    let source = formats
+        .iter()
+        .filter(|f| f.encoding == EncodingType::CashAddr)
+        .find(|f| f.matches_cashaddr(address))
+        .ok_or_else(|| {
+            Error::InvalidInput(format!("Not a Bitcoin Cash CashAddr address: {}", address))
+        })?;
+    let (_, _, hash) = cashaddr::decode(address, &source.hrps)
+        .map_err(|e| Error::InvalidInput(format!("CashAddr decode error: {}", e)))?;
+    if hash.len() != 20 {
+        return Err(Error::InvalidInput(format!(
+            "{}-byte CashAddr hash has no legacy form",
+            hash.len()
+        )));
+    }
+
+    let version = formats
+        .iter()
+        .filter(|f| f.encoding == EncodingType::Base58Check)
+        .find(|f| f.network == source.network && f.output_type == source.output_type)
+        .and_then(|f| f.version_bytes.first().copied())
+        .ok_or_else(|| {
+            Error::InvalidInput(format!("No legacy format for {:?}", source.output_type))
+        })?;
+
+    Ok(base58check::encode(version, &hash))
+}
+
+/// Convert a legacy Base58Check Bitcoin Cash address to its prefixed CashAddr form
+///
+/// Legacy addresses share version bytes with Bitcoin, so any valid Bitcoin
+/// P2PKH/P2SH address converts.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::legacy_to_cashaddr;
+///
+/// let cashaddr = legacy_to_cashaddr("3CWFddi6m4ndiGyKqzYvsFYagqDLPVMTzC")?;
+/// assert_eq!(cashaddr, "bitcoincash:ppm2qsznhks23z7629mms6s4cwef74vcwvn0h829pq");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn legacy_to_cashaddr(address: &str) -> Result<String, Error> {
+    let formats = bitcoin_cash_formats()?;
+
+    let (version, hash) = base58check::validate(address)?
+        .ok_or_else(|| Error::InvalidInput(format!("Invalid Base58Check address: {}", address)))?;
+    let source = formats
+        .iter()
+        .filter(|f| f.encoding == EncodingType::Base58Check)
+        .find(|f| f.version_bytes.contains(&version))
+        .ok_or_else(|| {
+            Error::InvalidInput(format!(
+                "Version byte {} is not a Bitcoin Cash address type",
+                version
+            ))
+        })?;
+
+    let target = formats
+        .iter()
+        .filter(|f| f.encoding == EncodingType::CashAddr)
+        .find(|f| f.network == source.network && f.output_type == source.output_type)
+        .ok_or_else(|| {
+            Error::InvalidInput(format!("No CashAddr format for {:?}", source.output_type))
+        })?;
+    let prefix = target
+        .hrps
+        .first()
+        .ok_or_else(|| Error::InvalidInput("CashAddr format has no prefix".to_string()))?;
+    let address_type = match target.output_type {
+        Some(crate::registry::OutputType::P2SH) => cashaddr::TYPE_P2SH,
+        _ => cashaddr::TYPE_P2PKH,
+    };
+
+    cashaddr::encode(prefix, address_type, &hash)
+        .map_err(|e| Error::InvalidInput(format!("CashAddr encode error: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cashaddr_to_legacy_p2pkh_and_p2sh() {
+        assert_eq!(
+            cashaddr_to_legacy("bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a").unwrap(),
+            "1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu"
+        );
+        assert_eq!(
+            cashaddr_to_legacy("bitcoincash:ppm2qsznhks23z7629mms6s4cwef74vcwvn0h829pq").unwrap(),
+            "3CWFddi6m4ndiGyKqzYvsFYagqDLPVMTzC"
+        );
+    }
+
+    #[test]
+    fn test_cashaddr_to_legacy_without_prefix() {
+        assert_eq!(
+            cashaddr_to_legacy("qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a").unwrap(),
+            "1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu"
+        );
+    }
+
+    #[test]
+    fn test_cashaddr_to_legacy_testnet() {
+        let legacy =
+            cashaddr_to_legacy("bchtest:qpm2qsznhks23z7629mms6s4cwef74vcwvqcw003ap").unwrap();
+        assert!(legacy.starts_with('m') || legacy.starts_with('n'));
+        assert_eq!(
+            legacy_to_cashaddr(&legacy).unwrap(),
+            "bchtest:qpm2qsznhks23z7629mms6s4cwef74vcwvqcw003ap"
+        );
+    }
+
+    #[test]
+    fn test_legacy_to_cashaddr() {
+        assert_eq!(
+            legacy_to_cashaddr("1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu").unwrap(),
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a"
+        );
+    }
+
+    #[test]
+    fn test_conversion_rejects_other_formats() {
+        assert!(cashaddr_to_legacy("1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu").is_err());
+        assert!(
+            cashaddr_to_legacy("bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6b").is_err()
+        );
+        // Litecoin P2PKH version byte (48) is not a Bitcoin Cash address type
+        assert!(legacy_to_cashaddr("LKDyUEtTR1HXamkiEphisSiBJu6o3ZPE34").is_err());
+        assert!(legacy_to_cashaddr("not an address").is_err());
+    }
+}
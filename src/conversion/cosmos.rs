@@ -0,0 +1,131 @@
+//! Cosmos account ⇄ validator operator address conversion
+//!
+//! An account and its validator operator address share the same 20 bytes and
+//! differ only in the HRP, which the chain metadata declares per role.
+
+use crate::registry::{AddressRole, Registry};
+use crate::shared::encoding::bech32 as bech32_encoding;
+use crate::Error;
+use bech32::Variant;
+
+/// Convert a Cosmos account address to its validator operator address
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::cosmos_account_to_valoper;
+///
+/// let valoper = cosmos_account_to_valoper("cosmos1w508d6qejxtdg4y5r3zarvary0c5xw7k6ah60c")?;
+/// assert_eq!(valoper, "cosmosvaloper1w508d6qejxtdg4y5r3zarvary0c5xw7klfr0rt");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn cosmos_account_to_valoper(address: &str) -> Result<String, Error> {
+    convert_role(
+        address,
+        AddressRole::Account,
+        AddressRole::ValidatorOperator,
+    )
+}
+
+/// Convert a Cosmos validator operator address to the account address that operates it
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::cosmos_valoper_to_account;
+///
+/// let account = cosmos_valoper_to_account("osmovaloper1w508d6qejxtdg4y5r3zarvary0c5xw7kg3vfwd")?;
+/// assert_eq!(account, "osmo1w508d6qejxtdg4y5r3zarvary0c5xw7kjxy2e2");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn cosmos_valoper_to_account(address: &str) -> Result<String, Error> {
+    convert_role(
+        address,
+        AddressRole::ValidatorOperator,
+        AddressRole::Account,
+    )
+}
+
+/// Re-encode the payload of a `from` address under the HRP of the same chain's `to` role
+fn convert_role(address: &str, from: AddressRole, to: AddressRole) -> Result<String, Error> {
+    let (hrp, data, variant) = bech32_encoding::decode(address)
+        .map_err(|e| Error::InvalidInput(format!("Bech32 decode error: {}", e)))?;
+    if variant != Variant::Bech32 {
+        return Err(Error::InvalidInput(format!(
+            "Cosmos addresses use Bech32, not Bech32m: {}",
+            address
+        )));
+    }
+
+    let chain = Registry::get()
+        .chains
+        .iter()
+        .find(|chain| {
+            chain
+                .address_formats
+                .iter()
+                .any(|f| f.role == Some(from) && f.hrps.contains(&hrp))
+        })
+        .ok_or_else(|| {
+            Error::InvalidInput(format!(
+                "{} is not a Cosmos {} address prefix",
+                hrp,
+                from.name()
+            ))
+        })?;
+    let target = chain
+        .address_formats
+        .iter()
+        .find(|f| f.role == Some(to))
+        .and_then(|f| f.hrps.first())
+        .ok_or_else(|| {
+            Error::InvalidInput(format!("Chain {} has no {} prefix", chain.id, to.name()))
+        })?;
+
+    bech32_encoding::encode(target, &data, Variant::Bech32)
+        .map_err(|e| Error::InvalidInput(format!("Bech32 encoding error: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cosmos_account_to_valoper() {
+        assert_eq!(
+            cosmos_account_to_valoper("cosmos1w508d6qejxtdg4y5r3zarvary0c5xw7k6ah60c").unwrap(),
+            "cosmosvaloper1w508d6qejxtdg4y5r3zarvary0c5xw7klfr0rt"
+        );
+        assert_eq!(
+            cosmos_account_to_valoper("osmo1w508d6qejxtdg4y5r3zarvary0c5xw7kjxy2e2").unwrap(),
+            "osmovaloper1w508d6qejxtdg4y5r3zarvary0c5xw7kg3vfwd"
+        );
+    }
+
+    #[test]
+    fn test_cosmos_valoper_to_account_round_trip() {
+        let account = "cosmos1hkfq3zahaqkkzx5mjnamwjsfpq2jk7z0emlrvp";
+        let valoper = cosmos_account_to_valoper(account).unwrap();
+        assert!(valoper.starts_with("cosmosvaloper1"));
+        assert_eq!(cosmos_valoper_to_account(&valoper).unwrap(), account);
+    }
+
+    #[test]
+    fn test_cosmos_conversion_rejects_other_roles() {
+        // Already a valoper address
+        assert!(
+            cosmos_account_to_valoper("cosmosvaloper1w508d6qejxtdg4y5r3zarvary0c5xw7klfr0rt")
+                .is_err()
+        );
+        // Consensus addresses hash the Ed25519 key and have no account form
+        assert!(
+            cosmos_valoper_to_account("cosmosvalcons1y8lrrhap2j3xzcntlp2qgm7jyudhhm2tfeslut")
+                .is_err()
+        );
+        // Bitcoin SegWit shares Bech32 but no Cosmos role
+        assert!(cosmos_account_to_valoper("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").is_err());
+        assert!(
+            cosmos_account_to_valoper("cosmos1w508d6qejxtdg4y5r3zarvary0c5xw7k6ah60d").is_err()
+        );
+    }
+}
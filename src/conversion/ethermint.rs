@@ -0,0 +1,138 @@
+//! Ethermint Bech32 ⇄ EVM `0x` address conversion
+//!
+//! Chains using `eth_secp256k1` keys (Evmos, Injective, Cronos) encode the
+//! 20 EVM address bytes in Bech32 under the account HRP, so both forms name
+//! the same account.
+
+use crate::registry::{AddressMetadata, AddressRole, Registry};
+use crate::shared::checksum::eip55;
+use crate::shared::encoding::bech32 as bech32_encoding;
+use crate::Error;
+use bech32::{u5, Variant};
+
+/// Pipeline of chains whose accounts are EVM address bytes
+const PIPELINE: &str = "ethermint";
+
+/// Account format of a registered Ethermint chain
+fn account_format(chain_id: &str) -> Option<&'static AddressMetadata> {
+    let registry = Registry::get();
+    if registry.get_chain_config(chain_id)?.address_pipeline != PIPELINE {
+        return None;
+    }
+    registry
+        .chains
+        .iter()
+        .find(|chain| chain.id == chain_id)?
+        .address_formats
+        .iter()
+        .find(|f| f.role == Some(AddressRole::Account))
+}
+
+/// Whether a chain derives its Bech32 accounts from EVM address bytes
+pub(crate) fn is_ethermint_chain(chain_id: &str) -> bool {
+    account_format(chain_id).is_some()
+}
+
+/// Convert an Ethermint Bech32 account (`evmos1...`, `inj1...`) to its EIP-55 `0x` address
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::ethermint_to_evm;
+///
+/// let evm = ethermint_to_evm("inj1nk9x9ajk4rgkzhqjjn7hr6w0k0jg2kj0knl55v")?;
+/// assert_eq!(evm, "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn ethermint_to_evm(address: &str) -> Result<String, Error> {
+    let (hrp, data, variant) = bech32_encoding::decode(address)
+        .map_err(|e| Error::InvalidInput(format!("Bech32 decode error: {}", e)))?;
+    let is_account_hrp = Registry::get()
+        .chains
+        .iter()
+        .any(|chain| account_format(&chain.id).is_some_and(|format| format.hrps.contains(&hrp)));
+    if !is_account_hrp || variant != Variant::Bech32 {
+        return Err(Error::InvalidInput(format!(
+            "Not an Ethermint account address: {}",
+            address
+        )));
+    }
+
+    let u5_bytes: Vec<u8> = data.iter().map(|u5| u8::from(*u5)).collect();
+    let bytes = bech32_encoding::convert_bits(&u5_bytes, 5, 8, false)
+        .map_err(|e| Error::InvalidInput(format!("Bech32 conversion error: {}", e)))?;
+    if bytes.len() != 20 {
+        return Err(Error::InvalidInput(format!(
+            "{}-byte account has no EVM form",
+            bytes.len()
+        )));
+    }
+    eip55::normalize(&format!("0x{}", ::hex::encode(bytes)))
+}
+
+/// Convert an EVM `0x` address to the Bech32 account of an Ethermint chain
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::evm_to_ethermint;
+///
+/// let evmos = evm_to_ethermint("0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F", "evmos")?;
+/// assert_eq!(evmos, "evmos1nk9x9ajk4rgkzhqjjn7hr6w0k0jg2kj07me7uu");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn evm_to_ethermint(address: &str, chain_id: &str) -> Result<String, Error> {
+    let hrp = account_format(chain_id)
+        .and_then(|format| format.hrps.first())
+        .ok_or_else(|| Error::InvalidInput(format!("{} is not an Ethermint chain", chain_id)))?;
+    let hex_part = address
+        .strip_prefix("0x")
+        .filter(|hex_part| hex_part.len() == 40)
+        .ok_or_else(|| Error::InvalidInput(format!("Not an EVM address: {}", address)))?;
+    let bytes =
+        ::hex::decode(hex_part).map_err(|e| Error::InvalidInput(format!("Invalid hex: {}", e)))?;
+
+    let data = bech32_encoding::convert_bits(&bytes, 8, 5, true)
+        .map_err(|e| Error::InvalidInput(format!("Bech32 conversion error: {}", e)))?;
+    let data_u5: Vec<u5> = bech32_encoding::bytes_to_u5(&data);
+    bech32_encoding::encode(hrp, &data_u5, Variant::Bech32)
+        .map_err(|e| Error::InvalidInput(format!("Bech32 encoding error: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVM: &str = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+
+    #[test]
+    fn test_evm_to_ethermint() {
+        assert_eq!(
+            evm_to_ethermint(EVM, "evmos").unwrap(),
+            "evmos1nk9x9ajk4rgkzhqjjn7hr6w0k0jg2kj07me7uu"
+        );
+        assert_eq!(
+            evm_to_ethermint(&EVM.to_lowercase(), "cronos").unwrap(),
+            "crc1nk9x9ajk4rgkzhqjjn7hr6w0k0jg2kj0tyjx52"
+        );
+    }
+
+    #[test]
+    fn test_ethermint_to_evm_round_trip() {
+        for chain in ["evmos", "injective", "cronos"] {
+            let bech32 = evm_to_ethermint(EVM, chain).unwrap();
+            assert_eq!(ethermint_to_evm(&bech32).unwrap(), EVM);
+        }
+    }
+
+    #[test]
+    fn test_ethermint_conversion_rejects_other_chains() {
+        assert!(evm_to_ethermint(EVM, "ethereum").is_err());
+        assert!(evm_to_ethermint(EVM, "cosmos_hub").is_err());
+        assert!(evm_to_ethermint("0x1234", "evmos").is_err());
+        // Cosmos accounts hash the compressed key, not the EVM bytes
+        assert!(ethermint_to_evm("cosmos1w508d6qejxtdg4y5r3zarvary0c5xw7k6ah60c").is_err());
+        // Validator operator addresses are not accounts
+        assert!(ethermint_to_evm("evmosvaloper1nk9x9ajk4rgkzhqjjn7hr6w0k0jg2kj0n4kwap").is_err());
+    }
+}
@@ -0,0 +1,17 @@
+//! Conversion between equivalent address forms
+//!
+//! Conversions are driven by the chain metadata in the registry: the target
+//! format is the one declaring the same network and output type as the source,
+//! or for Cosmos chains the same chain under another address role.
+
+mod bitcoin_cash;
+mod cosmos;
+mod ethermint;
+mod substrate;
+
+pub use bitcoin_cash::{cashaddr_to_legacy, legacy_to_cashaddr};
+pub use cosmos::{cosmos_account_to_valoper, cosmos_valoper_to_account};
+pub(crate) use ethermint::is_ethermint_chain;
+pub use ethermint::{ethermint_to_evm, evm_to_ethermint};
+pub(crate) use substrate::is_account_id20_chain;
+pub use substrate::{evm_to_ss58, ss58_to_evm};
@@ -0,0 +1,118 @@
+//! `AccountId20` SS58 ⇄ EVM `0x` address conversion
+//!
+//! Substrate chains with 20-byte `AccountId20` accounts (Moonbeam, Moonriver,
+//! Darwinia) use EVM addresses as account ids, so the SS58 form under the
+//! chain's prefix and the `0x` form name the same account.
+
+use crate::registry::{AddressMetadata, EncodingType, Registry};
+use crate::shared::checksum::eip55;
+use crate::shared::encoding::ss58;
+use crate::Error;
+
+/// SS58 format of a registered `AccountId20` chain (an EVM chain with an SS58 prefix)
+fn account_id20_format(chain_id: &str) -> Option<&'static AddressMetadata> {
+    let registry = Registry::get();
+    if registry.get_chain_config(chain_id)?.address_pipeline != "evm" {
+        return None;
+    }
+    registry
+        .chains
+        .iter()
+        .find(|chain| chain.id == chain_id)?
+        .address_formats
+        .iter()
+        .find(|f| f.encoding == EncodingType::SS58 && f.ss58_prefix.is_some())
+}
+
+/// Whether a chain's accounts are 20-byte EVM addresses with an SS58 form
+pub(crate) fn is_account_id20_chain(chain_id: &str) -> bool {
+    account_id20_format(chain_id).is_some()
+}
+
+/// Convert an SS58 `AccountId20` address (Moonbeam) to its EIP-55 `0x` address
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::ss58_to_evm;
+///
+/// let evm = ss58_to_evm("6voedjU2oqLPWe1E9HrSPXJ7kw6GCAbEK")?;
+/// assert_eq!(evm, "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn ss58_to_evm(address: &str) -> Result<String, Error> {
+    let (_, account_id) = ss58::decode(address)
+        .map_err(|e| Error::InvalidInput(format!("SS58 decode error: {}", e)))?;
+    if account_id.len() != 20 {
+        return Err(Error::InvalidInput(format!(
+            "{}-byte account has no EVM form",
+            account_id.len()
+        )));
+    }
+    eip55::normalize(&format!("0x{}", ::hex::encode(account_id)))
+}
+
+/// Convert an EVM `0x` address to the SS58 form of an `AccountId20` chain
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::evm_to_ss58;
+///
+/// let moonbeam = evm_to_ss58("0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F", "moonbeam")?;
+/// assert_eq!(moonbeam, "6voedjU2oqLPWe1E9HrSPXJ7kw6GCAbEK");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn evm_to_ss58(address: &str, chain_id: &str) -> Result<String, Error> {
+    let prefix = account_id20_format(chain_id)
+        .and_then(|format| format.ss58_prefix)
+        .ok_or_else(|| Error::InvalidInput(format!("{} has no AccountId20 accounts", chain_id)))?;
+    let hex_part = address
+        .strip_prefix("0x")
+        .filter(|hex_part| hex_part.len() == 40)
+        .ok_or_else(|| Error::InvalidInput(format!("Not an EVM address: {}", address)))?;
+    let bytes =
+        ::hex::decode(hex_part).map_err(|e| Error::InvalidInput(format!("Invalid hex: {}", e)))?;
+
+    ss58::encode(prefix, &bytes)
+        .map_err(|e| Error::InvalidInput(format!("SS58 encoding error: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVM: &str = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+
+    #[test]
+    fn test_evm_to_ss58() {
+        assert_eq!(
+            evm_to_ss58(EVM, "moonbeam").unwrap(),
+            "6voedjU2oqLPWe1E9HrSPXJ7kw6GCAbEK"
+        );
+        assert_eq!(
+            evm_to_ss58(&EVM.to_lowercase(), "darwinia").unwrap(),
+            "PJRNTSf8KHYtBzcT44fKQWaxqLkggZn"
+        );
+    }
+
+    #[test]
+    fn test_ss58_to_evm_round_trip() {
+        for chain in ["moonbeam", "moonriver", "darwinia"] {
+            assert!(is_account_id20_chain(chain));
+            let ss58 = evm_to_ss58(EVM, chain).unwrap();
+            assert_eq!(ss58_to_evm(&ss58).unwrap(), EVM);
+        }
+    }
+
+    #[test]
+    fn test_account_id20_conversion_rejects_other_chains() {
+        assert!(!is_account_id20_chain("ethereum"));
+        assert!(!is_account_id20_chain("polkadot"));
+        assert!(evm_to_ss58(EVM, "ethereum").is_err());
+        assert!(evm_to_ss58(EVM, "polkadot").is_err());
+        assert!(evm_to_ss58("0x1234", "moonbeam").is_err());
+        // 32-byte accounts have no EVM form
+        assert!(ss58_to_evm("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5").is_err());
+    }
+}
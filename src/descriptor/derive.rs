@@ -0,0 +1,227 @@
+//! Address derivation for parsed descriptors
+
+use crate::descriptor::parser::{Descriptor, MultisigWrapper};
+use crate::registry::{AddressMetadata, Network, OutputType};
+use crate::script::{
+    check_redeem_script_size, check_witness_script_size, encode_address, find_format,
+    multisig_script, ScriptType,
+};
+use crate::shared::crypto::hash::{hash160, sha256};
+use crate::shared::crypto::secp256k1;
+use crate::Error;
+
+impl Descriptor {
+    /// Output script type and the hash or witness program it commits to
+    fn output(&self) -> Result<(ScriptType, Vec<u8>), Error> {
+        match self {
+            Descriptor::Pkh(key) => Ok((ScriptType::P2PKH, hash160(key).to_vec())),
+            Descriptor::Wpkh(key) => Ok((ScriptType::P2WPKH, hash160(key).to_vec())),
+            Descriptor::ShWpkh(key) => {
+                let redeem = [&[0x00, 0x14], &hash160(key)[..]].concat();
+                Ok((ScriptType::P2SH, hash160(&redeem).to_vec()))
+            }
+            Descriptor::Tr(internal_key) => Ok((
+                ScriptType::P2TR,
+                secp256k1::taproot_output_key(internal_key)?.to_vec(),
+            )),
+            Descriptor::Multisig {
+                wrapper,
+                threshold,
+                keys,
+                sorted,
+            } => {
+                let script = multisig_script(*threshold, keys, *sorted)?;
+                match wrapper {
+                    MultisigWrapper::Sh => {
+                        check_redeem_script_size(&script)?;
+                        Ok((ScriptType::P2SH, hash160(&script).to_vec()))
+                    }
+                    MultisigWrapper::Wsh => {
+                        check_witness_script_size(&script)?;
+                        Ok((ScriptType::P2WSH, sha256(&script).to_vec()))
+                    }
+                    MultisigWrapper::ShWsh => {
+                        check_witness_script_size(&script)?;
+                        let nested = [&[0x00, 0x20], &sha256(&script)[..]].concat();
+                        Ok((ScriptType::P2SH, hash160(&nested).to_vec()))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Whether the descriptor nests a segwit v0 program inside P2SH
+    fn is_nested_segwit(&self) -> bool {
+        matches!(
+            self,
+            Descriptor::ShWpkh(_)
+                | Descriptor::Multisig {
+                    wrapper: MultisigWrapper::ShWsh,
+                    ..
+                }
+        )
+    }
+
+    /// Derive the address on a chain and network
+    ///
+    /// Returns `Ok(None)` when the chain has no format for the output type,
+    /// or cannot spend nested segwit outputs because it has no segwit at all.
+    pub(crate) fn address(
+        &self,
+        chain: &str,
+        network: Network,
+    ) -> Result<Option<(&'static AddressMetadata, String)>, Error> {
+        let (script_type, payload) = self.output()?;
+        let output_type = script_type
+            .output_type()
+            .ok_or_else(|| Error::InvalidInput(format!("{:?} has no address", script_type)))?;
+        if self.is_nested_segwit() && find_format(chain, network, OutputType::WitnessV0)?.is_none()
+        {
+            return Ok(None);
+        }
+        match find_format(chain, network, output_type)? {
+            Some(format) => Ok(Some((
+                format,
+                encode_address(format, script_type, &payload)?,
+            ))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::descriptor::parser::parse_descriptor;
+    use crate::registry::Network;
+    use crate::script::multisig_addresses;
+    use crate::shared::crypto::secp256k1;
+
+    fn address(descriptor: &str, chain: &str, network: Network) -> Option<String> {
+        parse_descriptor(descriptor, 0)
+            .unwrap()
+            .descriptor
+            .address(chain, network)
+            .unwrap()
+            .map(|(_, address)| address)
+    }
+
+    #[test]
+    fn test_single_key_addresses() {
+        assert_eq!(
+            address(
+                "pkh(02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5)",
+                "bitcoin",
+                Network::Mainnet
+            )
+            .as_deref(),
+            Some("1cMh228HTCiwS8ZsaakH8A8wze1JR5ZsP")
+        );
+        assert_eq!(
+            address(
+                "wpkh(02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9)",
+                "bitcoin",
+                Network::Mainnet
+            )
+            .as_deref(),
+            Some("bc1q0ht9tyks4vh7p5p904t340cr9nvahy7u3re7zg")
+        );
+        assert_eq!(
+            address(
+                "sh(wpkh(03fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a1460297556))",
+                "bitcoin",
+                Network::Mainnet
+            )
+            .as_deref(),
+            Some("3LKyvRN6SmYXGBNn8fcQvYxW9MGKtwcinN")
+        );
+        // BIP-86 first receiving address
+        assert_eq!(
+            address(
+                "tr(cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115)",
+                "bitcoin",
+                Network::Mainnet
+            )
+            .as_deref(),
+            Some("bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr")
+        );
+    }
+
+    #[test]
+    fn test_multisig_addresses() {
+        let keys = "02ff12471208c14bd580709cb2358d98975247d8765f92bc25eab3b2763ed605f8,02fe6f0a5a297eb38c391581c4413e084773ea23954d93f7753db7dc0adc188b2f";
+        assert_eq!(
+            address(
+                &format!("sh(sortedmulti(2,{}))", keys),
+                "bitcoin",
+                Network::Mainnet
+            )
+            .as_deref(),
+            Some("39bgKC7RFbpoCRbtD5KEdkYKtNyhpsNa3Z")
+        );
+        assert_eq!(
+            address(
+                &format!("sh(wsh(sortedmulti(2,{})))", keys),
+                "bitcoin",
+                Network::Mainnet
+            )
+            .as_deref(),
+            Some("3BBLivaThSP3C31jzmQJiMWBM7BLndaWfh")
+        );
+        assert_eq!(
+            address(
+                &format!("wsh(sortedmulti(2,{}))", keys),
+                "bitcoin",
+                Network::Testnet
+            )
+            .as_deref(),
+            Some("tb1qknwt9mhqpd7hrjrvpqz57zjqk28xlp2h90te6v22en0m3uctnamsxfkmzk")
+        );
+    }
+
+    #[test]
+    fn test_chains_without_output_type() {
+        // Dogecoin has P2SH but no segwit, so nested segwit is not derived
+        let descriptor =
+            "sh(wpkh(03fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a1460297556))";
+        assert_eq!(address(descriptor, "dogecoin", Network::Mainnet), None);
+        assert_eq!(
+            address(
+                "tr(cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115)",
+                "dogecoin",
+                Network::Mainnet
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_multisig_script_size_matches_multisig_addresses() {
+        // 1-of-16 with compressed keys: 547 bytes, over the P2SH limit only
+        let keys: Vec<String> = (1..=16u8)
+            .map(|i| {
+                let mut secret = [0u8; 32];
+                secret[31] = i;
+                ::hex::encode(secp256k1::public_key_from_secret(&secret, true).unwrap())
+            })
+            .collect();
+        let key_refs: Vec<&str> = keys.iter().map(String::as_str).collect();
+        let expected =
+            multisig_addresses(1, &key_refs, false, "bitcoin", Network::Mainnet).unwrap();
+
+        let sh = parse_descriptor(&format!("sh(multi(1,{}))", keys.join(",")), 0).unwrap();
+        let error = sh
+            .descriptor
+            .address("bitcoin", Network::Mainnet)
+            .unwrap_err();
+        assert!(error.to_string().contains("limited to 520"));
+        assert_eq!(expected.p2sh, None);
+
+        let wsh = format!("wsh(multi(1,{}))", keys.join(","));
+        assert_eq!(address(&wsh, "bitcoin", Network::Mainnet), expected.p2wsh);
+        let sh_wsh = format!("sh(wsh(multi(1,{})))", keys.join(","));
+        assert_eq!(
+            address(&sh_wsh, "bitcoin", Network::Mainnet),
+            expected.p2sh_p2wsh
+        );
+    }
+}
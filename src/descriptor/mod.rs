@@ -0,0 +1,128 @@
+//! Bitcoin output descriptors
+//!
+//! Parsing of `pkh`, `wpkh`, `sh(wpkh)`, `tr` and `multi`/`sortedmulti`
+//! descriptors with the BIP-380 checksum, and derivation of their addresses
+//! on Bitcoin-family chains, including ranged descriptors over extended keys.
+
+mod derive;
+mod parser;
+
+use crate::registry::Network;
+use crate::Error;
+
+pub(crate) use parser::{looks_like_descriptor, parse_descriptor, Descriptor};
+
+/// Return a descriptor with its BIP-380 checksum appended
+///
+/// An existing checksum is replaced.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::descriptor_with_checksum;
+///
+/// assert_eq!(descriptor_with_checksum("raw(deadbeef)")?, "raw(deadbeef)#89f8spxm");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn descriptor_with_checksum(descriptor: &str) -> Result<String, Error> {
+    parser::with_checksum(descriptor)
+}
+
+/// Derive the address of a descriptor on a chain and network
+///
+/// Keys are hex public keys or extended public keys with unhardened
+/// derivation steps (`xpub.../0/1`). Ranged descriptors, whose extended keys
+/// end in `/*`, are rejected: use [`descriptor_address_at`] to pick the index.
+/// A `#checksum` suffix is optional but must be valid when present.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::{descriptor_address, Network};
+///
+/// let address = descriptor_address(
+///     "wpkh(02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9)#8zl0zxma",
+///     "bitcoin",
+///     Network::Mainnet,
+/// )?;
+/// assert_eq!(address, "bc1q0ht9tyks4vh7p5p904t340cr9nvahy7u3re7zg");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn descriptor_address(
+    descriptor: &str,
+    chain: &str,
+    network: Network,
+) -> Result<String, Error> {
+    let parsed = parse_descriptor(descriptor, 0)?;
+    if parsed.ranged {
+        return Err(Error::InvalidInput(
+            "Ranged descriptor (key ending in /*) needs an address index, use descriptor_address_at"
+                .to_string(),
+        ));
+    }
+    address(&parsed.descriptor, chain, network)
+}
+
+/// Derive the address at `index` of a ranged descriptor on a chain and network
+///
+/// The `*` wildcard of each extended key is replaced with `index`;
+/// descriptors without a wildcard give the same address at every index.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::{descriptor_address_at, Network};
+///
+/// // BIP-84 account 0, second receive address
+/// let address = descriptor_address_at(
+///     "wpkh(zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs/0/*)",
+///     "bitcoin",
+///     Network::Mainnet,
+///     1,
+/// )?;
+/// assert_eq!(address, "bc1qnjg0jd8228aq7egyzacy8cys3knf9xvrerkf9g");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn descriptor_address_at(
+    descriptor: &str,
+    chain: &str,
+    network: Network,
+    index: u32,
+) -> Result<String, Error> {
+    address(
+        &parse_descriptor(descriptor, index)?.descriptor,
+        chain,
+        network,
+    )
+}
+
+fn address(descriptor: &Descriptor, chain: &str, network: Network) -> Result<String, Error> {
+    descriptor
+        .address(chain, network)?
+        .map(|(_, address)| address)
+        .ok_or_else(|| {
+            Error::InvalidInput(format!(
+                "{} has no address format for {} on {:?}",
+                chain,
+                descriptor.shape(),
+                network
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RANGED: &str = "wpkh(zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs/0/*)";
+
+    #[test]
+    fn test_descriptor_address_at_ranged() {
+        assert_eq!(
+            descriptor_address_at(RANGED, "bitcoin", Network::Mainnet, 0).unwrap(),
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+        );
+        let error = descriptor_address(RANGED, "bitcoin", Network::Mainnet).unwrap_err();
+        assert!(error.to_string().contains("descriptor_address_at"));
+    }
+}
@@ -0,0 +1,458 @@
+//! Descriptor parsing
+//!
+//! Supports the single-key and multisig descriptors of BIP-381 to BIP-386
+//! with hex public keys or extended public keys followed by unhardened
+//! derivation steps, the last of which may be the `*` wildcard of a ranged
+//! descriptor. Key origins (`[fingerprint/path]`) are accepted and ignored.
+
+use crate::bip32::{looks_like_extended_key, ExtendedPublicKey, HARDENED};
+use crate::input::redact_input;
+use crate::private_key::{parse_private_key, PrivateKey};
+use crate::shared::checksum::descriptor as descriptor_checksum;
+use crate::shared::crypto::secp256k1;
+use crate::shared::encoding::hex;
+use crate::Error;
+
+/// Descriptor functions recognized as top-level script expressions
+const SCRIPT_FUNCTIONS: &[&str] = &[
+    "pk",
+    "pkh",
+    "wpkh",
+    "sh",
+    "wsh",
+    "tr",
+    "multi",
+    "sortedmulti",
+    "combo",
+    "raw",
+    "rawtr",
+    "addr",
+];
+
+/// Script wrapping a multisig script
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MultisigWrapper {
+    /// `sh(multi(...))`
+    Sh,
+    /// `wsh(multi(...))`
+    Wsh,
+    /// `sh(wsh(multi(...)))`
+    ShWsh,
+}
+
+/// A parsed output descriptor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Descriptor {
+    /// `pkh(KEY)`
+    Pkh(Vec<u8>),
+    /// `wpkh(KEY)`
+    Wpkh(Vec<u8>),
+    /// `sh(wpkh(KEY))`
+    ShWpkh(Vec<u8>),
+    /// `tr(KEY)` without a script tree, holding the x-only internal key
+    Tr(Vec<u8>),
+    /// `multi`/`sortedmulti` inside `sh`, `wsh` or `sh(wsh)`
+    Multisig {
+        wrapper: MultisigWrapper,
+        threshold: usize,
+        keys: Vec<Vec<u8>>,
+        sorted: bool,
+    },
+}
+
+impl Descriptor {
+    /// Script expression shape used in reasoning strings, e.g. `sh(wpkh())`
+    pub(crate) fn shape(&self) -> String {
+        let multi = |sorted: bool| if sorted { "sortedmulti()" } else { "multi()" };
+        match self {
+            Descriptor::Pkh(_) => "pkh()".to_string(),
+            Descriptor::Wpkh(_) => "wpkh()".to_string(),
+            Descriptor::ShWpkh(_) => "sh(wpkh())".to_string(),
+            Descriptor::Tr(_) => "tr()".to_string(),
+            Descriptor::Multisig {
+                wrapper, sorted, ..
+            } => match wrapper {
+                MultisigWrapper::Sh => format!("sh({})", multi(*sorted)),
+                MultisigWrapper::Wsh => format!("wsh({})", multi(*sorted)),
+                MultisigWrapper::ShWsh => format!("sh(wsh({}))", multi(*sorted)),
+            },
+        }
+    }
+}
+
+/// A descriptor together with the checksum it was given with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParsedDescriptor {
+    pub descriptor: Descriptor,
+    /// Whether a `#checksum` suffix was present (it is always valid once parsed)
+    pub has_checksum: bool,
+    /// Whether a key ends in the `*` wildcard, resolved to the index given to the parser
+    pub ranged: bool,
+}
+
+/// Check whether an input is shaped like a descriptor (`func(...)`, optional `#checksum`)
+pub(crate) fn looks_like_descriptor(input: &str) -> bool {
+    let body = input.split_once('#').map_or(input, |(body, _)| body);
+    body.ends_with(')')
+        && body
+            .split_once('(')
+            .is_some_and(|(name, _)| SCRIPT_FUNCTIONS.contains(&name))
+}
+
+/// Parse a descriptor, verifying its checksum if one is present
+///
+/// `index` replaces the `*` wildcard of ranged extended keys.
+pub(crate) fn parse_descriptor(input: &str, index: u32) -> Result<ParsedDescriptor, Error> {
+    let input = input.trim();
+    let (body, checksum) = match input.split_once('#') {
+        Some((body, checksum)) => (body, Some(checksum)),
+        None => (input, None),
+    };
+    if let Some(checksum) = checksum {
+        if !descriptor_checksum::validate(body, checksum) {
+            return Err(Error::InvalidInput(format!(
+                "Invalid descriptor checksum: {}",
+                checksum
+            )));
+        }
+    }
+
+    Ok(ParsedDescriptor {
+        descriptor: parse_top_level(body, index)?,
+        has_checksum: checksum.is_some(),
+        ranged: body.contains('*'),
+    })
+}
+
+/// Add a BIP-380 checksum to a descriptor, replacing any existing one
+pub(crate) fn with_checksum(input: &str) -> Result<String, Error> {
+    let body = input
+        .trim()
+        .split_once('#')
+        .map_or(input.trim(), |(b, _)| b);
+    let checksum = descriptor_checksum::calculate(body)
+        .ok_or_else(|| Error::InvalidInput("Descriptor contains invalid characters".to_string()))?;
+    Ok(format!("{}#{}", body, checksum))
+}
+
+/// Split `name(args)` into its name and arguments
+fn split_call(expression: &str) -> Result<(&str, &str), Error> {
+    expression
+        .strip_suffix(')')
+        .and_then(|e| e.split_once('('))
+        .ok_or_else(|| {
+            Error::InvalidInput(format!(
+                "Malformed descriptor expression: {}",
+                redact_input(expression)
+            ))
+        })
+}
+
+fn unsupported(name: &str, context: &str) -> Error {
+    Error::InvalidInput(format!("{}() is not supported {}", name, context))
+}
+
+fn parse_top_level(expression: &str, index: u32) -> Result<Descriptor, Error> {
+    let (name, args) = split_call(expression)?;
+    match name {
+        "pkh" => Ok(Descriptor::Pkh(parse_key(args, KeyContext::Legacy, index)?)),
+        "wpkh" => Ok(Descriptor::Wpkh(parse_key(
+            args,
+            KeyContext::Segwit,
+            index,
+        )?)),
+        "tr" => {
+            if args.contains(',') {
+                return Err(Error::InvalidInput(
+                    "tr() descriptors with script trees are not supported".to_string(),
+                ));
+            }
+            Ok(Descriptor::Tr(parse_key(args, KeyContext::Taproot, index)?))
+        }
+        "sh" => {
+            let (inner, inner_args) = split_call(args)?;
+            match inner {
+                "wpkh" => Ok(Descriptor::ShWpkh(parse_key(
+                    inner_args,
+                    KeyContext::Segwit,
+                    index,
+                )?)),
+                "wsh" => parse_multisig(inner_args, MultisigWrapper::ShWsh, index),
+                "multi" | "sortedmulti" => parse_multisig(args, MultisigWrapper::Sh, index),
+                other => Err(unsupported(other, "inside sh()")),
+            }
+        }
+        "wsh" => parse_multisig(args, MultisigWrapper::Wsh, index),
+        other => Err(unsupported(other, "as a top-level descriptor")),
+    }
+}
+
+fn parse_multisig(
+    expression: &str,
+    wrapper: MultisigWrapper,
+    index: u32,
+) -> Result<Descriptor, Error> {
+    let (name, args) = split_call(expression)?;
+    let sorted = match name {
+        "multi" => false,
+        "sortedmulti" => true,
+        other => return Err(unsupported(other, "inside sh()/wsh()")),
+    };
+    let mut args = args.split(',');
+    let threshold = args
+        .next()
+        .and_then(|t| t.parse::<usize>().ok())
+        .ok_or_else(|| Error::InvalidInput(format!("Invalid {}() threshold", name)))?;
+    let context = match wrapper {
+        MultisigWrapper::Sh => KeyContext::Legacy,
+        MultisigWrapper::Wsh | MultisigWrapper::ShWsh => KeyContext::Segwit,
+    };
+    let keys = args
+        .map(|key| parse_key(key, context, index))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Descriptor::Multisig {
+        wrapper,
+        threshold,
+        keys,
+        sorted,
+    })
+}
+
+/// Script context a key appears in, which restricts its serialization
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyContext {
+    /// Compressed or uncompressed keys
+    Legacy,
+    /// Compressed keys only
+    Segwit,
+    /// x-only or compressed keys, returned as x-only
+    Taproot,
+}
+
+/// Parse a key expression: optional `[origin]` followed by a hex public key
+/// or an extended public key with its derivation steps
+fn parse_key(expression: &str, context: KeyContext, index: u32) -> Result<Vec<u8>, Error> {
+    let key = match expression.strip_prefix('[') {
+        Some(rest) => {
+            rest.split_once(']')
+                .ok_or_else(|| {
+                    Error::InvalidInput(format!(
+                        "Unterminated key origin: {}",
+                        redact_input(expression)
+                    ))
+                })?
+                .1
+        }
+        None => expression,
+    };
+    let bytes = match extended_key(key, index)? {
+        Some(public_key) => public_key.to_vec(),
+        None => hex::decode(key).map_err(|_| match parse_private_key(key) {
+            Some(PrivateKey::Wif { .. }) => {
+                Error::InvalidInput("WIF private keys are not supported in descriptors".to_string())
+            }
+            _ => Error::InvalidInput(format!(
+                "Unsupported descriptor key (expected a hex or extended public key): {}",
+                key_shape(key)
+            )),
+        })?,
+    };
+
+    let valid = match (context, bytes.len()) {
+        (KeyContext::Taproot, 32) => return x_only_key(&bytes, key),
+        (KeyContext::Taproot, 33) => {
+            return if secp256k1::is_valid_public_key(&bytes) {
+                Ok(bytes[1..].to_vec())
+            } else {
+                Err(invalid_key(key))
+            }
+        }
+        (KeyContext::Legacy, 33 | 65) | (KeyContext::Segwit, 33) => {
+            secp256k1::is_valid_public_key(&bytes)
+        }
+        (KeyContext::Segwit, 65) => {
+            return Err(Error::InvalidInput(format!(
+                "Uncompressed keys are not allowed in segwit descriptors: {}",
+                key_shape(key)
+            )))
+        }
+        _ => false,
+    };
+    if valid {
+        Ok(bytes)
+    } else {
+        Err(invalid_key(key))
+    }
+}
+
+/// Derive the child public key of `xpub.../0/*`-style keys
+///
+/// Returns `Ok(None)` when the key is not an extended key. Steps must be
+/// unhardened; a final `*` is replaced with `index`.
+fn extended_key(key: &str, index: u32) -> Result<Option<[u8; 33]>, Error> {
+    let mut steps = key.split('/');
+    let encoded = steps.next().unwrap_or_default();
+    if !looks_like_extended_key(encoded) {
+        return Ok(None);
+    }
+    let steps: Vec<&str> = steps.collect();
+    let path = steps
+        .iter()
+        .enumerate()
+        .map(|(position, &step)| match step {
+            "*" if position + 1 == steps.len() => Ok(index),
+            _ => step
+                .parse::<u32>()
+                .ok()
+                .filter(|&step| step < HARDENED)
+                .ok_or_else(|| {
+                    Error::InvalidInput(format!(
+                        "Unsupported derivation step in descriptor key (only unhardened steps and a final * can be derived from an extended public key): {}",
+                        step
+                    ))
+                }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let extended: ExtendedPublicKey = encoded.parse()?;
+    Ok(Some(extended.derive_path(&path)?.public_key))
+}
+
+/// Validate an x-only key by checking its even-y compressed form
+fn x_only_key(bytes: &[u8], key: &str) -> Result<Vec<u8>, Error> {
+    if secp256k1::is_valid_public_key(&[&[0x02], bytes].concat()) {
+        Ok(bytes.to_vec())
+    } else {
+        Err(invalid_key(key))
+    }
+}
+
+fn invalid_key(key: &str) -> Error {
+    Error::InvalidInput(format!("Invalid secp256k1 public key: {}", key_shape(key)))
+}
+
+/// Key as shown in error messages
+///
+/// Descriptors exported with their private keys (`listdescriptors true`)
+/// hold WIF and hex secrets in key position, so only the length is shown.
+fn key_shape(key: &str) -> String {
+    format!("<{} characters>", key.chars().count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5";
+
+    #[test]
+    fn test_looks_like_descriptor() {
+        assert!(looks_like_descriptor(&format!("pkh({})", KEY)));
+        assert!(looks_like_descriptor("raw(deadbeef)#89f8spxm"));
+        assert!(!looks_like_descriptor(
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+        ));
+        assert!(!looks_like_descriptor("foo(bar)"));
+    }
+
+    #[test]
+    fn test_parse_descriptor_shapes() {
+        let parsed =
+            parse_descriptor(&format!("sh(wpkh([d34db33f/49'/0'/0']{}))", KEY), 0).unwrap();
+        assert_eq!(parsed.descriptor.shape(), "sh(wpkh())");
+        assert!(!parsed.has_checksum);
+
+        let parsed = parse_descriptor(&format!("sh(wsh(sortedmulti(1,{})))", KEY), 0).unwrap();
+        assert_eq!(parsed.descriptor.shape(), "sh(wsh(sortedmulti()))");
+
+        // Compressed keys in tr() are reduced to x-only
+        let parsed = parse_descriptor(&format!("tr({})", KEY), 0).unwrap();
+        assert_eq!(
+            parsed.descriptor,
+            Descriptor::Tr(hex::decode(&KEY[2..]).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_descriptor_checksum() {
+        let parsed = parse_descriptor(&format!("pkh({})#8fhd9pwu", KEY), 0).unwrap();
+        assert!(parsed.has_checksum);
+        assert!(parse_descriptor(&format!("pkh({})#8fhd9pwv", KEY), 0).is_err());
+        assert_eq!(
+            with_checksum(&format!("pkh({})", KEY)).unwrap(),
+            format!("pkh({})#8fhd9pwu", KEY)
+        );
+    }
+
+    #[test]
+    fn test_parse_descriptor_rejects_invalid() {
+        let uncompressed = "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+        assert!(parse_descriptor(&format!("pkh({})", uncompressed), 0).is_ok());
+        assert!(parse_descriptor(&format!("wpkh({})", uncompressed), 0).is_err());
+        assert!(parse_descriptor(&format!("pkh({})", &KEY[2..]), 0).is_err());
+        assert!(parse_descriptor(&format!("wsh(pkh({}))", KEY), 0).is_err());
+        assert!(parse_descriptor(&format!("tr({},pk({}))", KEY, KEY), 0).is_err());
+        assert!(parse_descriptor("raw(deadbeef)", 0).is_err());
+    }
+
+    #[test]
+    fn test_parse_descriptor_extended_keys() {
+        // BIP-84 account 0 key: receive addresses 0/0 and 0/1
+        let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+        let ranged = format!("wpkh([73c5da0a/84'/0'/0']{}/0/*)", zpub);
+        let first = parse_descriptor(&ranged, 0).unwrap();
+        assert!(first.ranged);
+        assert_eq!(
+            first.descriptor,
+            Descriptor::Wpkh(
+                hex::decode("0330d54fd0dd420a6e5f8d3624f5f3482cae350f79d5f0753bf5beef9c2d91af3c")
+                    .unwrap()
+            )
+        );
+        let second = parse_descriptor(&ranged, 1).unwrap();
+        assert_eq!(
+            second.descriptor,
+            Descriptor::Wpkh(
+                hex::decode("03e775fd51f0dfb8cd865d9ff1cca2a158cf651fe997fdc9fee9c1d3b5e995ea77")
+                    .unwrap()
+            )
+        );
+        // A fixed path is not ranged and matches the ranged key at that index
+        let fixed = parse_descriptor(&format!("wpkh({}/0/1)", zpub), 7).unwrap();
+        assert!(!fixed.ranged);
+        assert_eq!(fixed.descriptor, second.descriptor);
+    }
+
+    #[test]
+    fn test_parse_descriptor_rejects_invalid_extended_keys() {
+        let xpub = "xpub6ERApfZwUNrhLCkDtcHTcxd75RbzS1ed54G1LkBUHQVHQKqhMkhgbmJbZRkrgZw4koxb5JaHWkY4ALHY2grBGRjaDMzQLcgJvLJuZZvRcEL";
+        assert!(parse_descriptor(&format!("wpkh({}/0/*)", xpub), 0).is_ok());
+        for step in ["0'/*", "0h/*", "*'", "*/0", "<0;1>/*"] {
+            let error = parse_descriptor(&format!("wpkh({}/{})", xpub, step), 0).unwrap_err();
+            assert!(error.to_string().contains("derivation step"), "{}", step);
+        }
+    }
+
+    #[test]
+    fn test_parse_descriptor_errors_omit_private_keys() {
+        let wif = "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617";
+        let xprv = "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg2LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi";
+        let hex_secret = "0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d";
+        for descriptor in [
+            format!("wpkh({})", wif),
+            format!("pkh([d34db33f/44'/0'/0']{})", wif),
+            format!("tr({}/0/*)", xprv),
+            format!("wpkh({})", hex_secret),
+            format!("wpkh({}", wif),
+            format!("wpkh([d34db33f{})", wif),
+        ] {
+            let message = parse_descriptor(&descriptor, 0).unwrap_err().to_string();
+            for secret in [wif, xprv, hex_secret] {
+                assert!(!message.contains(&secret[..16]), "{}", message);
+            }
+        }
+        let error = parse_descriptor(&format!("wpkh({})", wif), 0).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("WIF private keys are not supported"));
+    }
+}
@@ -5,9 +5,10 @@
 //! only implements format-specific validation logic.
 
 use crate::input::InputCharacteristics;
-use crate::registry::{AddressMetadata, ChecksumType, EncodingType};
+use crate::registry::{AddressMetadata, ChecksumType, EncodingType, Network};
 use crate::shared::checksum::{base58check, eip55};
 use crate::shared::encoding::bech32 as bech32_encoding;
+use crate::shared::encoding::cashaddr;
 use crate::Error;
 use bech32;
 
@@ -108,22 +109,34 @@ fn validate_checksum(
                 Ok(false)
             }
         }
+        // The HRP must be one of this format's own (e.g. `cosmosvaloper` is not `cosmos`)
         ChecksumType::Bech32 => match bech32_encoding::decode(input) {
-            Ok((_, _, variant)) => Ok(variant == bech32::Variant::Bech32),
+            Ok((hrp, _, variant)) => {
+                Ok(variant == bech32::Variant::Bech32 && hrp_matches(&hrp, metadata))
+            }
             Err(_) => Ok(false),
         },
         ChecksumType::Bech32m => match bech32_encoding::decode(input) {
-            Ok((_, _, variant)) => Ok(variant == bech32::Variant::Bech32m),
+            Ok((hrp, _, variant)) => {
+                Ok(variant == bech32::Variant::Bech32m && hrp_matches(&hrp, metadata))
+            }
             Err(_) => Ok(false),
         },
-        ChecksumType::SS58 => {
-            // SS58 validation is complex, delegate to shared module
-            // For now, return true if it's valid Base58
-            Ok(true) // TODO: Implement proper SS58 validation
-        }
+        ChecksumType::CashAddr => Ok(metadata.matches_cashaddr(input)),
+        // The prefix must be the chain's own (e.g. 0 for Polkadot, 2 for Kusama)
+        ChecksumType::SS58 => Ok(metadata.matches_ss58(input)),
+        // The HRP and header network must be the format's (`addr` and network id 1 on mainnet)
+        ChecksumType::Cardano => Ok(metadata.matches_cardano(input)),
+        // Mainnet Byron addresses carry no protocol magic, testnet ones do
+        ChecksumType::CardanoByron => Ok(metadata.matches_byron(input)),
     }
 }
 
+/// Whether a decoded HRP is declared by the format (formats without HRPs accept any)
+fn hrp_matches(hrp: &str, metadata: &AddressMetadata) -> bool {
+    metadata.hrps.is_empty() || metadata.hrps.iter().any(|h| h == hrp)
+}
+
 /// Validate version bytes for Base58Check
 fn validate_version_bytes(
     input: &str,
@@ -153,6 +166,13 @@ fn normalize_address(input: &str, metadata: &AddressMetadata) -> Result<String,
             // Base58 is case-sensitive, return as-is
             Ok(input.to_string())
         }
+        EncodingType::CashAddr => {
+            // Normalize to the lowercase form with its prefix
+            let (prefix, address_type, hash) = cashaddr::decode(input, &metadata.hrps)
+                .map_err(|e| Error::InvalidInput(format!("CashAddr decode error: {}", e)))?;
+            cashaddr::encode(&prefix, address_type, &hash)
+                .map_err(|e| Error::InvalidInput(format!("CashAddr encode error: {}", e)))
+        }
     }
 }
 
@@ -192,7 +212,16 @@ fn generate_reasoning(
 ) -> String {
     let mut parts = Vec::new();
 
-    parts.push(format!("{:?} address", metadata.encoding));
+    match metadata.output_type {
+        Some(output_type) => {
+            parts.push(format!("{:?} {:?} address", metadata.encoding, output_type))
+        }
+        None => parts.push(format!("{:?} address", metadata.encoding)),
+    }
+
+    if metadata.network == Some(Network::Testnet) {
+        parts.push("testnet".to_string());
+    }
 
     if checksum_valid {
         parts.push("valid checksum".to_string());
@@ -209,7 +238,7 @@ fn generate_reasoning(
 mod tests {
     use super::*;
     use crate::input::extract_characteristics;
-    use crate::registry::{AddressMetadata, CharSet, Network};
+    use crate::registry::{AddressMetadata, CharSet};
 
     #[test]
     fn test_detect_evm_address() {
@@ -226,10 +255,43 @@ mod tests {
             version_bytes: vec![],
             checksum: Some(ChecksumType::EIP55),
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let result = detect_address(input, &chars, &metadata, "ethereum".to_string());
         assert!(result.is_ok());
         // Result may be Some or None depending on checksum validation
     }
+
+    #[test]
+    fn test_detect_ss58_address_prefix_and_checksum() {
+        let metadata = AddressMetadata {
+            encoding: EncodingType::SS58,
+            char_set: Some(CharSet::Base58),
+            exact_length: None,
+            length_range: Some((35, 50)),
+            prefixes: vec![],
+            hrps: vec![],
+            version_bytes: vec![],
+            checksum: Some(ChecksumType::SS58),
+            network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: Some(0),
+        };
+        let detect = |input: &str| {
+            let chars = extract_characteristics(input);
+            detect_address(input, &chars, &metadata, "polkadot".to_string()).unwrap()
+        };
+
+        // Alice under the Polkadot prefix
+        let result = detect("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5").unwrap();
+        assert!(result.reasoning.contains("valid checksum"));
+        // Same account under the generic Substrate prefix
+        assert!(detect("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").is_none());
+        // Corrupted checksum
+        assert!(detect("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp6").is_none());
+    }
 }
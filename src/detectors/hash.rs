@@ -0,0 +1,194 @@
+//! Metadata-driven transaction and block hash detector
+//!
+//! Hashes carry no checksum or version byte, so a match only says the input
+//! has the shape a chain uses. Confidence reflects how distinctive that shape
+//! is: proof-of-work leading zeros, 64-byte signatures and transaction
+//! hashes rank high, block hashes without leading zeros rank low.
+
+use super::address::DetectionResult;
+use crate::registry::{EncodingType, HashKind, HashMetadata};
+use crate::shared::encoding::base58;
+
+/// Detect a transaction or block hash using metadata
+pub fn detect_hash(input: &str, metadata: &HashMetadata, chain: String) -> Option<DetectionResult> {
+    // Prefixed formats require one of their prefixes, bare formats reject 0x
+    let (prefix, body) = if metadata.prefixes.is_empty() {
+        if input.starts_with("0x") {
+            return None;
+        }
+        ("", input)
+    } else {
+        metadata.prefixes.iter().find_map(|prefix| {
+            input
+                .strip_prefix(prefix.as_str())
+                .map(|body| (prefix.as_str(), body))
+        })?
+    };
+
+    let (bytes, normalized) = match metadata.encoding {
+        EncodingType::Hex => {
+            if body.len() != metadata.bytes * 2 {
+                return None;
+            }
+            let bytes = ::hex::decode(body).ok()?;
+            let body = if metadata.uppercase {
+                body.to_uppercase()
+            } else {
+                body.to_lowercase()
+            };
+            (bytes, format!("{}{}", prefix, body))
+        }
+        EncodingType::Base58 => (base58::decode(body).ok()?, input.to_string()),
+        _ => return None,
+    };
+    if bytes.len() != metadata.bytes
+        || bytes[..metadata.leading_zero_bytes.min(bytes.len())]
+            .iter()
+            .any(|&b| b != 0)
+    {
+        return None;
+    }
+
+    let label = match metadata.kind {
+        HashKind::Transaction => "transaction ID",
+        HashKind::Block => "block hash",
+    };
+    let mut reasoning = format!(
+        "{}-byte {} {}",
+        metadata.bytes,
+        encoding_name(metadata.encoding),
+        label
+    );
+
+    let mut confidence: f64 = match metadata.kind {
+        // Transaction hashes are what gets pasted into a search; rank them
+        // above the public key readings (0.8) of the same bytes
+        HashKind::Transaction => 0.85,
+        HashKind::Block => 0.3,
+    };
+    if metadata.encoding == EncodingType::Base58 && metadata.bytes == 64 {
+        // Signatures are the only 64-byte Base58 strings
+        confidence = 0.9;
+    }
+    if metadata.leading_zero_bytes > 0 {
+        confidence = 0.9;
+        reasoning.push_str(&format!(
+            " with at least {} leading zero bytes",
+            metadata.leading_zero_bytes
+        ));
+    }
+    if !prefix.is_empty() && metadata.kind == HashKind::Block {
+        confidence += 0.1;
+    }
+
+    // Hex digits in the other case than the chain displays them
+    if metadata.encoding == EncodingType::Hex
+        && body.chars().any(|c| c.is_ascii_alphabetic())
+        && !normalized.ends_with(body)
+    {
+        confidence -= 0.1;
+        reasoning.push_str(if metadata.uppercase {
+            ", usually displayed in uppercase"
+        } else {
+            ", usually displayed in lowercase"
+        });
+    }
+
+    Some(DetectionResult {
+        chain,
+        encoding: metadata.encoding,
+        normalized,
+        confidence: confidence.clamp(0.0, 1.0),
+        reasoning,
+    })
+}
+
+fn encoding_name(encoding: EncodingType) -> &'static str {
+    match encoding {
+        EncodingType::Base58 => "Base58",
+        _ => "hex",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(kind: HashKind, prefix: Option<&str>) -> HashMetadata {
+        HashMetadata {
+            kind,
+            encoding: EncodingType::Hex,
+            bytes: 32,
+            prefixes: prefix.map(|p| vec![p.to_string()]).unwrap_or_default(),
+            uppercase: false,
+            leading_zero_bytes: 0,
+        }
+    }
+
+    #[test]
+    fn test_detect_hex_hash() {
+        let tx = "0xcdf331416ac94df404cfa95b13ecd4b23b2b1de895c945e25ff1b557c597a64e";
+        let evm = format(HashKind::Transaction, Some("0x"));
+        let result = detect_hash(tx, &evm, "ethereum".to_string()).unwrap();
+        assert_eq!(result.normalized, tx);
+        assert_eq!(result.confidence, 0.85);
+
+        // Prefix presence must match the format
+        assert!(detect_hash(&tx[2..], &evm, "ethereum".to_string()).is_none());
+        let bare = format(HashKind::Transaction, None);
+        assert!(detect_hash(tx, &bare, "bitcoin".to_string()).is_none());
+        assert!(detect_hash(&tx[..64], &evm, "ethereum".to_string()).is_none());
+    }
+
+    #[test]
+    fn test_detect_hash_canonical_case() {
+        let tx = "3E0BA99F9A254B4DEC6EE5CB04F833535DD409ECCC26133D8DF0CF943EE9B326";
+        let cosmos = HashMetadata {
+            uppercase: true,
+            ..format(HashKind::Transaction, None)
+        };
+        let result = detect_hash(tx, &cosmos, "cosmos_hub".to_string()).unwrap();
+        assert_eq!(result.confidence, 0.85);
+
+        let result = detect_hash(&tx.to_lowercase(), &cosmos, "cosmos_hub".to_string()).unwrap();
+        assert_eq!(result.normalized, tx);
+        assert!(result.confidence < 0.8);
+        assert!(result.reasoning.contains("uppercase"));
+    }
+
+    #[test]
+    fn test_detect_block_leading_zeros() {
+        // Bitcoin genesis block
+        let genesis = "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+        let block = HashMetadata {
+            leading_zero_bytes: 4,
+            ..format(HashKind::Block, None)
+        };
+        let result = detect_hash(genesis, &block, "bitcoin".to_string()).unwrap();
+        assert_eq!(result.confidence, 0.9);
+        assert!(detect_hash(
+            &genesis.replace("0000000000", "1000000000"),
+            &block,
+            "bitcoin".to_string()
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_detect_base58_signature() {
+        let signature = "5wpHU1gGYcgKabL7heGGgiKBx3WJMruHiN34sCjTYwQu4sk9H2uMyZsm1P28RqaJPVELtcVxNmSGieq6V5ZZxpDT";
+        let solana = HashMetadata {
+            encoding: EncodingType::Base58,
+            bytes: 64,
+            ..format(HashKind::Transaction, None)
+        };
+        let result = detect_hash(signature, &solana, "solana".to_string()).unwrap();
+        assert_eq!(result.confidence, 0.9);
+        assert!(detect_hash(
+            "11111111111111111111111111111111",
+            &solana,
+            "solana".to_string()
+        )
+        .is_none());
+    }
+}
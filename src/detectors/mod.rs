@@ -4,5 +4,7 @@
 //! hardcoded heuristics.
 
 pub mod address;
+pub mod hash;
 
 pub use address::detect_address;
+pub use hash::detect_hash;
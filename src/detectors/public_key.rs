@@ -181,6 +181,7 @@ mod tests {
             hrps: vec![],
             key_type: PublicKeyType::Secp256k1,
             checksum: None,
+            role: None,
         };
         
         let result = detect_public_key(input, &chars, &metadata, "ethereum".to_string());
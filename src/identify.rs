@@ -6,20 +6,41 @@
 //! 3. For addresses: run address detection
 //! 4. For public keys: use pipeline-based derivation
 //! 5. Return all candidates sorted by confidence
-
-use crate::detectors::detect_address;
+//!
+//! Output descriptors, extended public keys, mnemonics, private keys and raw
+//! signed EVM transactions are recognized before classification and resolved
+//! to the addresses they describe, control or were sent from. 32-byte hex
+//! private keys and transaction/block hashes are reported next to the other
+//! interpretations of the input.
+
+use crate::bip32::{chain_address, looks_like_extended_key, AddressSource, ExtendedPublicKey};
+use crate::cardano::{parse_byron_address, parse_cardano_address, ByronAddress, CardanoAddress};
+use crate::conversion::{
+    cosmos_account_to_valoper, ethermint_to_evm, evm_to_ethermint, evm_to_ss58,
+    is_account_id20_chain, is_ethermint_chain, ss58_to_evm,
+};
+use crate::descriptor::{looks_like_descriptor, parse_descriptor};
+use crate::detectors::{detect_address, detect_hash};
 use crate::input::{
-    classify_input, extract_characteristics, match_input_with_metadata, InputCharacteristics,
-    InputPossibility,
+    classify_input, extract_characteristics, match_input_with_metadata, redact_input,
+    DetectedKeyType, InputCharacteristics, InputPossibility,
 };
-use crate::pipelines::addresses::execute_pipeline;
-use crate::registry::{PublicKeyType, Registry};
-use crate::shared::derivation::decode_public_key;
+use crate::mnemonic::{looks_like_mnemonic, parse_mnemonic, seed_addresses, MnemonicInfo};
+use crate::pipelines::addresses::derive_chain_address;
+use crate::private_key::{parse_private_key, PrivateKey};
+use crate::registry::{
+    AddressRole, ChecksumType, EncodingType, HashKind, Network, PublicKeyType, Registry,
+};
+use crate::shared::derivation::{decode_public_key, decode_wrapped_public_key};
+use crate::transaction::{decode_evm_transaction, EvmTransaction};
 use crate::Error;
-use serde_json::json;
 
 /// A candidate identification result
+///
+/// Fields are added as new input types are supported, so candidates are
+/// only built by this crate.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct IdentificationCandidate {
     /// Type of input (address or public key)
     pub input_type: InputType,
@@ -33,6 +54,58 @@ pub struct IdentificationCandidate {
     pub confidence: f64,
     /// Reasoning for this candidate
     pub reasoning: String,
+    /// Whether the input is secret material (a private key)
+    ///
+    /// Sensitive inputs must not be logged or stored; reasoning never
+    /// contains the key itself.
+    pub sensitive: bool,
+    /// Cosmos address role of `normalized` (account, valoper or valcons);
+    /// `None` on other chains
+    pub role: Option<AddressRole>,
+    /// For a linked candidate, the address of the candidate it is equivalent to
+    ///
+    /// Ethermint chains (Evmos, Injective, Cronos) name one account both as
+    /// `0x...` and in Bech32; each form is reported linked to the other.
+    pub linked_from: Option<String>,
+    /// Chain-specific decoding of `normalized` (Cardano Shelley and Byron
+    /// addresses); `None` on other chains
+    pub details: Option<CandidateDetails>,
+}
+
+/// Chain-specific decoding of a candidate's address
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CandidateDetails {
+    /// Cardano Shelley address (type, network id, credentials, stake pointer)
+    Cardano(CardanoAddress),
+    /// Cardano Byron address (root, type, protocol magic)
+    Byron(ByronAddress),
+}
+
+impl CandidateDetails {
+    /// One-line description used as the prefix of the candidate's reasoning
+    pub fn describe(&self) -> String {
+        match self {
+            CandidateDetails::Cardano(address) => address.describe(),
+            CandidateDetails::Byron(address) => address.describe(),
+        }
+    }
+
+    /// The Shelley address, if these are Cardano Shelley details
+    pub fn cardano(&self) -> Option<&CardanoAddress> {
+        match self {
+            CandidateDetails::Cardano(address) => Some(address),
+            _ => None,
+        }
+    }
+
+    /// The Byron address, if these are Cardano Byron details
+    pub fn byron(&self) -> Option<&ByronAddress> {
+        match self {
+            CandidateDetails::Byron(address) => Some(address),
+            _ => None,
+        }
+    }
 }
 
 /// Type of input being identified
@@ -42,7 +115,20 @@ pub enum InputType {
     Address,
     /// Public key input
     PublicKey,
-    // Future: Transaction, Block, PrivateKey
+    /// Output descriptor (e.g. `wpkh(...)`), normalized to the address it describes
+    Descriptor,
+    /// BIP-32 extended public key, normalized to its first receive address (`0/0`)
+    ExtendedPublicKey,
+    /// Private key (WIF, 32-byte hex, Solana keypair), normalized to an address it controls
+    PrivateKey,
+    /// BIP-39 mnemonic phrase, normalized to a first derived address per chain
+    Mnemonic,
+    /// Transaction hash or signature, normalized to the chain's display case
+    Transaction,
+    /// Block hash, normalized to the chain's display case
+    Block,
+    /// Raw signed transaction, normalized to its recovered sender address
+    SignedTransaction,
 }
 
 /// Identify the blockchain(s) for a given input string
@@ -55,19 +141,82 @@ pub enum InputType {
 /// 2. Classify input (non-chain-aware: Address? PublicKey? Both? None?)
 /// 3. Match with metadata (metadata-driven signature matching)
 /// 4. Structural validation (checksums, decodes, pipeline derivation)
+/// 5. Linked candidates for the other form of Ethermint and `AccountId20` accounts
 pub fn identify(input: &str) -> Result<Vec<IdentificationCandidate>, Error> {
+    identify_with_options(input, &IdentifyOptions::default())
+}
+
+/// Options for [`identify_with_options`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IdentifyOptions {
+    /// Curve of a public key input, when the caller knows the scheme
+    ///
+    /// A raw 32-byte key is either Ed25519 or sr25519 and is reported as
+    /// "ed25519 or sr25519" (sr25519 on chains declaring it). A hint reads it
+    /// with that scheme only; public key interpretations on other curves are
+    /// dropped.
+    pub curve_hint: Option<PublicKeyType>,
+}
+
+/// Identify the blockchain(s) for a given input string, with options
+///
+/// Same as [`identify`], with public key interpretations narrowed by
+/// [`IdentifyOptions::curve_hint`].
+pub fn identify_with_options(
+    input: &str,
+    options: &IdentifyOptions,
+) -> Result<Vec<IdentificationCandidate>, Error> {
+    let mut candidates = identify_candidates(input, options)?;
+    let linked = linked_candidates(&candidates);
+    candidates.extend(linked);
+    // Stable: a linked candidate stays after the one it was linked from
+    sort_candidates(&mut candidates);
+    Ok(candidates)
+}
+
+/// Candidates before linking
+fn identify_candidates(
+    input: &str,
+    options: &IdentifyOptions,
+) -> Result<Vec<IdentificationCandidate>, Error> {
+    if looks_like_descriptor(input.trim()) {
+        return identify_descriptor(input);
+    }
+    if looks_like_extended_key(input.trim()) {
+        return identify_extended_key(input.trim());
+    }
+    if looks_like_mnemonic(input) {
+        return identify_mnemonic(input);
+    }
+    if let Ok(transaction) = decode_evm_transaction(input) {
+        return identify_evm_transaction(&transaction);
+    }
+    let private_key = parse_private_key(input);
+    if let Some(key) = private_key.as_ref().filter(|key| !key.is_ambiguous()) {
+        return identify_private_key(key);
+    }
+
+    let hashes = hash_candidates(input);
+
     // Step 1: Extract characteristics
     let chars = extract_characteristics(input);
 
     // Step 2: Classify input to get all possibilities (non-chain-aware)
-    let possibilities = classify_input(input, &chars)?;
+    let possibilities: Vec<InputPossibility> = match classify_input(input, &chars) {
+        Ok(possibilities) => possibilities,
+        Err(_) if private_key.is_some() || !hashes.is_empty() => Vec::new(),
+        Err(e) => return Err(e),
+    }
+    .into_iter()
+    .filter_map(|possibility| apply_curve_hint(possibility, options.curve_hint))
+    .collect();
 
     // Step 3: Match with metadata (metadata-driven signature matching)
     let registry = Registry::get();
     let chain_matches = match_input_with_metadata(input, &chars, &possibilities, registry);
 
     // Step 4: Process matches with structural validation
-    let results: Vec<IdentificationCandidate> = chain_matches
+    let mut results: Vec<IdentificationCandidate> = chain_matches
         .into_iter()
         .flat_map(|chain_match| match chain_match.possibility {
             InputPossibility::Address => {
@@ -80,26 +229,327 @@ pub fn identify(input: &str) -> Result<Vec<IdentificationCandidate>, Error> {
             }
         })
         .collect();
+    if let Some(key) = &private_key {
+        results.extend(private_key_candidates(key)?);
+    }
+    results.extend(hashes);
 
-    // Sort by confidence (highest first)
-    // Note: sort_by is acceptable here as it's a standard sorting operation, not a nested loop
-    let mut sorted_results = results;
-    sorted_results.sort_by(|a, b| {
+    sort_candidates(&mut results);
+    if results.is_empty() {
+        Err(Error::InvalidInput(format!(
+            "Unable to identify address format: {}",
+            redact_input(input)
+        )))
+    } else {
+        Ok(results)
+    }
+}
+
+/// Sort candidates by confidence, highest first
+///
+/// The sort is stable, so candidates of equal confidence keep their order.
+fn sort_candidates(candidates: &mut [IdentificationCandidate]) {
+    candidates.sort_by(|a, b| {
         b.confidence
             .partial_cmp(&a.confidence)
             .unwrap_or(std::cmp::Ordering::Equal)
     });
+}
 
-    if sorted_results.is_empty() {
+/// Derive the mainnet address of a descriptor on every chain with a matching format
+///
+/// Ranged descriptors are derived at index 0.
+fn identify_descriptor(input: &str) -> Result<Vec<IdentificationCandidate>, Error> {
+    let parsed = parse_descriptor(input, 0)?;
+    let checksum_note = if parsed.has_checksum {
+        "valid checksum"
+    } else {
+        "no checksum"
+    };
+    let index_note = if parsed.ranged { ", index 0" } else { "" };
+
+    let mut candidates = Vec::new();
+    for chain in &Registry::get().chains {
+        if let Some((format, address)) = parsed.descriptor.address(&chain.id, Network::Mainnet)? {
+            candidates.push(IdentificationCandidate {
+                input_type: InputType::Descriptor,
+                sensitive: false,
+                chain: chain.id.clone(),
+                encoding: format.encoding,
+                normalized: address,
+                confidence: if parsed.has_checksum { 0.9 } else { 0.8 },
+                reasoning: format!(
+                    "Derived from {} descriptor{}, {}",
+                    parsed.descriptor.shape(),
+                    index_note,
+                    checksum_note
+                ),
+                role: None,
+                linked_from: None,
+                details: None,
+            });
+        }
+    }
+
+    if candidates.is_empty() {
         Err(Error::InvalidInput(format!(
-            "Unable to identify address format: {}",
-            input
+            "No chain supports {} descriptors",
+            parsed.descriptor.shape()
+        )))
+    } else {
+        Ok(candidates)
+    }
+}
+
+/// Derive the first receive address (`0/0`) of an extended public key on every chain it applies to
+///
+/// Chains declaring the key version rank above chains reached through a plain
+/// `xpub` and their address pipeline. Multisig cosigner keys (Ypub/Zpub) are
+/// reported as-is, since their addresses need every cosigner.
+fn identify_extended_key(input: &str) -> Result<Vec<IdentificationCandidate>, Error> {
+    let key: ExtendedPublicKey = input.parse()?;
+    let first_receive = key.derive_path(&[0, 0])?;
+
+    let mut candidates = Vec::new();
+    for chain in &Registry::get().chains {
+        if let Some(format) = key.declared_format(chain) {
+            if format.script.is_multisig() {
+                let reasoning = format!(
+                    "{} extended public key (depth {}) for {:?} multisig; addresses need every cosigner key",
+                    key.prefix, key.depth, format.script
+                );
+                candidates.push(IdentificationCandidate {
+                    input_type: InputType::ExtendedPublicKey,
+                    sensitive: false,
+                    chain: chain.id.clone(),
+                    encoding: EncodingType::Base58Check,
+                    normalized: input.to_string(),
+                    confidence: 0.9,
+                    reasoning,
+                    role: None,
+                    linked_from: None,
+                    details: None,
+                });
+                continue;
+            }
+        }
+
+        let Some(derived) = chain_address(&key, &first_receive.public_key, chain, false)? else {
+            continue;
+        };
+        let (confidence, reasoning) = match &derived.source {
+            AddressSource::Script(script) => (
+                0.9,
+                format!(
+                    "{} extended public key (depth {}, {:?}); first receive address at 0/0",
+                    key.prefix, key.depth, script
+                ),
+            ),
+            AddressSource::Pipeline(pipeline) => (
+                0.5,
+                format!(
+                    "{} extended public key (depth {}); address at 0/0 derived using {} pipeline",
+                    key.prefix, key.depth, pipeline
+                ),
+            ),
+        };
+        candidates.push(IdentificationCandidate {
+            input_type: InputType::ExtendedPublicKey,
+            sensitive: false,
+            chain: chain.id.clone(),
+            encoding: derived.encoding,
+            role: address_role(&chain.id, &derived.address),
+            linked_from: None,
+            details: None,
+            normalized: derived.address,
+            confidence,
+            reasoning,
+        });
+    }
+
+    sort_candidates(&mut candidates);
+    if candidates.is_empty() {
+        Err(Error::InvalidInput(format!(
+            "No chain derives addresses from {} keys",
+            key.prefix
+        )))
+    } else {
+        Ok(candidates)
+    }
+}
+
+/// Derive the first address of a BIP-39 mnemonic (empty passphrase) on every chain with an HD path
+///
+/// Only the language and strength of the phrase appear in reasoning.
+fn identify_mnemonic(input: &str) -> Result<Vec<IdentificationCandidate>, Error> {
+    let mnemonic = parse_mnemonic(input)?;
+    let info = MnemonicInfo::of(&mnemonic);
+    let mnemonic_summary = format!(
+        "{}, {} words, {}-bit, valid checksum",
+        info.language, info.word_count, info.strength
+    );
+    let seed = zeroize::Zeroizing::new(mnemonic.to_seed(""));
+    drop(mnemonic);
+
+    let mut candidates = Vec::new();
+    for chain in &Registry::get().chains {
+        let Ok(addresses) = seed_addresses(&seed, chain, 1) else {
+            continue;
+        };
+        candidates.extend(addresses.into_iter().map(|(derived, encoding)| {
+            IdentificationCandidate {
+                input_type: InputType::Mnemonic,
+                sensitive: true,
+                chain: chain.id.clone(),
+                encoding,
+                role: address_role(&chain.id, &derived.address),
+                linked_from: None,
+                details: None,
+                reasoning: format!(
+                    "BIP-39 mnemonic ({}); first address at {}",
+                    mnemonic_summary, derived.path
+                ),
+                normalized: derived.address,
+                confidence: 0.95,
+            }
+        }));
+    }
+
+    if candidates.is_empty() {
+        Err(Error::InvalidInput(
+            "No chain derives addresses from the mnemonic".to_string(),
+        ))
+    } else {
+        Ok(candidates)
+    }
+}
+
+/// Map the sender of a signed EVM transaction to the chains its chain id designates
+///
+/// Legacy transactions without EIP-155 replay protection are valid on every
+/// EVM chain and rank lower.
+fn identify_evm_transaction(
+    transaction: &EvmTransaction,
+) -> Result<Vec<IdentificationCandidate>, Error> {
+    let registry = Registry::get();
+    let candidates: Vec<IdentificationCandidate> = registry
+        .chains
+        .iter()
+        .filter_map(|chain| {
+            let evm_chain_id = registry.get_chain_config(&chain.id)?.evm_chain_id?;
+            let (confidence, scope) = match transaction.chain_id {
+                Some(chain_id) if chain_id == evm_chain_id => {
+                    (0.95, format!("chain id {}", chain_id))
+                }
+                Some(_) => return None,
+                None => (0.6, "no chain id, valid on every EVM chain".to_string()),
+            };
+            Some(IdentificationCandidate {
+                input_type: InputType::SignedTransaction,
+                sensitive: false,
+                chain: chain.id.clone(),
+                encoding: EncodingType::Hex,
+                normalized: transaction.from.clone(),
+                confidence,
+                reasoning: format!(
+                    "Signed {} transaction ({}), sender recovered from signature; hash {}",
+                    transaction.tx_type.name(),
+                    scope,
+                    transaction.hash
+                ),
+                role: None,
+                linked_from: None,
+                details: None,
+            })
+        })
+        .collect();
+
+    if candidates.is_empty() {
+        Err(Error::InvalidInput(format!(
+            "Signed {} transaction from {} on chain id {}, which no supported chain declares",
+            transaction.tx_type.name(),
+            transaction.from,
+            transaction.chain_id.unwrap_or_default()
+        )))
+    } else {
+        Ok(candidates)
+    }
+}
+
+/// Report the addresses a WIF key or Solana keypair controls
+fn identify_private_key(key: &PrivateKey) -> Result<Vec<IdentificationCandidate>, Error> {
+    let mut candidates = private_key_candidates(key)?;
+    sort_candidates(&mut candidates);
+    if candidates.is_empty() {
+        Err(Error::InvalidInput(format!(
+            "No chain derives addresses from {}",
+            key.description()
         )))
     } else {
-        Ok(sorted_results)
+        Ok(candidates)
     }
 }
 
+/// One sensitive candidate per address a private key controls
+///
+/// Chains named by the key's encoding rank highest; 32-byte hex keys rank
+/// low since the input may as well be a public key or a hash.
+fn private_key_candidates(key: &PrivateKey) -> Result<Vec<IdentificationCandidate>, Error> {
+    Ok(key
+        .controlled_addresses()?
+        .into_iter()
+        .map(|controlled| IdentificationCandidate {
+            input_type: InputType::PrivateKey,
+            sensitive: true,
+            role: address_role(&controlled.chain, &controlled.address),
+            linked_from: None,
+            details: None,
+            chain: controlled.chain,
+            encoding: controlled.encoding,
+            normalized: controlled.address,
+            confidence: match (controlled.declared, key.is_ambiguous()) {
+                (true, _) => 0.95,
+                (false, false) => 0.7,
+                (false, true) => 0.3,
+            },
+            reasoning: format!(
+                "{}; controls {} address of public key {}",
+                key.description(),
+                controlled.scheme,
+                controlled.public_key
+            ),
+        })
+        .collect())
+}
+
+/// Match an input against every chain's transaction and block hash formats
+fn hash_candidates(input: &str) -> Vec<IdentificationCandidate> {
+    Registry::get()
+        .chains
+        .iter()
+        .flat_map(|chain| {
+            chain.hash_formats.iter().filter_map(|format| {
+                let result = detect_hash(input, format, chain.id.clone())?;
+                Some(IdentificationCandidate {
+                    input_type: match format.kind {
+                        HashKind::Transaction => InputType::Transaction,
+                        HashKind::Block => InputType::Block,
+                    },
+                    sensitive: false,
+                    chain: result.chain,
+                    encoding: result.encoding,
+                    normalized: result.normalized,
+                    confidence: result.confidence,
+                    reasoning: result.reasoning,
+                    role: None,
+                    linked_from: None,
+                    details: None,
+                })
+            })
+        })
+        .collect()
+}
+
 /// Try address detection for a specific chain (after metadata matching)
 fn try_address_detection_for_chain(
     input: &str,
@@ -122,14 +572,36 @@ fn try_address_detection_for_chain(
             detect_address(input, chars, addr_format, chain_id.to_string())
                 .ok()
                 .flatten()
+                .map(|result| (result, addr_format))
         })
-        .map(|result| IdentificationCandidate {
-            input_type: InputType::Address,
-            chain: result.chain,
-            encoding: result.encoding,
-            normalized: result.normalized,
-            confidence: result.confidence,
-            reasoning: result.reasoning,
+        .map(|(result, addr_format)| {
+            // Shelley addresses report their decoded header and credentials,
+            // Byron addresses their type and protocol magic
+            let details = match addr_format.checksum {
+                Some(ChecksumType::Cardano) => parse_cardano_address(&result.normalized)
+                    .ok()
+                    .map(CandidateDetails::Cardano),
+                Some(ChecksumType::CardanoByron) => parse_byron_address(&result.normalized)
+                    .ok()
+                    .map(CandidateDetails::Byron),
+                _ => None,
+            };
+            let reasoning = match &details {
+                Some(details) => format!("{}: {}", details.describe(), result.reasoning),
+                None => result.reasoning,
+            };
+            IdentificationCandidate {
+                input_type: InputType::Address,
+                sensitive: false,
+                chain: result.chain,
+                encoding: result.encoding,
+                normalized: result.normalized,
+                confidence: result.confidence,
+                reasoning,
+                role: addr_format.role,
+                linked_from: None,
+                details,
+            }
         })
         .collect()
 }
@@ -138,7 +610,7 @@ fn try_address_detection_for_chain(
 fn try_public_key_derivation_for_chain(
     input: &str,
     chars: &InputCharacteristics,
-    key_type: crate::input::DetectedKeyType,
+    key_type: DetectedKeyType,
     chain_id: &str,
 ) -> Vec<IdentificationCandidate> {
     // Decode public key
@@ -148,75 +620,204 @@ fn try_public_key_derivation_for_chain(
     };
 
     let registry = Registry::get();
-
-    // Get chain config
     let chain_config = match registry.get_chain_config(chain_id) {
         Some(config) => config,
         None => return Vec::new(),
     };
+    let chain_metadata = match registry.chains.iter().find(|c| c.id == chain_id) {
+        Some(chain) => chain,
+        None => return Vec::new(),
+    };
 
-    // Check if chain requires stake key (Cardano) - skip if only 1 PK provided
-    if chain_config.requires_stake_key {
-        return Vec::new();
+    // Typed Cosmos keys derive only with the pipelines of their type, and
+    // amino Bech32 keys name their chain and role through the HRP
+    let wrapped = decode_wrapped_public_key(input);
+    if let Some(cosmos_type) = wrapped.as_ref().and_then(|key| key.cosmos_type) {
+        if !cosmos_type
+            .pipelines()
+            .contains(&chain_config.address_pipeline.as_str())
+        {
+            return Vec::new();
+        }
     }
+    let declared_role = match wrapped.and_then(|key| key.hrp) {
+        Some(hrp) => match chain_metadata
+            .public_key_formats
+            .iter()
+            .find(|f| f.hrps.contains(&hrp))
+        {
+            Some(format) => format.role,
+            None => return Vec::new(),
+        },
+        None => None,
+    };
 
-    // Build pipeline params from chain config
-    let params = json!(chain_config.address_params);
-
-    // Execute pipeline
-    match execute_pipeline(&chain_config.address_pipeline, &key_bytes, &params) {
+    // Derive with the chain's pipeline; the address is validated against its formats
+    let derived =
+        derive_chain_address(chain_id, &key_bytes).and_then(|address| match declared_role {
+            Some(AddressRole::ValidatorOperator) => cosmos_account_to_valoper(&address),
+            _ => Ok(address),
+        });
+    match derived {
         Ok(derived_address) => {
-            // Validate the derived address
-            let derived_chars = extract_characteristics(&derived_address);
-            let chain_metadata = match registry.chains.iter().find(|c| c.id == chain_id) {
-                Some(chain) => chain,
-                None => return Vec::new(),
-            };
-
-            let matches = chain_metadata
-                .address_formats
-                .iter()
-                .any(|addr_format| addr_format.validate_raw(&derived_address, &derived_chars));
-
-            if matches {
-                let curve = match key_type {
-                    crate::input::DetectedKeyType::Secp256k1 { .. } => PublicKeyType::Secp256k1,
-                    crate::input::DetectedKeyType::Ed25519 => PublicKeyType::Ed25519,
-                    crate::input::DetectedKeyType::Sr25519 => PublicKeyType::Sr25519,
-                };
-
-                vec![IdentificationCandidate {
-                    input_type: InputType::PublicKey,
-                    chain: chain_id.to_string(),
-                    encoding: chain_metadata.address_formats[0].encoding,
-                    normalized: derived_address,
-                    confidence: 0.8, // High confidence for derived addresses
-                    reasoning: format!(
-                        "Derived from {} public key using {} pipeline",
-                        curve_name(curve),
-                        chain_config.address_pipeline
-                    ),
-                }]
-            } else {
-                Vec::new()
-            }
+            let reasoning = format!(
+                "Derived from {} public key using {} pipeline",
+                key_type_name(key_type),
+                chain_config.address_pipeline
+            );
+            // Cardano keys derive their enterprise address
+            let details = (chain_metadata.address_formats[0].checksum
+                == Some(ChecksumType::Cardano))
+            .then(|| parse_cardano_address(&derived_address).ok())
+            .flatten()
+            .map(CandidateDetails::Cardano);
+            vec![IdentificationCandidate {
+                input_type: InputType::PublicKey,
+                sensitive: false,
+                chain: chain_id.to_string(),
+                encoding: chain_metadata.address_formats[0].encoding,
+                normalized: derived_address.clone(),
+                confidence: 0.8, // High confidence for derived addresses
+                reasoning: match &details {
+                    Some(details) => format!("{}: {}", details.describe(), reasoning),
+                    None => reasoning,
+                },
+                role: declared_role.or_else(|| address_role(chain_id, &derived_address)),
+                linked_from: None,
+                details,
+            }]
         }
         Err(_) => Vec::new(),
     }
 }
 
-/// Get curve name for display
-fn curve_name(key_type: PublicKeyType) -> &'static str {
+/// The other form of accounts whose bytes are an EVM address
+///
+/// Ethermint Bech32 accounts and `AccountId20` SS58 accounts (Moonbeam) get
+/// their `0x` form, and `0x` addresses on those chains the Bech32 or SS58
+/// form. Linked candidates keep the input type, chain and confidence of the
+/// candidate they come from; forms already reported are skipped.
+fn linked_candidates(candidates: &[IdentificationCandidate]) -> Vec<IdentificationCandidate> {
+    let mut linked: Vec<IdentificationCandidate> = Vec::new();
+    for candidate in candidates {
+        if matches!(
+            candidate.input_type,
+            InputType::Transaction | InputType::Block
+        ) {
+            continue;
+        }
+        let Some((normalized, encoding, role, reason)) = linked_form(candidate) else {
+            continue;
+        };
+        let known = |c: &IdentificationCandidate| {
+            c.chain == candidate.chain && c.normalized.eq_ignore_ascii_case(&normalized)
+        };
+        if candidates.iter().any(known) || linked.iter().any(known) {
+            continue;
+        }
+        linked.push(IdentificationCandidate {
+            input_type: candidate.input_type,
+            sensitive: candidate.sensitive,
+            chain: candidate.chain.clone(),
+            encoding,
+            confidence: candidate.confidence,
+            reasoning: format!(
+                "Same {} account as {} ({})",
+                candidate.chain, candidate.normalized, reason
+            ),
+            role,
+            linked_from: Some(candidate.normalized.clone()),
+            details: None,
+            normalized,
+        });
+    }
+    linked
+}
+
+/// The other form of a candidate's account, its encoding and role, and why they are the same
+fn linked_form(
+    candidate: &IdentificationCandidate,
+) -> Option<(String, EncodingType, Option<AddressRole>, &'static str)> {
+    let is_evm = candidate.normalized.starts_with("0x");
+    if is_ethermint_chain(&candidate.chain) {
+        let reason = "eth_secp256k1 keys share the EVM address bytes";
+        if is_evm {
+            let address = evm_to_ethermint(&candidate.normalized, &candidate.chain).ok()?;
+            Some((
+                address,
+                EncodingType::Bech32,
+                Some(AddressRole::Account),
+                reason,
+            ))
+        } else if candidate.role == Some(AddressRole::Account) {
+            let address = ethermint_to_evm(&candidate.normalized).ok()?;
+            Some((address, EncodingType::Hex, None, reason))
+        } else {
+            None
+        }
+    } else if is_account_id20_chain(&candidate.chain) {
+        let reason = "AccountId20 is the EVM address";
+        if is_evm {
+            let address = evm_to_ss58(&candidate.normalized, &candidate.chain).ok()?;
+            Some((address, EncodingType::SS58, None, reason))
+        } else {
+            let address = ss58_to_evm(&candidate.normalized).ok()?;
+            Some((address, EncodingType::Hex, None, reason))
+        }
+    } else {
+        None
+    }
+}
+
+/// Cosmos role of an address, from the chain format it validates against
+fn address_role(chain_id: &str, address: &str) -> Option<AddressRole> {
+    let chain = Registry::get().chains.iter().find(|c| c.id == chain_id)?;
+    let chars = extract_characteristics(address);
+    chain
+        .address_formats
+        .iter()
+        .filter(|f| f.role.is_some())
+        .find(|f| f.validate_raw(address, &chars))
+        .and_then(|f| f.role)
+}
+
+/// Narrow a public key possibility to the caller's curve hint
+///
+/// An ambiguous 32-byte key takes the hinted scheme; keys on another curve
+/// are dropped. Addresses are kept.
+fn apply_curve_hint(
+    possibility: InputPossibility,
+    curve_hint: Option<PublicKeyType>,
+) -> Option<InputPossibility> {
+    let (InputPossibility::PublicKey { key_type }, Some(curve)) = (possibility, curve_hint) else {
+        return Some(possibility);
+    };
+    let key_type = match (key_type, curve) {
+        (DetectedKeyType::Ed25519OrSr25519, PublicKeyType::Ed25519) => DetectedKeyType::Ed25519,
+        (DetectedKeyType::Ed25519OrSr25519, PublicKeyType::Sr25519) => DetectedKeyType::Sr25519,
+        (DetectedKeyType::Secp256k1 { .. }, PublicKeyType::Secp256k1)
+        | (DetectedKeyType::Ed25519, PublicKeyType::Ed25519)
+        | (DetectedKeyType::Sr25519, PublicKeyType::Sr25519) => key_type,
+        _ => return None,
+    };
+    Some(InputPossibility::PublicKey { key_type })
+}
+
+/// Get the key type name for display
+fn key_type_name(key_type: DetectedKeyType) -> &'static str {
     match key_type {
-        PublicKeyType::Secp256k1 => "secp256k1",
-        PublicKeyType::Ed25519 => "ed25519",
-        PublicKeyType::Sr25519 => "sr25519",
+        DetectedKeyType::Secp256k1 { .. } => "secp256k1",
+        DetectedKeyType::Ed25519 => "ed25519",
+        DetectedKeyType::Sr25519 => "sr25519",
+        DetectedKeyType::Ed25519OrSr25519 => "ed25519 or sr25519",
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cardano::CardanoAddressType;
+    use crate::shared::encoding::bech32 as bech32_encoding;
 
     #[test]
     fn test_identify_empty_input() {
@@ -237,8 +838,7 @@ mod tests {
         let result = identify(input);
 
         // Verify the full pipeline works
-        if result.is_ok() {
-            let candidates = result.unwrap();
+        if let Ok(candidates) = result {
             assert!(!candidates.is_empty());
             // Should return multiple EVM chains
             assert!(candidates.iter().any(|c| c.chain == "ethereum"));
@@ -265,11 +865,10 @@ mod tests {
         let result = identify(input);
 
         // Verify the full pipeline works
-        if result.is_ok() {
-            let candidates = result.unwrap();
+        if let Ok(candidates) = result {
             assert!(!candidates.is_empty());
             // Should have multiple EVM chains
-            assert!(candidates.len() >= 1);
+            assert!(!candidates.is_empty());
             // All should be EVM chains
             let evm_chains = [
                 "ethereum",
@@ -282,6 +881,12 @@ mod tests {
                 "fantom",
                 "celo",
                 "gnosis",
+                "evmos",
+                "injective",
+                "cronos",
+                "darwinia",
+                "moonbeam",
+                "moonriver",
             ];
             assert!(candidates
                 .iter()
@@ -314,8 +919,7 @@ mod tests {
         let result = identify(&tron_addr);
 
         // Verify the full pipeline works
-        if result.is_ok() {
-            let candidates = result.unwrap();
+        if let Ok(candidates) = result {
             assert!(!candidates.is_empty());
             // Should include Tron (if detection works)
             if candidates.iter().any(|c| c.chain == "tron") {
@@ -334,6 +938,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_identify_error_does_not_echo_key_material() {
+        // A hex private key with a typo in its last digit
+        let typo = "0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1g";
+        let message = identify(typo).unwrap_err().to_string();
+        assert!(!message.contains(&typo[..16]), "{}", message);
+        assert!(message.contains("<64 characters>"), "{}", message);
+    }
+
     #[test]
     fn test_identify_full_pipeline_structure() {
         // Test that identify() returns correct structure even if detection fails
@@ -350,11 +963,8 @@ mod tests {
                     assert!(candidate.confidence >= 0.0 && candidate.confidence <= 1.0);
                     assert!(!candidate.reasoning.is_empty());
                     // Verify encoding is valid
-                    match candidate.encoding {
-                        crate::registry::EncodingType::Hex => {
-                            assert!(candidate.normalized.starts_with("0x"))
-                        }
-                        _ => {}
+                    if candidate.encoding == crate::registry::EncodingType::Hex {
+                        assert!(candidate.normalized.starts_with("0x"))
                     }
                 }
                 // Verify sorting (highest confidence first)
@@ -399,6 +1009,12 @@ mod tests {
             "fantom",
             "celo",
             "gnosis",
+            "evmos",
+            "injective",
+            "cronos",
+            "darwinia",
+            "moonbeam",
+            "moonriver",
         ];
         let matched_chains: Vec<_> = result.iter().map(|c| c.chain.as_str()).collect();
         assert!(evm_chains
@@ -431,6 +1047,12 @@ mod tests {
             "fantom",
             "celo",
             "gnosis",
+            "evmos",
+            "injective",
+            "cronos",
+            "darwinia",
+            "moonbeam",
+            "moonriver",
         ];
         let matched_chains: Vec<_> = result.iter().map(|c| c.chain.as_str()).collect();
         assert!(evm_chains
@@ -461,6 +1083,12 @@ mod tests {
             "fantom",
             "celo",
             "gnosis",
+            "evmos",
+            "injective",
+            "cronos",
+            "darwinia",
+            "moonbeam",
+            "moonriver",
         ];
         let matched_chains: Vec<_> = result.iter().map(|c| c.chain.as_str()).collect();
         assert!(evm_chains
@@ -553,6 +1181,573 @@ mod tests {
         assert!(result.iter().any(|c| c.chain == "dogecoin"));
     }
 
+    #[test]
+    fn test_identify_litecoin_p2sh() {
+        // Litecoin M-prefixed P2SH (version 50)
+        let input = "M7uBSTV2qNDHDe2tHfNMqhFkZucgRMpJQk";
+        let result = identify(input).unwrap();
+
+        assert!(result.iter().any(|c| c.chain == "litecoin"));
+        assert!(!result.iter().any(|c| c.chain == "bitcoin"));
+    }
+
+    #[test]
+    fn test_identify_legacy_p2sh_ambiguous_btc_ltc() {
+        // Version 5 P2SH ("3...") is shared by Bitcoin and legacy Litecoin
+        let input = "31h38a54tFMrR8kzBnP2241MFD2EUHtGha";
+        let result = identify(input).unwrap();
+
+        assert!(result.iter().any(|c| c.chain == "bitcoin"));
+        assert!(result.iter().any(|c| c.chain == "litecoin"));
+    }
+
+    #[test]
+    fn test_identify_litecoin_segwit() {
+        let input = "ltc1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysn3s44dy";
+        let result = identify(input).unwrap();
+
+        assert!(result.iter().any(|c| c.chain == "litecoin"));
+        assert!(!result.iter().any(|c| c.chain == "bitcoin"));
+        assert_eq!(result[0].normalized, input);
+    }
+
+    #[test]
+    fn test_identify_dogecoin_p2sh() {
+        // Version 22 P2SH addresses start with "9" or "A"
+        for input in [
+            "9rSHsR8xxKEkKW8Tbv3SGBdiwnQGWZ4bdM",
+            "AFmseVrdL9f9oyCzZefL9tG6UbvhFLcxeB",
+        ] {
+            let result = identify(input).unwrap();
+            assert!(result.iter().any(|c| c.chain == "dogecoin"));
+        }
+    }
+
+    #[test]
+    fn test_identify_testnet_addresses() {
+        // Bitcoin testnet P2PKH (version 111) is shared with Litecoin testnet
+        let result = identify("mfWyW5fc9NUj75YAnFgoRLrjxgLDn2MMth").unwrap();
+        assert!(result.iter().any(|c| c.chain == "bitcoin"));
+        assert!(result.iter().any(|c| c.chain == "litecoin"));
+        assert!(result.iter().all(|c| c.reasoning.contains("testnet")));
+
+        // Dogecoin testnet P2PKH (version 113)
+        let result = identify("nUCBUJGBZjQUjwpLq6MSPbQKDgr7DPLQiL").unwrap();
+        assert!(result.iter().any(|c| c.chain == "dogecoin"));
+
+        // Litecoin testnet P2SH (version 58)
+        let result = identify("QLc1KKsLWovHm79aV22uihS3bwgECPid8z").unwrap();
+        assert!(result.iter().any(|c| c.chain == "litecoin"));
+
+        // Segwit testnet HRPs
+        let result = identify("tb1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysnl25zw8").unwrap();
+        assert!(result.iter().all(|c| c.chain == "bitcoin"));
+        let result = identify("tltc1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysnxzku7w").unwrap();
+        assert!(result.iter().all(|c| c.chain == "litecoin"));
+    }
+
+    #[test]
+    fn test_identify_bitcoin_taproot() {
+        let input = "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0";
+        let result = identify(input).unwrap();
+
+        assert!(result.iter().any(|c| c.chain == "bitcoin"));
+        assert_eq!(result[0].encoding, crate::registry::EncodingType::Bech32m);
+    }
+
+    #[test]
+    fn test_identify_short_p2pkh() {
+        // Base58Check P2PKH with leading zero bytes is shorter than 34 chars
+        let input = "1111111111111111111114oLvT2";
+        let result = identify(input).unwrap();
+
+        assert!(result.iter().any(|c| c.chain == "bitcoin"));
+    }
+
+    #[test]
+    fn test_identify_bitcoin_cash_cashaddr() {
+        let input = "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a";
+        let result = identify(input).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].chain, "bitcoin_cash");
+        assert_eq!(result[0].encoding, crate::registry::EncodingType::CashAddr);
+        assert!(result[0].reasoning.contains("P2PKH"));
+        assert_eq!(result[0].normalized, input);
+    }
+
+    #[test]
+    fn test_identify_bitcoin_cash_cashaddr_without_prefix() {
+        // The prefix is recovered from the checksum and restored when normalizing
+        let result = identify("PPM2QSZNHKS23Z7629MMS6S4CWEF74VCWVN0H829PQ").unwrap();
+        assert!(result.iter().all(|c| c.chain == "bitcoin_cash"));
+        assert!(result[0].reasoning.contains("P2SH"));
+        assert_eq!(
+            result[0].normalized,
+            "bitcoincash:ppm2qsznhks23z7629mms6s4cwef74vcwvn0h829pq"
+        );
+
+        let result = identify("qpm2qsznhks23z7629mms6s4cwef74vcwvqcw003ap").unwrap();
+        assert!(result[0].reasoning.contains("testnet"));
+        assert_eq!(
+            result[0].normalized,
+            "bchtest:qpm2qsznhks23z7629mms6s4cwef74vcwvqcw003ap"
+        );
+    }
+
+    #[test]
+    fn test_identify_bitcoin_cash_invalid_cashaddr() {
+        // Bad checksum, and a valid payload under the wrong prefix
+        assert!(identify("bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6b").is_err());
+        assert!(identify("bchtest:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a").is_err());
+    }
+
+    #[test]
+    fn test_identify_legacy_ambiguous_btc_bch() {
+        // Legacy Base58Check addresses are valid on both Bitcoin and Bitcoin Cash
+        for input in [
+            "1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu",
+            "3CWFddi6m4ndiGyKqzYvsFYagqDLPVMTzC",
+        ] {
+            let result = identify(input).unwrap();
+            let btc = result.iter().find(|c| c.chain == "bitcoin").unwrap();
+            let bch = result.iter().find(|c| c.chain == "bitcoin_cash").unwrap();
+            assert_eq!(btc.confidence, bch.confidence);
+            assert_eq!(bch.encoding, crate::registry::EncodingType::Base58Check);
+        }
+    }
+
+    #[test]
+    fn test_identify_bitcoin_cash_from_public_key() {
+        let input = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let result = identify(input).unwrap();
+
+        let bch = result.iter().find(|c| c.chain == "bitcoin_cash").unwrap();
+        assert_eq!(bch.input_type, InputType::PublicKey);
+        assert_eq!(bch.encoding, crate::registry::EncodingType::CashAddr);
+        assert_eq!(
+            bch.normalized,
+            "bitcoincash:qp63uahgrxged4z5jswyt5dn5v3lzsem6cy4spdc2h"
+        );
+    }
+
+    #[test]
+    fn test_identify_bitcoin_from_public_key() {
+        // P2PKH hashes the key as serialized: compressed and uncompressed
+        // forms of the generator point give different addresses
+        let address = |input: &str| {
+            identify(input)
+                .unwrap()
+                .into_iter()
+                .find(|c| c.chain == "bitcoin" && c.input_type == InputType::PublicKey)
+                .unwrap()
+                .normalized
+        };
+        assert_eq!(
+            address("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+            "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"
+        );
+        assert_eq!(
+            address("0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"),
+            "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm"
+        );
+    }
+
+    #[test]
+    fn test_identify_descriptor_with_checksum() {
+        let input =
+            "wpkh(02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9)#8zl0zxma";
+        let result = identify(input).unwrap();
+
+        let btc = result.iter().find(|c| c.chain == "bitcoin").unwrap();
+        assert_eq!(btc.input_type, InputType::Descriptor);
+        assert_eq!(btc.normalized, "bc1q0ht9tyks4vh7p5p904t340cr9nvahy7u3re7zg");
+        assert_eq!(btc.confidence, 0.9);
+        assert!(btc.reasoning.contains("wpkh()"));
+        assert!(btc.reasoning.contains("valid checksum"));
+        // Litecoin has segwit too; Dogecoin and Bitcoin Cash do not
+        assert!(result
+            .iter()
+            .any(|c| c.chain == "litecoin" && c.normalized.starts_with("ltc1q")));
+        assert!(!result.iter().any(|c| c.chain == "dogecoin"));
+        assert!(!result.iter().any(|c| c.chain == "bitcoin_cash"));
+    }
+
+    #[test]
+    fn test_identify_descriptor_without_checksum() {
+        let input = "pkh([d34db33f/44'/0'/0']02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5)";
+        let result = identify(input).unwrap();
+
+        let btc = result.iter().find(|c| c.chain == "bitcoin").unwrap();
+        assert_eq!(btc.normalized, "1cMh228HTCiwS8ZsaakH8A8wze1JR5ZsP");
+        assert_eq!(btc.confidence, 0.8);
+        assert!(btc.reasoning.contains("no checksum"));
+        let bch = result.iter().find(|c| c.chain == "bitcoin_cash").unwrap();
+        assert_eq!(bch.encoding, crate::registry::EncodingType::CashAddr);
+    }
+
+    #[test]
+    fn test_identify_ranged_descriptor() {
+        // BIP-84 account 0 key, first receive address
+        let input = "wpkh([73c5da0a/84'/0'/0']zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs/0/*)";
+        let result = identify(input).unwrap();
+
+        let btc = result.iter().find(|c| c.chain == "bitcoin").unwrap();
+        assert_eq!(btc.input_type, InputType::Descriptor);
+        assert_eq!(btc.normalized, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+        assert!(btc.reasoning.contains("index 0"));
+    }
+
+    #[test]
+    fn test_identify_extended_public_key_zpub() {
+        // BIP-84 account 0
+        let input = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+        let result = identify(input).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].chain, "bitcoin");
+        assert_eq!(result[0].input_type, InputType::ExtendedPublicKey);
+        assert_eq!(
+            result[0].normalized,
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+        );
+        assert!(result[0].reasoning.contains("depth 3"));
+    }
+
+    #[test]
+    fn test_identify_extended_public_key_xpub_across_chains() {
+        // BIP-32 test vector 1, m/0H
+        let input = "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw";
+        let result = identify(input).unwrap();
+
+        // Declared by Bitcoin, Litecoin and Bitcoin Cash
+        for chain in ["bitcoin", "litecoin", "bitcoin_cash"] {
+            let candidate = result.iter().find(|c| c.chain == chain).unwrap();
+            assert_eq!(candidate.confidence, 0.9);
+        }
+        let btc = result.iter().find(|c| c.chain == "bitcoin").unwrap();
+        assert!(btc.normalized.starts_with('1'));
+        // Plain xpub keys also reach other secp256k1 chains through their pipeline
+        let eth = result.iter().find(|c| c.chain == "ethereum").unwrap();
+        assert_eq!(eth.confidence, 0.5);
+        assert!(eth.normalized.starts_with("0x"));
+        assert!(eth.reasoning.contains("evm pipeline"));
+        assert!(!result.iter().any(|c| c.chain == "dogecoin"));
+    }
+
+    #[test]
+    fn test_identify_extended_public_key_multisig_and_invalid() {
+        // Re-encode the BIP-84 key with the Zpub (P2WSH multisig) version
+        let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+        let mut data = crate::shared::checksum::base58check::decode_data(zpub).unwrap();
+        data[0..4].copy_from_slice(&[0x02, 0xaa, 0x7e, 0xd3]);
+        let multisig_key = crate::shared::checksum::base58check::encode_data(&data);
+        let result = identify(&multisig_key).unwrap();
+        assert_eq!(result[0].chain, "bitcoin");
+        assert_eq!(result[0].normalized, multisig_key);
+        assert!(result[0].reasoning.contains("multisig"));
+
+        // Unknown version bytes
+        data[0..4].copy_from_slice(&[0x04, 0x88, 0xad, 0xe4]);
+        let xprv_version = crate::shared::checksum::base58check::encode_data(&data);
+        assert!(identify(&xprv_version).is_err());
+    }
+
+    #[test]
+    fn test_identify_private_key_wif() {
+        let secret = "0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d";
+        let result = identify("KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617").unwrap();
+
+        assert!(result
+            .iter()
+            .all(|c| c.input_type == InputType::PrivateKey && c.sensitive));
+        assert!(result.iter().all(|c| !c.reasoning.contains(secret)));
+        // Chains declaring the WIF version rank first
+        assert_eq!(result[0].confidence, 0.95);
+        assert!(result.iter().any(|c| c.chain == "bitcoin"
+            && c.normalized == "bc1qmy63mjadtw8nhzl69ukdepwzsyvv4yex5qlmkd"));
+        let eth = result.iter().find(|c| c.chain == "ethereum").unwrap();
+        assert_eq!(eth.confidence, 0.7);
+    }
+
+    #[test]
+    fn test_identify_mnemonic() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let result = identify(phrase).unwrap();
+
+        assert!(result
+            .iter()
+            .all(|c| c.input_type == InputType::Mnemonic && c.sensitive));
+        assert!(result.iter().all(|c| !c.reasoning.contains("abandon")));
+        assert!(result[0].reasoning.contains("English, 12 words, 128-bit"));
+        let btc: Vec<_> = result.iter().filter(|c| c.chain == "bitcoin").collect();
+        assert_eq!(btc.len(), 3);
+        assert!(btc.iter().any(
+            |c| c.normalized == "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+                && c.encoding == crate::registry::EncodingType::Bech32
+                && c.reasoning.contains("m/84'/0'/0'/0/0")
+        ));
+        assert!(result.iter().any(|c| c.chain == "solana"
+            && c.normalized == "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk"));
+        // EVM addresses are EIP-55 checksummed, as for an address input
+        assert!(result.iter().any(|c| c.chain == "ethereum"
+            && c.normalized == "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"));
+        assert!(!result.iter().any(|c| c.chain == "polkadot"));
+
+        // Wordlist words with a wrong checksum are not echoed back
+        let err = identify(&phrase.replace("about", "abandon")).unwrap_err();
+        assert!(err.to_string().contains("checksum"));
+        assert!(!err.to_string().contains("abandon"));
+    }
+
+    #[test]
+    fn test_identify_private_key_hex_is_low_confidence() {
+        let input = "0x0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d";
+        let result = identify(input).unwrap();
+
+        let private: Vec<_> = result
+            .iter()
+            .filter(|c| c.input_type == InputType::PrivateKey)
+            .collect();
+        assert!(!private.is_empty());
+        assert!(private.iter().all(|c| c.sensitive && c.confidence == 0.3));
+        assert!(private.iter().any(|c| c.chain == "ethereum"));
+        assert!(private.iter().any(|c| c.reasoning.contains("sr25519")));
+        // Public key interpretations are still reported, and are not sensitive
+        assert!(result
+            .iter()
+            .filter(|c| c.input_type == InputType::PublicKey)
+            .all(|c| !c.sensitive));
+    }
+
+    #[test]
+    fn test_identify_transaction_hashes() {
+        // Examples from docs/onchain-examples.md
+        let has = |input: &str, chain: &str| {
+            identify(input)
+                .unwrap()
+                .iter()
+                .any(|c| c.chain == chain && c.input_type == InputType::Transaction && !c.sensitive)
+        };
+        let evm = "0xcdf331416ac94df404cfa95b13ecd4b23b2b1de895c945e25ff1b557c597a64e";
+        assert!(has(evm, "ethereum"));
+        assert!(has(evm, "polygon"));
+        assert!(has(evm, "polkadot"));
+        assert!(!has(evm, "bitcoin"));
+        // The prefixed hash outranks the public and private key readings
+        let candidates = identify(evm).unwrap();
+        assert_eq!(candidates[0].input_type, InputType::Transaction);
+        assert!(candidates
+            .iter()
+            .filter(|c| c.input_type != InputType::Transaction)
+            .all(|c| c.confidence < candidates[0].confidence));
+
+        let btc = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+        for chain in [
+            "bitcoin",
+            "litecoin",
+            "dogecoin",
+            "tron",
+            "cardano",
+            "cosmos_hub",
+        ] {
+            assert!(has(btc, chain), "{}", chain);
+        }
+        assert!(!has(btc, "ethereum"));
+        // A bare txid also outranks the public and private key readings
+        let candidates = identify(btc).unwrap();
+        assert_eq!(candidates[0].input_type, InputType::Transaction);
+        assert!(candidates
+            .iter()
+            .filter(|c| c.input_type != InputType::Transaction)
+            .all(|c| c.confidence < candidates[0].confidence));
+        assert!(has(
+            "1000bde6f051bfb65ab62ea8a596f4b3cd5fb0768c3c23e26c474287c1c1490f",
+            "litecoin"
+        ));
+        assert!(has(
+            "5156e18743c2ceba71f40640c75a8402066a8c42e570f17eecda2cc1101575f4",
+            "tron"
+        ));
+
+        // Tendermint hashes are displayed in uppercase
+        let cosmos = "3E0BA99F9A254B4DEC6EE5CB04F833535DD409ECCC26133D8DF0CF943EE9B326";
+        let result = identify(cosmos).unwrap();
+        let confidence = |chain: &str| {
+            result
+                .iter()
+                .find(|c| c.chain == chain && c.input_type == InputType::Transaction)
+                .map(|c| c.confidence)
+        };
+        assert!(confidence("cosmos_hub") > confidence("bitcoin"));
+        let osmosis = identify(&cosmos.to_lowercase())
+            .unwrap()
+            .into_iter()
+            .find(|c| c.chain == "osmosis" && c.input_type == InputType::Transaction)
+            .unwrap();
+        assert_eq!(osmosis.normalized, cosmos);
+
+        let solana = identify("5wpHU1gGYcgKabL7heGGgiKBx3WJMruHiN34sCjTYwQu4sk9H2uMyZsm1P28RqaJPVELtcVxNmSGieq6V5ZZxpDT").unwrap();
+        assert_eq!(solana.len(), 1);
+        assert_eq!(solana[0].chain, "solana");
+        assert_eq!(solana[0].confidence, 0.9);
+    }
+
+    #[test]
+    fn test_identify_signed_evm_transaction() {
+        // EIP-1559 transaction on Polygon (chain id 137)
+        let result = identify("0x02f8ad818907843b9aca008506fc23ac00825208943535353535353535353535353535353535353535880de0b6b3a764000080f838f7943535353535353535353535353535353535353535e1a0000000000000000000000000000000000000000000000000000000000000000101a04e6a01e17f5f87fa802238c9183f93a9f2a1ea6a7f644337953c4681cd886e09a0619b8940fea33f2c2e927ea56c7c40794ddf9497534ccff67685e41d33bb0570").unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].input_type, InputType::SignedTransaction);
+        assert_eq!(result[0].chain, "polygon");
+        assert_eq!(
+            result[0].normalized,
+            "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"
+        );
+        assert!(result[0].reasoning.contains("EIP-1559"));
+
+        // Without EIP-155 replay protection every EVM chain is plausible
+        let result = identify("0xf864808504a817c80082520894353535353535353535353535353535353535353501801ba00789dee922a809e21365f515c10b61bdc44f18eb5cf7e4f71d492ac8be157576a041eb57e1c0da39e83579e0d2f1d2c3df7564943491493f9dda28d95073406276").unwrap();
+        // 16 EVM chains, plus the Bech32 form of the sender on the 3 Ethermint
+        // chains and its SS58 form on the 3 AccountId20 chains
+        assert_eq!(result.len(), 22);
+        assert!(result.iter().all(|c| c.confidence == 0.6));
+
+        // Chain id 999 is not in the registry
+        let err = identify("0x02f8538203e7800101830186a08080826080c080a0e5648161e95dbf2bfc687b72b745269fa906031e2108118050aba59524a23c40a03f473e888d91171543d2198a1924e98989f2ce235fb107a682430cd1c75a39a7").unwrap_err();
+        assert!(err.to_string().contains("chain id 999"));
+    }
+
+    #[test]
+    fn test_identify_block_hash() {
+        // Bitcoin genesis block
+        let result =
+            identify("000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f").unwrap();
+        assert_eq!(result[0].input_type, InputType::Block);
+        assert!(result
+            .iter()
+            .any(|c| c.chain == "bitcoin" && c.input_type == InputType::Block));
+        assert!(!result
+            .iter()
+            .any(|c| c.chain == "litecoin" && c.input_type == InputType::Block));
+    }
+
+    #[test]
+    fn test_identify_wrapped_public_keys() {
+        let public_key_addresses = |input: &str| {
+            let mut addresses: Vec<(String, String)> = identify(input)
+                .unwrap()
+                .into_iter()
+                .filter(|c| c.input_type == InputType::PublicKey)
+                .map(|c| (c.chain, c.normalized))
+                .collect();
+            addresses.sort();
+            addresses
+        };
+
+        let secp256k1 = public_key_addresses(
+            "0x024bc2a31265153f07e70e0bab08724e6b85e217f8cd628ceb62974247bb493382",
+        );
+        assert!(secp256k1.contains(&(
+            "ethereum".to_string(),
+            "0x9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f".to_string()
+        )));
+        for input in [
+            "AkvCoxJlFT8H5w4LqwhyTmuF4hf4zWKM62KXQke7STOC",
+            "-----BEGIN PUBLIC KEY-----\nMDYwEAYHKoZIzj0CAQYFK4EEAAoDIgACS8KjEmUVPwfnDgurCHJOa4XiF/jNYozrYpdCR7tJM4I=\n-----END PUBLIC KEY-----",
+        ] {
+            assert_eq!(public_key_addresses(input), secp256k1, "{}", input);
+        }
+
+        // Typed Cosmos JSON keys derive only on chains of their pipeline
+        let pipeline_chains = |pipeline: &str| -> Vec<(String, String)> {
+            secp256k1
+                .iter()
+                .filter(|(chain, _)| {
+                    Registry::get()
+                        .get_chain_config(chain)
+                        .is_some_and(|config| config.address_pipeline == pipeline)
+                })
+                .cloned()
+                .collect()
+        };
+        let cosmos = public_key_addresses(
+            r#"{"@type":"/cosmos.crypto.secp256k1.PubKey","key":"AkvCoxJlFT8H5w4LqwhyTmuF4hf4zWKM62KXQke7STOC"}"#,
+        );
+        assert_eq!(cosmos, pipeline_chains("cosmos"));
+        assert!(cosmos.iter().any(|(chain, _)| chain == "cosmos_hub"));
+        let ethermint = public_key_addresses(
+            r#"{"@type":"/ethermint.crypto.v1.ethsecp256k1.PubKey","key":"AkvCoxJlFT8H5w4LqwhyTmuF4hf4zWKM62KXQke7STOC"}"#,
+        );
+        assert_eq!(ethermint, pipeline_chains("ethermint"));
+        assert!(ethermint
+            .iter()
+            .any(|(_, address)| address == "inj1nk9x9ajk4rgkzhqjjn7hr6w0k0jg2kj0knl55v"));
+        // Ed25519 consensus keys give only valcons addresses
+        let consensus = identify(
+            r#"{"type": "tendermint/PubKeyEd25519", "value": "11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo="}"#,
+        )
+        .unwrap();
+        assert!(consensus.iter().any(|c| c.chain == "cosmos_hub"));
+        assert!(consensus
+            .iter()
+            .all(|c| c.role == Some(AddressRole::ValidatorConsensus)));
+
+        // Amino Bech32 keys name their chain and role
+        let roles = |input: &str| -> Vec<(String, String, Option<AddressRole>)> {
+            identify(input)
+                .unwrap()
+                .into_iter()
+                .filter(|c| c.input_type == InputType::PublicKey)
+                .map(|c| (c.chain, c.normalized, c.role))
+                .collect()
+        };
+        assert_eq!(
+            roles("cosmospub1addwnpepqf9u9gcjv52n7pl8pc96kzrjfe4ctcshlrxk9r8tv2t5y3amfyecyrxe3kt"),
+            vec![(
+                "cosmos_hub".to_string(),
+                "cosmos1hkfq3zahaqkkzx5mjnamwjsfpq2jk7z0emlrvp".to_string(),
+                Some(AddressRole::Account)
+            )]
+        );
+        let valoper = roles(
+            "cosmosvaloperpub1addwnpepqf9u9gcjv52n7pl8pc96kzrjfe4ctcshlrxk9r8tv2t5y3amfyecy29uuec",
+        );
+        assert_eq!(valoper.len(), 1);
+        assert!(valoper[0].1.starts_with("cosmosvaloper1"));
+        assert_eq!(valoper[0].2, Some(AddressRole::ValidatorOperator));
+        assert_eq!(
+            roles("osmopub1addwnpepqf9u9gcjv52n7pl8pc96kzrjfe4ctcshlrxk9r8tv2t5y3amfyecyx2ey7s")[0]
+                .0,
+            "osmosis"
+        );
+
+        // Containers declare Ed25519, so no sr25519 interpretation
+        let ed25519 = public_key_addresses(
+            "0xd75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        );
+        let pem = "-----BEGIN PUBLIC KEY-----\nMCowBQYDK2VwAyEA11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo=\n-----END PUBLIC KEY-----\n";
+        let candidates = identify(pem).unwrap();
+        assert!(candidates.iter().all(|c| c.reasoning.contains("ed25519")));
+        assert!(public_key_addresses(pem)
+            .iter()
+            .all(|address| ed25519.contains(address)));
+        assert!(public_key_addresses(pem)
+            .iter()
+            .any(|(chain, _)| chain == "solana"));
+    }
+
+    #[test]
+    fn test_identify_descriptor_invalid() {
+        // Wrong checksum
+        let result = identify(
+            "wpkh(02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9)#8zl0zxmb",
+        );
+        assert!(result.unwrap_err().to_string().contains("checksum"));
+        // Unsupported descriptor function
+        assert!(identify("raw(deadbeef)#89f8spxm").is_err());
+    }
+
     // 1.3 Cosmos Ecosystem (10 chains)
     #[test]
     fn test_identify_cosmos_hub() {
@@ -581,6 +1776,83 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_identify_cosmos_address_roles() {
+        let role_of = |input: &str| {
+            identify(input)
+                .unwrap()
+                .into_iter()
+                .find(|c| c.chain == "cosmos_hub")
+                .map(|c| c.role)
+        };
+        assert_eq!(
+            role_of("cosmos1w508d6qejxtdg4y5r3zarvary0c5xw7k6ah60c"),
+            Some(Some(AddressRole::Account))
+        );
+        // The valoper HRP starts with the account HRP but is its own role
+        assert_eq!(
+            role_of("cosmosvaloper1w508d6qejxtdg4y5r3zarvary0c5xw7klfr0rt"),
+            Some(Some(AddressRole::ValidatorOperator))
+        );
+        assert_eq!(
+            role_of("cosmosvalcons1y8lrrhap2j3xzcntlp2qgm7jyudhhm2tfeslut"),
+            Some(Some(AddressRole::ValidatorConsensus))
+        );
+        // Non-Cosmos addresses carry no role
+        let bitcoin = identify("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").unwrap();
+        assert!(bitcoin.iter().all(|c| c.role.is_none()));
+    }
+
+    #[test]
+    fn test_identify_ethermint_linked_candidates() {
+        let evm = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+        let inj = "inj1nk9x9ajk4rgkzhqjjn7hr6w0k0jg2kj0knl55v";
+
+        // 0x address: the Bech32 account is linked on every Ethermint chain
+        let candidates = identify(evm).unwrap();
+        let linked = candidates
+            .iter()
+            .find(|c| c.chain == "injective" && c.linked_from.is_some())
+            .unwrap();
+        assert_eq!(linked.normalized, inj);
+        assert_eq!(linked.linked_from.as_deref(), Some(evm));
+        assert_eq!(linked.role, Some(AddressRole::Account));
+        assert!(candidates
+            .iter()
+            .any(|c| c.chain == "evmos" && c.normalized.starts_with("evmos1")));
+        assert!(!candidates
+            .iter()
+            .any(|c| c.chain == "ethereum" && c.linked_from.is_some()));
+
+        // Bech32 account: the 0x address is linked on its chain only
+        let candidates = identify(inj).unwrap();
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].normalized, inj);
+        assert_eq!(candidates[0].linked_from, None);
+        assert_eq!(candidates[1].chain, "injective");
+        assert_eq!(candidates[1].normalized, evm);
+        assert_eq!(candidates[1].encoding, EncodingType::Hex);
+
+        // Public keys derive the Bech32 account, linked to the 0x form
+        let candidates =
+            identify("0x024bc2a31265153f07e70e0bab08724e6b85e217f8cd628ceb62974247bb493382")
+                .unwrap();
+        let cronos: Vec<&str> = candidates
+            .iter()
+            .filter(|c| c.chain == "cronos" && c.input_type == InputType::PublicKey)
+            .map(|c| c.normalized.as_str())
+            .collect();
+        assert_eq!(
+            cronos,
+            vec!["crc1nk9x9ajk4rgkzhqjjn7hr6w0k0jg2kj0tyjx52", evm]
+        );
+
+        // Valoper addresses are not accounts and stay unlinked
+        let candidates = identify("evmosvaloper1nk9x9ajk4rgkzhqjjn7hr6w0k0jg2kj0n4kwap").unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].role, Some(AddressRole::ValidatorOperator));
+    }
+
     #[test]
     fn test_identify_osmosis() {
         // Test Osmosis address
@@ -710,42 +1982,178 @@ mod tests {
         }
     }
 
-    // 1.4 Substrate/Polkadot (3 chains)
+    // 1.4 Substrate/Polkadot (3 chains plus the SS58 registry)
     #[test]
     fn test_identify_polkadot() {
-        // Test Polkadot address
-        let input = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+        // Alice's account under the Polkadot prefix (0)
+        let input = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
         let result = identify(input).unwrap();
 
-        assert!(!result.is_empty());
-        // Should match Polkadot
-        assert!(result.iter().any(|c| c.chain == "polkadot"));
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].chain, "polkadot");
     }
 
     #[test]
     fn test_identify_kusama() {
-        // Test Kusama address
-        let input = "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty";
+        // Alice's account under the Kusama prefix (2)
+        let input = "HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F";
         let result = identify(input).unwrap();
 
-        assert!(!result.is_empty());
-        // Should match Kusama
-        assert!(result.iter().any(|c| c.chain == "kusama"));
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].chain, "kusama");
     }
 
     #[test]
     fn test_identify_substrate() {
-        // Test Substrate address (generic SS58)
-        // Using a valid SS58 address with different prefix
+        // Generic Substrate prefix (42) is only claimed by the fallback chain
         let input = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
         let result = identify(input).unwrap();
 
-        assert!(!result.is_empty());
-        // Should match at least one Substrate-based chain
-        let substrate_chains = ["polkadot", "kusama", "substrate"];
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].chain, "substrate");
+    }
+
+    #[test]
+    fn test_identify_ed25519_or_sr25519_public_key() {
+        // Alice's sr25519 public key
+        let input = "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+        let public_keys = |options: &IdentifyOptions| -> Vec<IdentificationCandidate> {
+            identify_with_options(input, options)
+                .unwrap()
+                .into_iter()
+                .filter(|c| c.input_type == InputType::PublicKey)
+                .collect()
+        };
+
+        // One candidate per chain; sr25519 chains report sr25519
+        let candidates = public_keys(&IdentifyOptions::default());
+        let polkadot: Vec<_> = candidates
+            .iter()
+            .filter(|c| c.chain == "polkadot")
+            .collect();
+        assert_eq!(polkadot.len(), 1);
+        assert_eq!(
+            polkadot[0].normalized,
+            "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"
+        );
+        assert!(polkadot[0].reasoning.contains("from sr25519 public key"));
+        let solana = candidates.iter().find(|c| c.chain == "solana").unwrap();
+        assert!(solana.reasoning.contains("ed25519 or sr25519 public key"));
+
+        // A hint narrows the scheme
+        let sr25519 = public_keys(&IdentifyOptions {
+            curve_hint: Some(PublicKeyType::Sr25519),
+        });
+        let mut chains: Vec<_> = sr25519.iter().map(|c| c.chain.as_str()).collect();
+        chains.sort();
+        assert_eq!(chains, vec!["kusama", "polkadot", "substrate"]);
+        let ed25519 = public_keys(&IdentifyOptions {
+            curve_hint: Some(PublicKeyType::Ed25519),
+        });
+        assert!(ed25519.iter().any(|c| c.chain == "solana"));
+        assert!(ed25519
+            .iter()
+            .all(|c| c.reasoning.contains("from ed25519 public key")));
+        // Substrate chains also hold Ed25519 accounts
+        assert!(ed25519.iter().any(|c| c.chain == "polkadot"
+            && c.normalized == "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"));
+        assert!(public_keys(&IdentifyOptions {
+            curve_hint: Some(PublicKeyType::Secp256k1),
+        })
+        .is_empty());
+    }
+
+    #[test]
+    fn test_identify_ss58_registry_networks() {
+        // Alice's account under registry prefixes, one- and two-byte
+        let cases = [
+            ("ajYMsCKsEAhEvHpeA4XqsfiA9v1CdzZPrCfS6pEfeGHW9j8", "astar"),
+            ("25fqepuLngYL2DK9ApTejNzqPadUUZ9ALYyKWX2jyvEiuZLa", "acala"),
+            (
+                "bt7zDqc4kAcKZ6kWup5yyuvRAbeYPhXGxwXY4CguG476A9MCE",
+                "bittensor",
+            ),
+        ];
+        for (input, chain) in cases {
+            let result = identify(input).unwrap();
+            assert_eq!(result.len(), 1, "{}", input);
+            assert_eq!(result[0].chain, chain);
+            assert_eq!(result[0].input_type, InputType::Address);
+        }
+
+        // A prefix no network registers falls back to the generic chain
+        let result = identify("WuZJaJUEkhCyZQzZLSMqHsqJFWNyzyWHhRMQLwzzX1DTzEkeG").unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].chain, "substrate");
+    }
+
+    #[test]
+    fn test_identify_account_id20_linked_candidates() {
+        let evm = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+        let moonbeam = "6voedjU2oqLPWe1E9HrSPXJ7kw6GCAbEK";
+
+        // SS58 form under the Moonbeam prefix, linked to the EVM address
+        let result = identify(moonbeam).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].chain, "moonbeam");
+        assert_eq!(result[0].encoding, EncodingType::SS58);
+        assert_eq!(result[1].normalized, evm);
+        assert_eq!(result[1].linked_from.as_deref(), Some(moonbeam));
+
+        // The EVM address is a Moonbeam account, reported with its SS58 form
+        let result = identify(evm).unwrap();
         assert!(result
             .iter()
-            .any(|c| substrate_chains.contains(&c.chain.as_str())));
+            .any(|c| c.chain == "moonbeam" && c.normalized == evm));
+        let linked = result
+            .iter()
+            .find(|c| c.chain == "moonbeam" && c.linked_from.is_some())
+            .unwrap();
+        assert_eq!(linked.normalized, moonbeam);
+        assert_eq!(linked.encoding, EncodingType::SS58);
+        assert!(linked.reasoning.contains("AccountId20"));
+    }
+
+    #[test]
+    fn test_identify_evm_address_chains() {
+        // Exactly the chains the metadata declares with the evm or ethermint
+        // pipeline; SS58 registry networks never resolve 0x addresses
+        let result = identify("0x742d35Cc6634C0532925a3b844Bc454e4438f44e").unwrap();
+        let mut chains: Vec<&str> = result
+            .iter()
+            .filter(|c| c.linked_from.is_none())
+            .map(|c| c.chain.as_str())
+            .collect();
+        chains.sort_unstable();
+        assert_eq!(
+            chains,
+            [
+                "arbitrum",
+                "avalanche",
+                "base",
+                "bsc",
+                "celo",
+                "cronos",
+                "darwinia",
+                "ethereum",
+                "evmos",
+                "fantom",
+                "gnosis",
+                "injective",
+                "moonbeam",
+                "moonriver",
+                "optimism",
+                "polygon",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_identify_ss58_invalid_checksum() {
+        // Alice's Substrate address with the last character changed
+        assert!(identify("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQX").is_err());
+        // Same for the Polkadot form
+        assert!(identify("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp6").is_err());
     }
 
     // 1.5 Other Chains (3 chains)
@@ -782,6 +2190,127 @@ mod tests {
         assert!(result.iter().any(|c| c.chain == "tron"));
     }
 
+    #[test]
+    fn test_identify_cardano_cip19_header() {
+        let cardano = |input: &str| -> Vec<IdentificationCandidate> {
+            identify(input)
+                .map(|candidates| {
+                    candidates
+                        .into_iter()
+                        .filter(|c| c.chain == "cardano")
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        // CIP-19 base address (type 0), longer than the BIP-173 limit
+        let base = cardano("addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x");
+        assert_eq!(base.len(), 1);
+        let address = *base[0].details.as_ref().unwrap().cardano().unwrap();
+        assert_eq!(address.address_type, CardanoAddressType::Base);
+        assert_eq!(address.network_id, 1);
+        assert_eq!(
+            ::hex::encode(address.payment.unwrap().hash()),
+            "9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e"
+        );
+        assert!(base[0]
+            .reasoning
+            .starts_with("Cardano base address (payment key hash, stake key hash), network id 1"));
+
+        // Testnet pointer address
+        let pointer =
+            cardano("addr_test1gz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer5pnz75xxcrdw5vky");
+        assert_eq!(pointer.len(), 1);
+        assert!(pointer[0].reasoning.contains("stake pointer 2498243/27/3"));
+        assert!(pointer[0].reasoning.contains("testnet"));
+        assert_eq!(
+            pointer[0]
+                .details
+                .as_ref()
+                .unwrap()
+                .cardano()
+                .unwrap()
+                .network(),
+            Some(Network::Testnet)
+        );
+
+        let reward = cardano("stake178phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcccycj5");
+        assert_eq!(
+            reward[0]
+                .details
+                .as_ref()
+                .unwrap()
+                .cardano()
+                .unwrap()
+                .stake
+                .map(|c| c.is_script()),
+            Some(true)
+        );
+
+        // Valid Bech32, but a testnet header under `addr`
+        let hash =
+            ::hex::decode("9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e").unwrap();
+        let data =
+            bech32_encoding::convert_bits(&[&[0x60][..], &hash].concat(), 8, 5, true).unwrap();
+        let mismatched = bech32_encoding::encode(
+            "addr",
+            &bech32_encoding::bytes_to_u5(&data),
+            bech32::Variant::Bech32,
+        )
+        .unwrap();
+        assert!(cardano(&mismatched).is_empty());
+    }
+
+    #[test]
+    fn test_identify_cardano_byron_addresses() {
+        // Icarus-style mainnet address: the only candidate, not Solana or Base58
+        let icarus =
+            identify("Ae2tdPwUPEZFRbyhz3cpfC2CumGzNkFBN2L42rcUc2yjQpEkxDbkPodpMAi").unwrap();
+        assert_eq!(icarus.len(), 1);
+        assert_eq!(icarus[0].chain, "cardano");
+        assert_eq!(icarus[0].encoding, EncodingType::Base58);
+        let byron = icarus[0].details.as_ref().unwrap().byron().unwrap();
+        assert_eq!(byron.protocol_magic, None);
+        assert!(icarus[0]
+            .reasoning
+            .starts_with("Cardano Byron address (public key), mainnet"));
+
+        // Daedalus address with an encrypted derivation path
+        let daedalus = identify("DdzFFzCqrhsrcTVhLygT24QwTnNqQqQ8mZrq5jykUzMveU26sxaH529kMpo7VhPrt5pwW3dXeB2k3EEvKcNBRmzCfcQ7dTkyGzTs658C").unwrap();
+        assert_eq!(daedalus.len(), 1);
+        assert!(daedalus[0]
+            .details
+            .as_ref()
+            .and_then(CandidateDetails::byron)
+            .unwrap()
+            .derivation_path
+            .is_some());
+
+        // Testnet address reports its protocol magic
+        let testnet =
+            identify("2cWKMJemoBakF2dV6Xmxq9JHZRrMGghVJ7fGGrJXt6hJfupmXJMAccTT66GsVzXY8yTJc")
+                .unwrap();
+        assert_eq!(testnet.len(), 1);
+        assert_eq!(
+            testnet[0]
+                .details
+                .as_ref()
+                .unwrap()
+                .byron()
+                .unwrap()
+                .protocol_magic,
+            Some(1097911063)
+        );
+        assert!(testnet[0].reasoning.contains("protocol magic 1097911063"));
+        assert!(testnet[0].reasoning.contains("testnet"));
+
+        // A corrupted CRC is not an address
+        assert!(
+            identify("Ae2tdPwUPEZFRbyhz3cpfC2CumGzNkFBN2L42rcUc2yjQpEkxDbkPodpMAj")
+                .map_or(true, |candidates| candidates.is_empty())
+        );
+    }
+
     #[test]
     fn test_identify_cardano() {
         // Test Cardano address
@@ -829,6 +2358,12 @@ mod tests {
             "fantom",
             "celo",
             "gnosis",
+            "evmos",
+            "injective",
+            "cronos",
+            "darwinia",
+            "moonbeam",
+            "moonriver",
         ];
         let matched_chains: Vec<_> = result.iter().map(|c| c.chain.as_str()).collect();
         assert!(evm_chains
@@ -859,6 +2394,12 @@ mod tests {
             "fantom",
             "celo",
             "gnosis",
+            "evmos",
+            "injective",
+            "cronos",
+            "darwinia",
+            "moonbeam",
+            "moonriver",
         ];
         let matched_chains: Vec<_> = result.iter().map(|c| c.chain.as_str()).collect();
         assert!(evm_chains
@@ -917,8 +2458,10 @@ mod tests {
                     || c.chain == "kusama"
             }) {
                 // Found Ed25519 chain match
+                // Polkadot also reads a 0x-prefixed 32-byte value as a transaction hash
                 let ed25519_match = candidates
                     .iter()
+                    .filter(|c| c.input_type != InputType::Transaction)
                     .find(|c| {
                         c.chain == "solana"
                             || c.chain == "cardano"
@@ -1001,8 +2544,10 @@ mod tests {
                 .any(|&chain| matched_chains.contains(&chain))
             {
                 // Found Substrate chain match
+                // Polkadot also reads a 0x-prefixed 32-byte value as a transaction hash
                 let substrate_match = candidates
                     .iter()
+                    .filter(|c| c.input_type != InputType::Transaction)
                     .find(|c| substrate_chains.contains(&c.chain.as_str()))
                     .unwrap();
                 assert!(
@@ -1028,8 +2573,10 @@ mod tests {
                 .any(|&chain| matched_chains.contains(&chain))
             {
                 // Found Substrate chain match
+                // Polkadot also reads a 0x-prefixed 32-byte value as a transaction hash
                 let substrate_match = candidates
                     .iter()
+                    .filter(|c| c.input_type != InputType::Transaction)
                     .find(|c| substrate_chains.contains(&c.chain.as_str()))
                     .unwrap();
                 assert!(
@@ -1206,6 +2753,12 @@ mod tests {
             "fantom",
             "celo",
             "gnosis",
+            "evmos",
+            "injective",
+            "cronos",
+            "darwinia",
+            "moonbeam",
+            "moonriver",
         ];
 
         for chain_id in &evm_chains {
@@ -1248,9 +2801,9 @@ mod tests {
         let substrate_tests = vec![
             (
                 "polkadot",
-                "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+                "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5",
             ),
-            ("kusama", "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty"),
+            ("kusama", "HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F"),
         ];
 
         for (chain_id, address) in substrate_tests {
@@ -1324,7 +2877,7 @@ mod tests {
 
     #[test]
     fn test_try_public_key_derivation_ed25519_cosmos() {
-        // Test Ed25519 key → Cosmos address derivation
+        // Test Ed25519 consensus key → Cosmos validator consensus address derivation
         let input = "0x9f7f8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4a5a6a7a8a9";
         let chars = extract_characteristics(input);
         let key_type = crate::input::DetectedKeyType::Ed25519;
@@ -1332,12 +2885,28 @@ mod tests {
 
         let candidates = try_public_key_derivation_for_chain(input, &chars, key_type, chain_id);
 
-        // Should derive to Cosmos address
-        if !candidates.is_empty() {
-            assert_eq!(candidates[0].chain, "cosmos_hub");
-            assert_eq!(candidates[0].input_type, InputType::PublicKey);
-            assert!(candidates[0].normalized.starts_with("cosmos1"));
-        }
+        // Should derive to a Cosmos valcons address, not an account
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].chain, "cosmos_hub");
+        assert_eq!(candidates[0].input_type, InputType::PublicKey);
+        assert!(candidates[0].normalized.starts_with("cosmosvalcons1"));
+    }
+
+    #[test]
+    fn test_try_public_key_derivation_secp256k1_cosmos() {
+        // Test secp256k1 key → Cosmos account address derivation
+        let input = "0x0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let chars = extract_characteristics(input);
+        let key_type = crate::input::DetectedKeyType::Secp256k1 { compressed: true };
+
+        let candidates = try_public_key_derivation_for_chain(input, &chars, key_type, "osmosis");
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(
+            candidates[0].normalized,
+            "osmo1w508d6qejxtdg4y5r3zarvary0c5xw7kjxy2e2"
+        );
+        assert!(candidates[0].reasoning.contains("secp256k1"));
     }
 
     #[test]
@@ -1359,17 +2928,33 @@ mod tests {
     }
 
     #[test]
-    fn test_try_public_key_derivation_cardano_requires_stake_key() {
-        // Test Cardano with single PK (should be excluded due to requires_stake_key)
-        let input = "0x9f7f8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4a5a6a7a8a9";
+    fn test_try_public_key_derivation_cardano_enterprise_address() {
+        // A single Cardano key derives its enterprise address (CIP-19 payment key)
+        let input = "0x73fea80d424276ad0978d4fe5310e8bc2d485f5f6bb3bf87612989f112ad5a7d";
         let chars = extract_characteristics(input);
         let key_type = crate::input::DetectedKeyType::Ed25519;
         let chain_id = "cardano";
 
         let candidates = try_public_key_derivation_for_chain(input, &chars, key_type, chain_id);
 
-        // Should return empty (Cardano requires stake key)
-        assert!(candidates.is_empty());
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(
+            candidates[0].normalized,
+            "addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8"
+        );
+        assert_eq!(
+            candidates[0]
+                .details
+                .as_ref()
+                .unwrap()
+                .cardano()
+                .unwrap()
+                .address_type,
+            CardanoAddressType::Enterprise
+        );
+        assert!(candidates[0]
+            .reasoning
+            .starts_with("Cardano enterprise address (payment key hash), network id 1"));
     }
 
     #[test]
@@ -1516,17 +3101,27 @@ mod tests {
     }
 
     #[test]
-    fn test_edge_case_cardano_single_pk_excluded() {
-        // Cardano with single PK (should be excluded)
+    fn test_edge_case_cardano_single_pk_derives_enterprise_address() {
+        // A single Cardano key is not excluded: identify reports its enterprise address
         let input = "0x9f7f8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4a5a6a7a8a9";
-        let chars = extract_characteristics(input);
-        let key_type = crate::input::DetectedKeyType::Ed25519;
-        let chain_id = "cardano";
-
-        let candidates = try_public_key_derivation_for_chain(input, &chars, key_type, chain_id);
+        let candidates = identify(input).unwrap();
 
-        // Should return empty (Cardano requires stake key)
-        assert!(candidates.is_empty());
+        let cardano: Vec<_> = candidates
+            .iter()
+            .filter(|c| c.chain == "cardano" && c.input_type == InputType::PublicKey)
+            .collect();
+        assert_eq!(cardano.len(), 1);
+        assert!(cardano[0].normalized.starts_with("addr1v"));
+        assert_eq!(
+            cardano[0]
+                .details
+                .as_ref()
+                .unwrap()
+                .cardano()
+                .unwrap()
+                .network_id,
+            1
+        );
     }
 
     #[test]
@@ -1592,11 +3187,17 @@ mod tests {
             "fantom",
             "celo",
             "gnosis",
+            "evmos",
+            "injective",
+            "cronos",
+            "darwinia",
+            "moonbeam",
+            "moonriver",
         ];
         let matched_chains: Vec<_> = result.iter().map(|c| c.chain.as_str()).collect();
         let matched_evm_count = evm_chains
             .iter()
-            .filter(|&chain| matched_chains.contains(&chain))
+            .filter(|chain| matched_chains.contains(chain))
             .count();
         assert!(matched_evm_count >= 1); // At least one EVM chain
     }
@@ -1641,7 +3242,7 @@ mod tests {
         let matched_chains: Vec<_> = result.iter().map(|c| c.chain.as_str()).collect();
         let matched_count = secp256k1_chains
             .iter()
-            .filter(|&chain| matched_chains.contains(&chain))
+            .filter(|chain| matched_chains.contains(chain))
             .count();
         assert!(matched_count >= 1); // At least one secp256k1 chain
     }
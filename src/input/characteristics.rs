@@ -84,6 +84,18 @@ fn detect_encoding(input: &str) -> (Vec<EncodingType>, Option<String>) {
         }
     }
 
+    // Try CashAddr (Bitcoin Cash): "prefix:payload" is checked against its own
+    // prefix; a bare payload can only be verified once chain prefixes are known
+    if input.contains(':') {
+        use crate::shared::encoding::cashaddr;
+        if let Ok((prefix, _, _)) = cashaddr::decode(input, &[]) {
+            hrp = Some(prefix);
+            encodings.push(EncodingType::CashAddr);
+        }
+    } else if is_cashaddr_payload(&input.to_lowercase()) {
+        encodings.push(EncodingType::CashAddr);
+    }
+
     // Try hex encoding
     if let Some(hex_part) = input.strip_prefix("0x") {
         if hex_part.chars().all(|c| c.is_ascii_hexdigit()) {
@@ -102,21 +114,14 @@ fn detect_encoding(input: &str) -> (Vec<EncodingType>, Option<String>) {
         }
     }
 
-    // Try SS58 (Substrate - Base58 with specific prefix and SS58 checksum)
-    // SS58 addresses start with '1', '3', or '5' but have different structure than Base58Check
-    // SS58 addresses are typically 35-48 chars (longer than Base58Check which is ~34 chars)
-    if is_base58(input)
-        && input.len() >= 35
-        && (input.starts_with('1') || input.starts_with('3') || input.starts_with('5'))
-    {
-        // Try to decode as Base58 to check structure
-        use crate::shared::encoding::base58;
-        if let Ok(decoded) = base58::decode(input) {
-            // SS58 addresses have structure: prefix_bytes (1-2 bytes) + 32-byte account_id + 2-byte checksum
-            // So decoded length should be 35-36 bytes (not 25 like Base58Check)
-            if decoded.len() >= 35 && decoded.len() <= 36 {
-                encodings.push(EncodingType::SS58);
-            }
+    // Try SS58 (Substrate - Base58 with network prefix and SS58 checksum)
+    // The leading character depends on the network prefix (1 for Polkadot, 5 for Substrate)
+    if is_base58(input) {
+        use crate::shared::encoding::ss58;
+        // prefix_bytes (1-2 bytes) + account id (20 or 32 bytes) or ECDSA key (33 bytes)
+        // + 2-byte checksum, checksum verified; short account indices are left out
+        if ss58::decode(input).is_ok_and(|(_, payload)| payload.len() >= 20) {
+            encodings.push(EncodingType::SS58);
         }
     }
 
@@ -138,7 +143,9 @@ fn detect_char_set(input: &str, encodings: &[EncodingType]) -> CharSet {
     if let Some(first_encoding) = encodings.first() {
         match first_encoding {
             EncodingType::Hex => CharSet::Hex,
-            EncodingType::Bech32 | EncodingType::Bech32m => CharSet::Base32,
+            EncodingType::Bech32 | EncodingType::Bech32m | EncodingType::CashAddr => {
+                CharSet::Base32
+            }
             EncodingType::Base58 | EncodingType::Base58Check | EncodingType::SS58 => {
                 CharSet::Base58
             }
@@ -155,6 +162,15 @@ fn detect_char_set(input: &str, encodings: &[EncodingType]) -> CharSet {
     }
 }
 
+/// Check if string looks like a CashAddr payload: Bech32 charset, starting with
+/// the P2PKH ('q') or P2SH ('p') type character, 42-112 chars
+fn is_cashaddr_payload(payload: &str) -> bool {
+    const CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+    (42..=112).contains(&payload.len())
+        && (payload.starts_with('q') || payload.starts_with('p'))
+        && payload.chars().all(|c| CHARSET.contains(c))
+}
+
 /// Check if string is valid Base58
 fn is_base58(input: &str) -> bool {
     // Base58 excludes: 0, O, I, l
@@ -196,6 +212,7 @@ fn calculate_entropy_class(input: &str, encodings: &[EncodingType]) -> EntropyCl
         match first_encoding {
             EncodingType::Hex if input.starts_with("0x") => EntropyClass::Low, // Highly structured
             EncodingType::Bech32 | EncodingType::Bech32m => EntropyClass::Low, // HRP structure
+            EncodingType::CashAddr => EntropyClass::Medium,                    // Prefix is optional
             EncodingType::Base58Check | EncodingType::SS58 => EntropyClass::Medium, // Some structure
             EncodingType::Base58 => EntropyClass::Medium, // Some structure
             _ => EntropyClass::High,                      // Random-looking
@@ -235,6 +252,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract_cashaddr_characteristics() {
+        let chars =
+            extract_characteristics("bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a");
+        assert_eq!(chars.encoding, vec![EncodingType::CashAddr]);
+        assert_eq!(chars.hrp, Some("bitcoincash".to_string()));
+        assert_eq!(chars.char_set, CharSet::Base32);
+
+        // Without prefix the checksum cannot be verified yet
+        let chars = extract_characteristics("qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a");
+        assert_eq!(chars.encoding.first(), Some(&EncodingType::CashAddr));
+        assert_eq!(chars.hrp, None);
+    }
+
     #[test]
     fn test_extract_base58_characteristics() {
         let input = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
@@ -12,7 +12,7 @@
 
 use crate::input::InputCharacteristics;
 use crate::registry::EncodingType;
-use crate::shared::encoding;
+use crate::shared::{derivation, encoding};
 use crate::Error;
 
 /// A possible classification of the input
@@ -31,9 +31,14 @@ pub enum DetectedKeyType {
     Secp256k1 { compressed: bool },
     /// Ed25519 public key (32 bytes)
     Ed25519,
-    /// sr25519 public key (32 bytes, indistinguishable from Ed25519)
-    #[allow(dead_code)] // Reserved for future use - currently indistinguishable from Ed25519
+    /// sr25519 public key (32 bytes)
     Sr25519,
+    /// 32-byte public key of either scheme
+    ///
+    /// Ed25519 and sr25519 keys are both 32 bytes with no distinguishing
+    /// structure; a raw key stays ambiguous unless a container or the
+    /// caller's curve hint names the scheme.
+    Ed25519OrSr25519,
 }
 
 /// Classify input and return all possibilities
@@ -47,7 +52,12 @@ pub fn classify_input(
     input: &str,
     chars: &InputCharacteristics,
 ) -> Result<Vec<InputPossibility>, Error> {
-    let address_possibilities = could_be_address(input, chars)?;
+    // A key in a container (e.g. `cosmospub1...`) is never an address
+    let address_possibilities = if derivation::decode_wrapped_public_key(input).is_some() {
+        Vec::new()
+    } else {
+        could_be_address(input, chars)?
+    };
     let public_key_possibilities = could_be_public_key(input, chars)?;
 
     let possibilities: Vec<InputPossibility> = address_possibilities
@@ -58,13 +68,31 @@ pub fn classify_input(
     if possibilities.is_empty() {
         Err(Error::InvalidInput(format!(
             "Unable to classify input format: {}",
-            input
+            redact_input(input)
         )))
     } else {
         Ok(possibilities)
     }
 }
 
+/// Input as shown in error messages
+///
+/// Inputs that may be secret material are replaced by their shape: phrases
+/// of several words (mistyped mnemonics) and tokens longer than an EVM
+/// address (hex and WIF private keys, Solana keypairs).
+pub(crate) fn redact_input(input: &str) -> String {
+    let input = input.trim();
+    let words = input.split_whitespace().count();
+    let length = input.chars().count();
+    if words > 1 {
+        format!("<{} words>", words)
+    } else if length > 42 {
+        format!("<{} characters>", length)
+    } else {
+        input.to_string()
+    }
+}
+
 /// Check if input could be an address based on basic heuristics
 ///
 /// This is non-chain-aware and does not perform validation.
@@ -84,11 +112,16 @@ fn could_be_address(
         EncodingType::Base58Check => {
             (26..=34).contains(&chars.length) || (35..=48).contains(&chars.length)
         }
-        EncodingType::Base58 => (32..=44).contains(&chars.length),
-        EncodingType::SS58 => (35..=48).contains(&chars.length),
+        // Solana-style 32-byte keys, or Cardano Byron CBOR addresses
+        EncodingType::Base58 => {
+            (32..=44).contains(&chars.length) || (50..=130).contains(&chars.length)
+        }
+        EncodingType::SS58 => (24..=51).contains(&chars.length),
         EncodingType::Bech32 | EncodingType::Bech32m => {
-            chars.hrp.is_some() && (14..=90).contains(&chars.length)
+            // Cardano base addresses exceed the BIP-173 limit of 90
+            chars.hrp.is_some() && (14..=130).contains(&chars.length)
         }
+        EncodingType::CashAddr => (42..=130).contains(&chars.length),
     }) || (chars.encoding.is_empty() && chars.hrp.is_some());
 
     Ok(if could_be {
@@ -101,13 +134,20 @@ fn could_be_address(
 /// Check if input could be a public key based on heuristics
 ///
 /// Returns Result with Vec<InputPossibility> for consistency with could_be_address.
-/// An input might match multiple key types (e.g., 32-byte could be Ed25519 or sr25519).
+/// A raw 32-byte key is a single `Ed25519OrSr25519` possibility.
 /// Returns Ok with empty vec if it could not be a public key.
 /// Never returns Err (public key classification is always successful, even if no match).
 fn could_be_public_key(
     input: &str,
     chars: &InputCharacteristics,
 ) -> Result<Vec<InputPossibility>, Error> {
+    // Containers (Cosmos JSON, amino Bech32, PEM/DER) declare the curve
+    if let Some(wrapped) = derivation::decode_wrapped_public_key(input) {
+        return Ok(vec![InputPossibility::PublicKey {
+            key_type: wrapped.key_type,
+        }]);
+    }
+
     // Try all possible encodings to decode the input
     let mut bytes = None;
 
@@ -127,22 +167,17 @@ fn could_be_public_key(
         }
     }
 
-    // Cannot be a PK if decoding fails
-    let bytes = match bytes {
+    // Cannot be a PK if decoding fails, bare Base64 being the last resort
+    let bytes = match bytes.or_else(|| derivation::decode_base64_public_key(input)) {
         Some(bytes) => bytes,
         None => return Ok(Vec::new()),
     };
 
     // Pure pattern matching with guards - no nested if/else
     let possibilities = match bytes.len() {
-        32 => vec![
-            InputPossibility::PublicKey {
-                key_type: DetectedKeyType::Ed25519,
-            },
-            InputPossibility::PublicKey {
-                key_type: DetectedKeyType::Sr25519,
-            },
-        ],
+        32 => vec![InputPossibility::PublicKey {
+            key_type: DetectedKeyType::Ed25519OrSr25519,
+        }],
         33 if bytes[0] == 0x02 || bytes[0] == 0x03 => vec![InputPossibility::PublicKey {
             key_type: DetectedKeyType::Secp256k1 { compressed: true },
         }],
@@ -194,19 +229,36 @@ mod tests {
 
         // Should return both possibilities
         assert!(possibilities.contains(&InputPossibility::Address));
-        // 32-byte keys should return both Ed25519 and Sr25519 possibilities
-        assert!(possibilities.iter().any(|p| matches!(
-            p,
-            InputPossibility::PublicKey {
-                key_type: DetectedKeyType::Ed25519
-            }
-        )));
-        assert!(possibilities.iter().any(|p| matches!(
-            p,
-            InputPossibility::PublicKey {
-                key_type: DetectedKeyType::Sr25519
-            }
-        )));
+        // A 32-byte key is one possibility of either scheme
+        assert_eq!(
+            possibilities
+                .iter()
+                .filter(|p| matches!(p, InputPossibility::PublicKey { .. }))
+                .collect::<Vec<_>>(),
+            vec![&InputPossibility::PublicKey {
+                key_type: DetectedKeyType::Ed25519OrSr25519
+            }]
+        );
+    }
+
+    #[test]
+    fn test_redact_input() {
+        assert_eq!(redact_input(" xyz123abc "), "xyz123abc");
+        assert_eq!(
+            redact_input("0xd8da6bf26964af9d7eed9e03e53415d37aa96045"),
+            "0xd8da6bf26964af9d7eed9e03e53415d37aa96045"
+        );
+        // Private keys and mnemonics are never echoed
+        assert_eq!(
+            redact_input("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTJvyTJ"),
+            "<51 characters>"
+        );
+        assert_eq!(
+            redact_input(
+                "legal winner thank year wave sausage worth useful legal winner thank yellow"
+            ),
+            "<12 words>"
+        );
     }
 
     #[test]
@@ -226,6 +278,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_classify_wrapped_public_key() {
+        // Amino Bech32 Cosmos key: only the declared curve, not an address
+        let input = "cosmospub1addwnpepqf9u9gcjv52n7pl8pc96kzrjfe4ctcshlrxk9r8tv2t5y3amfyecyrxe3kt";
+        let chars = extract_characteristics(input);
+        assert_eq!(
+            classify_input(input, &chars).unwrap(),
+            vec![InputPossibility::PublicKey {
+                key_type: DetectedKeyType::Secp256k1 { compressed: true }
+            }]
+        );
+
+        // Bare Base64 falls back on the key length
+        let input = "11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo=";
+        let chars = extract_characteristics(input);
+        assert!(classify_input(input, &chars)
+            .unwrap()
+            .contains(&InputPossibility::PublicKey {
+                key_type: DetectedKeyType::Ed25519OrSr25519
+            }));
+    }
+
     #[test]
     fn test_classify_evm_address_lowercase() {
         // Test with lowercase EVM address from failing test
@@ -360,19 +434,13 @@ mod tests {
         let input = "0x9f7f8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4a5a6a7a8a9";
         let chars = extract_characteristics(input);
         if let Ok(possibilities) = classify_input(input, &chars) {
-            // Should be classified as Ed25519 and Sr25519 (both 32-byte)
-            assert!(possibilities.iter().any(|p| matches!(
-                p,
-                InputPossibility::PublicKey {
-                    key_type: DetectedKeyType::Ed25519
-                }
-            )));
-            assert!(possibilities.iter().any(|p| matches!(
-                p,
-                InputPossibility::PublicKey {
-                    key_type: DetectedKeyType::Sr25519
-                }
-            )));
+            // Ed25519 and sr25519 keys are indistinguishable: one ambiguous possibility
+            assert!(possibilities.contains(&InputPossibility::PublicKey {
+                key_type: DetectedKeyType::Ed25519OrSr25519
+            }));
+            assert!(!possibilities.contains(&InputPossibility::PublicKey {
+                key_type: DetectedKeyType::Sr25519
+            }));
         }
         // If classification fails, public key might not be recognized
     }
@@ -386,18 +454,15 @@ mod tests {
 
         // Should return both possibilities
         assert!(possibilities.contains(&InputPossibility::Address));
-        assert!(possibilities.iter().any(|p| matches!(
-            p,
-            InputPossibility::PublicKey {
-                key_type: DetectedKeyType::Ed25519
-            }
-        )));
-        assert!(possibilities.iter().any(|p| matches!(
-            p,
-            InputPossibility::PublicKey {
-                key_type: DetectedKeyType::Sr25519
-            }
-        )));
+        assert_eq!(
+            possibilities
+                .iter()
+                .filter(|p| matches!(p, InputPossibility::PublicKey { .. }))
+                .collect::<Vec<_>>(),
+            vec![&InputPossibility::PublicKey {
+                key_type: DetectedKeyType::Ed25519OrSr25519
+            }]
+        );
     }
 
     #[test]
@@ -94,25 +94,30 @@ fn public_key_matches<'a>(
         .flat_map(move |pk_fmt| {
             pk_types
                 .iter()
-                .filter(move |pk| {
-                    let pk_curve = detected_key_to_curve(pk);
-                    pk_fmt.key_type == pk_curve
-                })
-                .map(move |pk| ChainMatch {
+                .filter_map(move |pk| key_type_for_curve(*pk, pk_fmt.key_type))
+                .map(move |key_type| ChainMatch {
                     chain_id: chain.id.clone(),
                     chain_name: chain.name.clone(),
-                    possibility: InputPossibility::PublicKey { key_type: *pk },
+                    possibility: InputPossibility::PublicKey { key_type },
                 })
         })
         .take(1) // Only one match per chain for public keys
 }
 
-/// Convert DetectedKeyType to PublicKeyType (curve)
-fn detected_key_to_curve(key_type: &DetectedKeyType) -> PublicKeyType {
-    match key_type {
-        DetectedKeyType::Secp256k1 { .. } => PublicKeyType::Secp256k1,
-        DetectedKeyType::Ed25519 => PublicKeyType::Ed25519,
-        DetectedKeyType::Sr25519 => PublicKeyType::Sr25519,
+/// Key type of a detected key read with a format's curve, if the curves agree
+///
+/// An ambiguous 32-byte key takes the scheme of a format declaring sr25519
+/// and stays ambiguous on Ed25519 formats.
+fn key_type_for_curve(key_type: DetectedKeyType, curve: PublicKeyType) -> Option<DetectedKeyType> {
+    match (key_type, curve) {
+        (DetectedKeyType::Secp256k1 { .. }, PublicKeyType::Secp256k1)
+        | (DetectedKeyType::Ed25519, PublicKeyType::Ed25519)
+        | (DetectedKeyType::Sr25519, PublicKeyType::Sr25519)
+        | (DetectedKeyType::Ed25519OrSr25519, PublicKeyType::Ed25519) => Some(key_type),
+        (DetectedKeyType::Ed25519OrSr25519, PublicKeyType::Sr25519) => {
+            Some(DetectedKeyType::Sr25519)
+        }
+        _ => None,
     }
 }
 
@@ -445,6 +450,27 @@ mod tests {
         // If classification fails, public key might not be recognized
     }
 
+    #[test]
+    fn test_match_ambiguous_key_takes_declared_sr25519() {
+        let input = "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+        let chars = extract_characteristics(input);
+        let possibilities = classify_input(input, &chars).unwrap();
+        let matches = match_input_with_metadata(input, &chars, &possibilities, Registry::get());
+        let key_type = |chain: &str| -> Vec<DetectedKeyType> {
+            matches
+                .iter()
+                .filter(|m| m.chain_id == chain)
+                .filter_map(|m| match m.possibility {
+                    InputPossibility::PublicKey { key_type } => Some(key_type),
+                    InputPossibility::Address => None,
+                })
+                .collect()
+        };
+
+        assert_eq!(key_type("polkadot"), vec![DetectedKeyType::Sr25519]);
+        assert_eq!(key_type("solana"), vec![DetectedKeyType::Ed25519OrSr25519]);
+    }
+
     #[test]
     fn test_match_no_matches_invalid() {
         // No matches: invalid input should return empty
@@ -9,6 +9,7 @@ pub mod matcher;
 pub mod signature;
 
 pub use characteristics::{extract_characteristics, InputCharacteristics};
+pub(crate) use classifier::redact_input;
 pub use classifier::{classify_input, DetectedKeyType, InputPossibility};
 pub use matcher::match_input_with_metadata;
 pub use signature::CategorySignature;
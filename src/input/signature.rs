@@ -20,7 +20,7 @@ pub struct CategorySignature {
     pub has_hrp: bool,
     /// Required prefixes (empty vec = no prefix requirement)
     pub prefixes: Vec<String>,
-    /// Required HRP prefixes (empty vec = no HRP requirement)
+    /// Required HRPs, matched exactly (empty vec = no HRP requirement)
     pub hrp_prefixes: Vec<String>,
     /// Encoding type (if specific)
     pub encoding_type: Option<EncodingType>,
@@ -80,13 +80,20 @@ impl CategorySignature {
             (0, usize::MAX)
         };
 
+        // CashAddr prefixes may be omitted from the input, so they are not required here
+        let hrps = if metadata.encoding == EncodingType::CashAddr {
+            vec![]
+        } else {
+            metadata.hrps.clone()
+        };
+
         CategorySignature {
             char_set: metadata.char_set,
             min_len,
             max_len,
-            has_hrp: !metadata.hrps.is_empty(),
+            has_hrp: !hrps.is_empty(),
             prefixes: metadata.prefixes.clone(),
-            hrp_prefixes: metadata.hrps.clone(),
+            hrp_prefixes: hrps,
             encoding_type: Some(metadata.encoding),
         }
     }
@@ -120,10 +127,10 @@ impl CategorySignature {
             return false;
         }
 
-        // Check HRP prefixes
+        // Check HRPs (exact: `cosmosvaloper` is not a `cosmos` HRP)
         if !self.hrp_prefixes.is_empty() {
             if let Some(ref hrp) = chars.hrp {
-                if !self.hrp_prefixes.iter().any(|h| hrp.starts_with(h)) {
+                if !self.hrp_prefixes.iter().any(|h| hrp == h) {
                     return false;
                 }
             } else {
@@ -173,6 +180,9 @@ mod tests {
             version_bytes: vec![],
             checksum: Some(ChecksumType::EIP55),
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let sig = CategorySignature::from_metadata(&metadata);
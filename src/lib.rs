@@ -3,16 +3,57 @@
 //! This crate provides functionality to identify which blockchain(s) an input
 //! string (address, public key, or private key) belongs to.
 
+mod bip32;
+mod cardano;
+mod conversion;
+mod descriptor;
 mod detectors;
 mod identify;
 mod input;
 mod loaders;
+mod mnemonic;
 mod models;
 mod pipelines;
+mod private_key;
 mod registry;
+mod script;
 mod shared;
+mod signature;
+mod substrate;
+mod transaction;
 
-pub use identify::{identify as identify_all, IdentificationCandidate, InputType};
+pub use bip32::{
+    derive_account_addresses, derive_addresses, AccountAddresses, AddressBranch, DerivedAddress,
+    ExtendedPublicKey,
+};
+pub use cardano::{
+    cardano_addresses, parse_byron_address, parse_cardano_address, ByronAddress, ByronAddressType,
+    CardanoAddress, CardanoAddressType, CardanoAddresses, CardanoCredential, CardanoPointer,
+};
+pub use conversion::{
+    cashaddr_to_legacy, cosmos_account_to_valoper, cosmos_valoper_to_account, ethermint_to_evm,
+    evm_to_ethermint, evm_to_ss58, legacy_to_cashaddr, ss58_to_evm,
+};
+pub use descriptor::{descriptor_address, descriptor_address_at, descriptor_with_checksum};
+pub use identify::{
+    identify as identify_all, identify_with_options, CandidateDetails, IdentificationCandidate,
+    IdentifyOptions, InputType,
+};
+pub use loaders::{
+    convert_cosmos_chain, import_cosmos_chain_registry, load_chain_directory, ImportedChain,
+};
+pub use mnemonic::{derive_mnemonic_addresses, inspect_mnemonic, MnemonicInfo};
+pub use registry::{AddressRole, Network, PublicKeyType};
+pub use script::{
+    address_to_script, build_multisig_script, decode_script_pubkey, multisig_addresses,
+    multisig_addresses_from_script, script_to_address, MultisigAddresses, ScriptPubKey, ScriptType,
+};
+pub use signature::{recover_signer, RecoveredSigner, SignatureScheme, SignerAddress};
+pub use substrate::{
+    pallet_account, parachain_sovereign_account, pure_proxy_account, sibling_sovereign_account,
+    substrate_multisig_account,
+};
+pub use transaction::{decode_evm_transaction, AccessListItem, EvmTransaction, EvmTransactionType};
 
 /// Identify the blockchain(s) for a given input string.
 ///
@@ -101,7 +142,7 @@ mod tests {
         assert!(result.is_ok());
         let candidates = result.unwrap();
         // Should have multiple EVM chains
-        assert!(candidates.len() >= 1);
+        assert!(!candidates.is_empty());
         // All should be EVM chains
         let evm_chains = [
             "ethereum",
@@ -114,6 +155,12 @@ mod tests {
             "fantom",
             "celo",
             "gnosis",
+            "evmos",
+            "injective",
+            "cronos",
+            "darwinia",
+            "moonbeam",
+            "moonriver",
         ];
         assert!(candidates
             .iter()
@@ -184,8 +231,7 @@ mod tests {
 
         let result = identify(&tron_addr);
         // May succeed or fail depending on validation
-        if result.is_ok() {
-            let candidates = result.unwrap();
+        if let Ok(candidates) = result {
             assert!(!candidates.is_empty());
             assert!(candidates.iter().any(|c| c.chain == "tron"));
         }
@@ -203,8 +249,7 @@ mod tests {
 
         let result = identify(&substrate_addr);
         // This may fail if the address doesn't validate, but tests integration
-        if result.is_ok() {
-            let candidates = result.unwrap();
+        if let Ok(candidates) = result {
             // Should have Substrate chain candidates if valid
             assert!(!candidates.is_empty());
         }
@@ -1,5 +1,9 @@
 use crate::models::chain::ChainConfig;
+use crate::registry::chain_converter::convert_chain_config;
+use crate::registry::Registry;
+use crate::Error;
 use serde_json;
+use std::path::Path;
 
 /// Load chain metadata by ID
 /// This function uses include_str! to load JSON at compile time
@@ -18,6 +22,7 @@ pub fn load_chain(id: &str) -> Result<ChainConfig, String> {
         "bitcoin" => include_str!("../../metadata/chains/bitcoin.json"),
         "litecoin" => include_str!("../../metadata/chains/litecoin.json"),
         "dogecoin" => include_str!("../../metadata/chains/dogecoin.json"),
+        "bitcoin_cash" => include_str!("../../metadata/chains/bitcoin_cash.json"),
         "solana" => include_str!("../../metadata/chains/solana.json"),
         "tron" => include_str!("../../metadata/chains/tron.json"),
         "cosmos_hub" => include_str!("../../metadata/chains/cosmos_hub.json"),
@@ -34,7 +39,101 @@ pub fn load_chain(id: &str) -> Result<ChainConfig, String> {
         "kusama" => include_str!("../../metadata/chains/kusama.json"),
         "substrate" => include_str!("../../metadata/chains/substrate.json"),
         "cardano" => include_str!("../../metadata/chains/cardano.json"),
+        "evmos" => include_str!("../../metadata/chains/evmos.json"),
+        "injective" => include_str!("../../metadata/chains/injective.json"),
+        "cronos" => include_str!("../../metadata/chains/cronos.json"),
+        "darwinia" => include_str!("../../metadata/chains/darwinia.json"),
+        "moonbeam" => include_str!("../../metadata/chains/moonbeam.json"),
+        "moonriver" => include_str!("../../metadata/chains/moonriver.json"),
         _ => return Err(format!("Unknown chain: {}", id)),
     };
     serde_json::from_str(json).map_err(|e| format!("Failed to parse chain JSON for {}: {}", id, e))
 }
+
+/// Add the chain metadata files (`*.json`) of a directory to the registry
+///
+/// Must be called before the first identification, since the registry is
+/// built once. Chains whose id or HRPs are already registered are skipped
+/// with a warning when the registry is built. Returns the number of chains
+/// read.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use foxchain_id::load_chain_directory;
+///
+/// // e.g. written by `foxchain-metadata cosmos-registry`
+/// let count = load_chain_directory("metadata/cosmos-registry")?;
+/// println!("{} chains loaded", count);
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn load_chain_directory(dir: impl AsRef<Path>) -> Result<usize, Error> {
+    let configs = read_chain_directory(dir.as_ref())?;
+    let count = configs.len();
+    Registry::queue_chains(configs)?;
+    Ok(count)
+}
+
+/// Parse and check every `*.json` chain file of a directory
+fn read_chain_directory(dir: &Path) -> Result<Vec<ChainConfig>, Error> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| Error::InvalidInput(format!("Cannot read {}: {}", dir.display(), e)))?;
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let json = std::fs::read_to_string(path).map_err(|e| {
+                Error::InvalidInput(format!("Cannot read {}: {}", path.display(), e))
+            })?;
+            let config: ChainConfig = serde_json::from_str(&json).map_err(|e| {
+                Error::InvalidInput(format!("Failed to parse {}: {}", path.display(), e))
+            })?;
+            convert_chain_config(config.clone()).map_err(|e| {
+                Error::InvalidInput(format!("Failed to convert {}: {}", path.display(), e))
+            })?;
+            Ok(config)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_chain_directory() {
+        let dir = std::env::temp_dir().join(format!("foxchain-chains-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("evmos.json"),
+            include_str!("../../metadata/chains/evmos.json"),
+        )
+        .unwrap();
+        std::fs::write(dir.join("README.md"), "not a chain").unwrap();
+
+        let configs = read_chain_directory(&dir).unwrap();
+        assert_eq!(configs.len(), 1);
+        assert_eq!(configs[0].id, "evmos");
+
+        std::fs::write(dir.join("broken.json"), "{}").unwrap();
+        assert!(read_chain_directory(&dir).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_chain_directory_after_registry_built() {
+        let dir = std::env::temp_dir().join(format!("foxchain-empty-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        Registry::get();
+
+        let result = load_chain_directory(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(result.unwrap_err().to_string().contains("before"));
+        assert!(load_chain_directory("/nonexistent/foxchain").is_err());
+    }
+}
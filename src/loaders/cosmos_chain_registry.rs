@@ -0,0 +1,316 @@
+//! Import of cosmos/chain-registry `chain.json` files as foxchain chain metadata
+//!
+//! The chain registry (<https://github.com/cosmos/chain-registry>) keeps one
+//! directory per chain with a `chain.json` declaring its Bech32 prefixes,
+//! SLIP-44 coin type and key algorithms. Chains using `ethsecp256k1` keys get
+//! the `ethermint` pipeline; the others the `cosmos` pipeline.
+
+use crate::models::chain::{ChainConfig, HashFormat, PublicKeyFormat};
+use crate::Error;
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// A chain directory of a chain-registry checkout and its converted metadata
+#[derive(Debug)]
+pub struct ImportedChain {
+    /// Directory name (the chain-registry `chain_name`)
+    pub name: String,
+    /// Foxchain chain metadata JSON, or why the chain could not be converted
+    pub metadata: Result<String, Error>,
+}
+
+/// Convert a chain-registry `chain.json` to foxchain chain metadata JSON
+///
+/// Only mainnets are converted, and only chains whose keys are `secp256k1`
+/// or `ethsecp256k1` (the key algorithm defaults to `secp256k1`).
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::convert_cosmos_chain;
+///
+/// let metadata = convert_cosmos_chain(r#"{
+///     "chain_name": "osmosis",
+///     "pretty_name": "Osmosis",
+///     "network_type": "mainnet",
+///     "bech32_prefix": "osmo",
+///     "slip44": 118
+/// }"#)?;
+/// assert!(metadata.contains(r#""address_pipeline": "cosmos""#));
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn convert_cosmos_chain(chain_json: &str) -> Result<String, Error> {
+    let chain: Value = serde_json::from_str(chain_json)
+        .map_err(|e| Error::InvalidInput(format!("Invalid chain.json: {}", e)))?;
+    let config = chain_config(&chain)?;
+    serde_json::to_string_pretty(&config)
+        .map(|metadata| metadata + "\n")
+        .map_err(|e| Error::InvalidInput(format!("Failed to serialize {}: {}", config.id, e)))
+}
+
+/// Convert every chain of a local chain-registry checkout
+///
+/// Mainnets live in top-level directories holding a `chain.json`; other
+/// directories (`testnets`, `_IBC`, ...) are skipped. Chains are returned in
+/// directory name order with their conversion result.
+pub fn import_cosmos_chain_registry(checkout: &Path) -> Result<Vec<ImportedChain>, Error> {
+    let entries = std::fs::read_dir(checkout)
+        .map_err(|e| Error::InvalidInput(format!("Cannot read {}: {}", checkout.display(), e)))?;
+
+    let mut chains: Vec<ImportedChain> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?.to_string();
+            if name.starts_with(['_', '.']) {
+                return None;
+            }
+            let chain_json = std::fs::read_to_string(path.join("chain.json")).ok()?;
+            Some(ImportedChain {
+                name,
+                metadata: convert_cosmos_chain(&chain_json),
+            })
+        })
+        .collect();
+    chains.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(chains)
+}
+
+fn chain_config(chain: &Value) -> Result<ChainConfig, Error> {
+    let field = |name: &str| chain.get(name).and_then(|v| v.as_str());
+    let id = field("chain_name")
+        .ok_or_else(|| Error::InvalidInput("chain.json has no chain_name".to_string()))?;
+
+    let network_type = field("network_type").unwrap_or("mainnet");
+    if network_type != "mainnet" {
+        return Err(Error::InvalidInput(format!(
+            "{} is a {}; only mainnets are imported",
+            id, network_type
+        )));
+    }
+    let hrp = field("bech32_prefix")
+        .ok_or_else(|| Error::InvalidInput(format!("{} has no bech32_prefix", id)))?;
+
+    let key_algos: Vec<&str> = chain
+        .get("key_algos")
+        .and_then(|v| v.as_array())
+        .map(|algos| algos.iter().filter_map(|a| a.as_str()).collect())
+        .unwrap_or_default();
+    let ethermint = if key_algos.contains(&"ethsecp256k1") {
+        true
+    } else if key_algos.is_empty() || key_algos.contains(&"secp256k1") {
+        false
+    } else {
+        return Err(Error::InvalidInput(format!(
+            "{} uses unsupported key algorithms: {}",
+            id,
+            key_algos.join(", ")
+        )));
+    };
+
+    // Tendermint hashes are uppercase hex; Ethermint chains also have 0x EVM hashes
+    let hash_formats = if ethermint {
+        vec![
+            hash_format("transaction", &["0x"], false),
+            hash_format("transaction", &[], true),
+            hash_format("block", &["0x"], false),
+            hash_format("block", &[], true),
+        ]
+    } else {
+        vec![
+            hash_format("transaction", &[], true),
+            hash_format("block", &[], true),
+        ]
+    };
+    let evm_chain_id = if ethermint {
+        field("chain_id").and_then(eip155_chain_id)
+    } else {
+        None
+    };
+
+    Ok(ChainConfig {
+        id: id.to_string(),
+        name: field("pretty_name").unwrap_or(id).to_string(),
+        curve: "secp256k1".to_string(),
+        address_pipeline: if ethermint { "ethermint" } else { "cosmos" }.to_string(),
+        bip32_ed25519: false,
+        slip44: chain
+            .get("slip44")
+            .and_then(|v| v.as_u64())
+            .and_then(|v| u32::try_from(v).ok()),
+        derivation_path: Some("m/44'/{coin_type}'/{account}'".to_string()),
+        evm_chain_id,
+        message_prefix: None,
+        address_params: address_params(chain, hrp, ethermint),
+        public_key_formats: vec![
+            PublicKeyFormat {
+                encoding: "hex".to_string(),
+                exact_length: None,
+                length_range: Some((66, 130)),
+                prefixes: vec!["0x".to_string()],
+                curve: None,
+            },
+            PublicKeyFormat {
+                encoding: "hex".to_string(),
+                exact_length: Some(64),
+                length_range: None,
+                prefixes: vec!["0x".to_string()],
+                curve: Some("ed25519".to_string()),
+            },
+        ],
+        hash_formats,
+    })
+}
+
+/// Address roles from `bech32_config`, defaulting to the SDK suffixes of `bech32_prefix`
+///
+/// Amino public keys of `ethsecp256k1` accounts are not decoded, so Ethermint
+/// chains only declare the consensus public key HRP.
+fn address_params(chain: &Value, hrp: &str, ethermint: bool) -> Value {
+    let config = chain.get("bech32_config");
+    let prefix = |key: &str, suffix: &str| {
+        config
+            .and_then(|c| c.get(key))
+            .and_then(|v| v.as_str())
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| format!("{}{}", hrp, suffix))
+    };
+    let role = |addr_key: &str, pub_key: &str, suffix: &str, with_pub: bool| {
+        let mut role = json!({ "hrp": prefix(addr_key, suffix) });
+        if with_pub {
+            role["pub_hrp"] = json!(prefix(pub_key, &format!("{}pub", suffix)));
+        }
+        role
+    };
+
+    json!({
+        "hrp": hrp,
+        "roles": {
+            "account": role("bech32PrefixAccAddr", "bech32PrefixAccPub", "", !ethermint),
+            "valoper": role("bech32PrefixValAddr", "bech32PrefixValPub", "valoper", !ethermint),
+            "valcons": role("bech32PrefixConsAddr", "bech32PrefixConsPub", "valcons", true),
+        }
+    })
+}
+
+fn hash_format(kind: &str, prefixes: &[&str], uppercase: bool) -> HashFormat {
+    HashFormat {
+        kind: kind.to_string(),
+        encoding: "hex".to_string(),
+        bytes: 32,
+        prefixes: prefixes.iter().map(|p| p.to_string()).collect(),
+        uppercase,
+        leading_zero_bytes: 0,
+    }
+}
+
+/// EIP-155 chain id of an Ethermint chain id, `{identifier}_{eip155}-{version}`
+fn eip155_chain_id(chain_id: &str) -> Option<u64> {
+    let (_, rest) = chain_id.rsplit_once('_')?;
+    let (number, _) = rest.split_once('-')?;
+    number.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::chain_converter::convert_chain_config;
+    use crate::registry::AddressRole;
+
+    const OSMOSIS: &str = r#"{
+        "$schema": "../chain.schema.json",
+        "chain_name": "osmosis",
+        "status": "live",
+        "network_type": "mainnet",
+        "pretty_name": "Osmosis",
+        "chain_id": "osmosis-1",
+        "bech32_prefix": "osmo",
+        "slip44": 118,
+        "key_algos": ["secp256k1"]
+    }"#;
+
+    const EVMOS: &str = r#"{
+        "chain_name": "evmos",
+        "network_type": "mainnet",
+        "pretty_name": "Evmos",
+        "chain_id": "evmos_9001-2",
+        "bech32_prefix": "evmos",
+        "bech32_config": { "bech32PrefixValAddr": "evmosvaloper" },
+        "slip44": 60,
+        "key_algos": ["ethsecp256k1"],
+        "extra_codecs": ["ethermint"]
+    }"#;
+
+    fn convert(chain_json: &str) -> ChainConfig {
+        serde_json::from_str(&convert_cosmos_chain(chain_json).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_convert_cosmos_chain() {
+        let config = convert(OSMOSIS);
+        assert_eq!(config.id, "osmosis");
+        assert_eq!(config.name, "Osmosis");
+        assert_eq!(config.address_pipeline, "cosmos");
+        assert_eq!(config.slip44, Some(118));
+        assert_eq!(config.evm_chain_id, None);
+
+        // Same formats as the hand-written osmosis.json
+        let chain = convert_chain_config(config).unwrap();
+        let builtin = convert_chain_config(crate::loaders::load_chain("osmosis").unwrap()).unwrap();
+        assert_eq!(chain.address_formats, builtin.address_formats);
+        assert_eq!(chain.public_key_formats, builtin.public_key_formats);
+    }
+
+    #[test]
+    fn test_convert_ethermint_chain() {
+        let config = convert(EVMOS);
+        assert_eq!(config.address_pipeline, "ethermint");
+        assert_eq!(config.evm_chain_id, Some(9001));
+        assert_eq!(config.slip44, Some(60));
+
+        let chain = convert_chain_config(config).unwrap();
+        let valoper = chain
+            .address_formats
+            .iter()
+            .find(|f| f.role == Some(AddressRole::ValidatorOperator))
+            .unwrap();
+        assert_eq!(valoper.hrps, vec!["evmosvaloper".to_string()]);
+        // Bech32 roles and the 0x form
+        assert!(chain.address_formats.iter().any(|f| f.prefixes == ["0x"]));
+    }
+
+    #[test]
+    fn test_convert_cosmos_chain_rejects() {
+        let testnet = OSMOSIS.replace(r#""mainnet""#, r#""testnet""#);
+        assert!(convert_cosmos_chain(&testnet).is_err());
+        let ed25519 = OSMOSIS.replace(r#"["secp256k1"]"#, r#"["ed25519"]"#);
+        assert!(convert_cosmos_chain(&ed25519).is_err());
+        assert!(convert_cosmos_chain(r#"{"chain_name": "nohrp"}"#).is_err());
+        assert!(convert_cosmos_chain("not json").is_err());
+    }
+
+    #[test]
+    fn test_import_cosmos_chain_registry() {
+        let checkout =
+            std::env::temp_dir().join(format!("foxchain-chain-registry-{}", std::process::id()));
+        for (name, chain_json) in [("osmosis", OSMOSIS), ("evmos", EVMOS), ("_IBC", OSMOSIS)] {
+            std::fs::create_dir_all(checkout.join(name)).unwrap();
+            std::fs::write(checkout.join(name).join("chain.json"), chain_json).unwrap();
+        }
+        std::fs::create_dir_all(checkout.join("testnets")).unwrap();
+
+        let chains = import_cosmos_chain_registry(&checkout).unwrap();
+        std::fs::remove_dir_all(&checkout).unwrap();
+
+        let names: Vec<&str> = chains.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["evmos", "osmosis"]);
+        assert!(chains.iter().all(|c| c.metadata.is_ok()));
+        assert!(import_cosmos_chain_registry(Path::new("/nonexistent/chain-registry")).is_err());
+    }
+
+    #[test]
+    fn test_eip155_chain_id() {
+        assert_eq!(eip155_chain_id("evmos_9001-2"), Some(9001));
+        assert_eq!(eip155_chain_id("cronosmainnet_25-1"), Some(25));
+        assert_eq!(eip155_chain_id("injective-1"), None);
+    }
+}
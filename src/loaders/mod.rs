@@ -1,8 +1,14 @@
 pub mod chain_loader;
+pub mod cosmos_chain_registry;
 pub mod curve_loader;
 pub mod metadata_loader;
 pub mod pipeline_loader;
+pub mod ss58_registry;
 
-pub use chain_loader::load_chain;
+pub use chain_loader::{load_chain, load_chain_directory};
+pub use cosmos_chain_registry::{
+    convert_cosmos_chain, import_cosmos_chain_registry, ImportedChain,
+};
 pub use metadata_loader::load_index;
 pub use pipeline_loader::load_pipeline;
+pub(crate) use ss58_registry::ss58_registry_chains;
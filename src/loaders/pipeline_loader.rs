@@ -12,6 +12,8 @@ pub fn load_pipeline(id: &str) -> Result<AddressPipeline, String> {
         "ss58" => include_str!("../../metadata/pipelines/addresses/ss58.json"),
         "cardano" => include_str!("../../metadata/pipelines/addresses/cardano.json"),
         "tron" => include_str!("../../metadata/pipelines/addresses/tron.json"),
+        "cashaddr" => include_str!("../../metadata/pipelines/addresses/cashaddr.json"),
+        "ethermint" => include_str!("../../metadata/pipelines/addresses/ethermint.json"),
         _ => return Err(format!("Unknown pipeline: {}", id)),
     };
     serde_json::from_str(json)
@@ -0,0 +1,105 @@
+//! Substrate chain entries from the SS58 registry
+//!
+//! The `ss58-registry` crate lists every network with a registered SS58
+//! prefix (<https://github.com/paritytech/ss58-registry>). Each one becomes an
+//! address-only `ss58` chain, so an SS58 address resolves to the network its
+//! prefix names. Chains declared in the metadata take precedence, which is
+//! how networks with 20-byte `AccountId20` accounts (Moonbeam) are declared
+//! as `evm` chains with an SS58 prefix.
+
+use crate::models::chain::ChainConfig;
+use serde_json::json;
+use ss58_registry::Ss58AddressFormat;
+
+/// Chain configs for the networks of the SS58 registry
+///
+/// Reserved prefixes and the bare public key formats (`BareSr25519`,
+/// `BareEd25519`, `BareSecp256k1`) are not networks and are skipped. The
+/// entries declare no public key or hash formats: keys and hashes are
+/// identified through the chains of the metadata.
+pub(crate) fn ss58_registry_chains() -> Vec<ChainConfig> {
+    Ss58AddressFormat::all()
+        .iter()
+        .map(|&format| Ss58AddressFormat::from(format))
+        .filter(|format| !format.is_reserved())
+        .map(|format| (format.prefix(), format.to_string()))
+        .filter(|(_, network)| !network.starts_with("Bare"))
+        .map(|(prefix, network)| ChainConfig {
+            id: chain_id(&network),
+            name: display_name(&network),
+            curve: "sr25519".to_string(),
+            address_pipeline: "ss58".to_string(),
+            bip32_ed25519: false,
+            slip44: None,
+            derivation_path: None,
+            evm_chain_id: None,
+            message_prefix: None,
+            address_params: json!({ "prefix": prefix }),
+            public_key_formats: vec![],
+            hash_formats: vec![],
+        })
+        .collect()
+}
+
+/// Chain id of a registry network name (`dock-pos-mainnet` → `dock_pos_mainnet`)
+fn chain_id(network: &str) -> String {
+    network.to_lowercase().replace('-', "_")
+}
+
+/// Display name of a registry network name (`dock-pos-mainnet` → `Dock Pos Mainnet`)
+fn display_name(network: &str) -> String {
+    network
+        .split(['-', '_'])
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prefix_of(chains: &[ChainConfig], id: &str) -> Option<u64> {
+        chains
+            .iter()
+            .find(|chain| chain.id == id)
+            .and_then(|chain| chain.address_params["prefix"].as_u64())
+    }
+
+    #[test]
+    fn test_ss58_registry_chains() {
+        let chains = ss58_registry_chains();
+
+        assert_eq!(prefix_of(&chains, "polkadot"), Some(0));
+        assert_eq!(prefix_of(&chains, "astar"), Some(5));
+        assert_eq!(prefix_of(&chains, "acala"), Some(10));
+        // Two-byte prefixes
+        assert_eq!(prefix_of(&chains, "moonbeam"), Some(1284));
+        assert_eq!(prefix_of(&chains, "bittensor"), Some(13116));
+        assert_eq!(prefix_of(&chains, "dock_pos_mainnet"), Some(22));
+        assert!(chains.iter().all(|chain| chain.address_pipeline == "ss58"));
+    }
+
+    #[test]
+    fn test_ss58_registry_skips_reserved_and_bare_formats() {
+        let chains = ss58_registry_chains();
+
+        assert!(chains.iter().all(|chain| !chain.id.starts_with("reserved")));
+        assert!(chains.iter().all(|chain| !chain.id.starts_with("bare")));
+        assert_eq!(prefix_of(&chains, "substrate"), Some(42));
+    }
+
+    #[test]
+    fn test_display_name() {
+        assert_eq!(display_name("acala"), "Acala");
+        assert_eq!(display_name("dock-pos-mainnet"), "Dock Pos Mainnet");
+        assert_eq!(display_name("quartz_mainnet"), "Quartz Mainnet");
+        assert_eq!(display_name("KICO"), "KICO");
+    }
+}
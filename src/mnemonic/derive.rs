@@ -0,0 +1,322 @@
+//! HD derivation from a BIP-39 seed (BIP-32 for secp256k1, SLIP-10 for Ed25519)
+
+use crate::bip32::{
+    chain_address, fill_account_path, DerivedAddress, ExtendedPublicKey, BIP32_MAINNET_VERSION,
+    HARDENED,
+};
+use crate::pipelines::addresses::derive_chain_address;
+use crate::registry::{ChainMetadata, EncodingType, ExtendedKeyMetadata, Network, Registry};
+use crate::shared::crypto::hash::{hash160, hmac_sha512};
+use crate::shared::crypto::{ed25519, secp256k1};
+use crate::Error;
+use zeroize::Zeroizing;
+
+/// HMAC key of the BIP-32 master key
+const BIP32_SEED_KEY: &[u8] = b"Bitcoin seed";
+/// HMAC key of the SLIP-10 Ed25519 master key
+const SLIP10_ED25519_SEED_KEY: &[u8] = b"ed25519 seed";
+
+/// A private node of an HD tree; key material is zeroized when dropped
+struct PrivateNode {
+    key: Zeroizing<[u8; 32]>,
+    chain_code: Zeroizing<[u8; 32]>,
+}
+
+impl PrivateNode {
+    fn from_hmac(hmac: Zeroizing<[u8; 64]>) -> Self {
+        let mut key = Zeroizing::new([0u8; 32]);
+        let mut chain_code = Zeroizing::new([0u8; 32]);
+        key.copy_from_slice(&hmac[..32]);
+        chain_code.copy_from_slice(&hmac[32..]);
+        PrivateNode { key, chain_code }
+    }
+
+    fn master(seed: &[u8], seed_key: &[u8]) -> Self {
+        Self::from_hmac(Zeroizing::new(hmac_sha512(seed_key, seed)))
+    }
+
+    /// BIP-32 private child derivation (CKDpriv)
+    fn derive_secp256k1(&self, index: u32) -> Result<Self, Error> {
+        let mut data = Zeroizing::new(Vec::with_capacity(37));
+        if index >= HARDENED {
+            data.push(0);
+            data.extend_from_slice(&self.key[..]);
+        } else {
+            data.extend(secp256k1::public_key_from_secret(&self.key[..], true)?);
+        }
+        data.extend_from_slice(&index.to_be_bytes());
+        let mut child = Self::from_hmac(Zeroizing::new(hmac_sha512(&self.chain_code[..], &data)));
+        let tweak = Zeroizing::new(*child.key);
+        child.key = secp256k1::tweak_add_secret_key(&self.key[..], &tweak)?;
+        Ok(child)
+    }
+
+    /// SLIP-10 Ed25519 child derivation (hardened indexes only)
+    fn derive_ed25519(&self, index: u32) -> Result<Self, Error> {
+        if index < HARDENED {
+            return Err(Error::InvalidInput(
+                "SLIP-10 Ed25519 derivation only supports hardened indexes".to_string(),
+            ));
+        }
+        let mut data = Zeroizing::new(vec![0u8]);
+        data.extend_from_slice(&self.key[..]);
+        data.extend_from_slice(&index.to_be_bytes());
+        Ok(Self::from_hmac(Zeroizing::new(hmac_sha512(
+            &self.chain_code[..],
+            &data,
+        ))))
+    }
+}
+
+/// Parse an absolute derivation path such as `m/44'/60'/0'`
+fn parse_path(path: &str) -> Result<Vec<u32>, Error> {
+    path.strip_prefix("m/")
+        .unwrap_or(path)
+        .split('/')
+        .map(|component| {
+            let (digits, hardened) = match component.strip_suffix('\'') {
+                Some(digits) => (digits, true),
+                None => (component, false),
+            };
+            digits
+                .parse::<u32>()
+                .ok()
+                .filter(|&index| index < HARDENED)
+                .map(|index| if hardened { index + HARDENED } else { index })
+                .ok_or_else(|| {
+                    Error::InvalidInput(format!("Invalid path component: {}", component))
+                })
+        })
+        .collect()
+}
+
+/// Derive the first `count` receive addresses of account 0 on a chain from a BIP-39 seed,
+/// with their encodings
+///
+/// secp256k1 chains use BIP-32 at `<account path>/0/i`, once per single-key
+/// script the chain declares (BIP-44/49/84 for Bitcoin). Ed25519 chains use
+/// SLIP-10 at `<account path with account i>/0'`, as Solana wallets do.
+pub(crate) fn seed_addresses(
+    seed: &[u8; 64],
+    chain: &ChainMetadata,
+    count: u32,
+) -> Result<Vec<(DerivedAddress, EncodingType)>, Error> {
+    let config = Registry::get()
+        .get_chain_config(&chain.id)
+        .ok_or_else(|| Error::InvalidInput(format!("Unknown chain: {}", chain.id)))?;
+    if config.derivation_path.is_none() {
+        return Err(Error::InvalidInput(format!(
+            "{} has no BIP-32/SLIP-10 derivation path",
+            chain.id
+        )));
+    }
+    if config.bip32_ed25519 {
+        return Err(Error::InvalidInput(format!(
+            "{} derives keys with BIP32-Ed25519, which is not supported",
+            chain.id
+        )));
+    }
+
+    match config.curve.as_str() {
+        "secp256k1" => secp256k1_addresses(seed, chain, count),
+        "ed25519" => (0..count)
+            .map(|account| {
+                let path = format!(
+                    "{}/0'",
+                    fill_account_path(&chain.id, 44, Network::Mainnet, account)?
+                );
+                let node = parse_path(&path)?.into_iter().try_fold(
+                    PrivateNode::master(seed, SLIP10_ED25519_SEED_KEY),
+                    |node, index| node.derive_ed25519(index),
+                )?;
+                let public_key = ed25519::public_key_from_seed(&node.key);
+                let derived = DerivedAddress {
+                    address: derive_chain_address(&chain.id, &public_key)?,
+                    public_key: ::hex::encode(public_key),
+                    path,
+                };
+                Ok((derived, chain.address_formats[0].encoding))
+            })
+            .collect(),
+        curve => Err(Error::InvalidInput(format!(
+            "Unsupported curve for HD derivation: {}",
+            curve
+        ))),
+    }
+}
+
+/// BIP-32 receive addresses for every single-key script a secp256k1 chain declares
+fn secp256k1_addresses(
+    seed: &[u8; 64],
+    chain: &ChainMetadata,
+    count: u32,
+) -> Result<Vec<(DerivedAddress, EncodingType)>, Error> {
+    // One account per script; chains without declared versions use a plain xpub
+    let mut formats: Vec<Option<&ExtendedKeyMetadata>> = Vec::new();
+    for format in &chain.extended_key_formats {
+        if format.network == Network::Mainnet
+            && !format.script.is_multisig()
+            && !formats.iter().flatten().any(|f| f.script == format.script)
+        {
+            formats.push(Some(format));
+        }
+    }
+    if formats.is_empty() {
+        formats.push(None);
+    }
+
+    let master = PrivateNode::master(seed, BIP32_SEED_KEY);
+    let mut addresses = Vec::new();
+    for format in formats {
+        let purpose = format.map_or(44, |f| f.script.purpose());
+        let account_path = fill_account_path(&chain.id, purpose, Network::Mainnet, 0)?;
+        let path = parse_path(&account_path)?;
+
+        let mut parent_fingerprint = [0u8; 4];
+        let mut node = master.derive_secp256k1(path[0])?;
+        for &index in &path[1..] {
+            let parent_key = secp256k1::public_key_from_secret(&node.key[..], true)?;
+            parent_fingerprint.copy_from_slice(&hash160(&parent_key)[..4]);
+            node = node.derive_secp256k1(index)?;
+        }
+        let account_key = ExtendedPublicKey {
+            prefix: format.map_or("xpub".to_string(), |f| f.prefix.clone()),
+            version: format.map_or(BIP32_MAINNET_VERSION, |f| f.version),
+            network: Network::Mainnet,
+            depth: path.len() as u8,
+            parent_fingerprint,
+            child_number: path[path.len() - 1],
+            chain_code: *node.chain_code,
+            public_key: secp256k1::public_key_from_secret(&node.key[..], true)?
+                .try_into()
+                .unwrap(),
+        };
+
+        let receive = account_key.derive_child(0)?;
+        for index in 0..count {
+            let child = receive.derive_child(index)?;
+            if let Some(derived) = chain_address(&account_key, &child.public_key, chain, true)? {
+                addresses.push((
+                    DerivedAddress {
+                        path: format!("{}/0/{}", account_path, index),
+                        public_key: ::hex::encode(child.public_key),
+                        address: derived.address,
+                    },
+                    derived.encoding,
+                ));
+            }
+        }
+    }
+    Ok(addresses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(id: &str) -> &'static ChainMetadata {
+        Registry::get().chains.iter().find(|c| c.id == id).unwrap()
+    }
+
+    fn abandon_seed() -> [u8; 64] {
+        let mnemonic = bip39::Mnemonic::parse(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        )
+        .unwrap();
+        mnemonic.to_seed("")
+    }
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(
+            parse_path("m/44'/60'/0'/0/1").unwrap(),
+            vec![44 + HARDENED, 60 + HARDENED, HARDENED, 0, 1]
+        );
+        assert!(parse_path("m/44'/x").is_err());
+    }
+
+    #[test]
+    fn test_bip32_vector_1_master() {
+        // BIP-32 test vector 1: m/0H public key
+        let seed = ::hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let node = PrivateNode::master(&seed, BIP32_SEED_KEY)
+            .derive_secp256k1(HARDENED)
+            .unwrap();
+        assert_eq!(
+            ::hex::encode(secp256k1::public_key_from_secret(&node.key[..], true).unwrap()),
+            "035a784662a4a20a65bf6aab9ae98a6c068a81c52e4b032c0fb5400c706cfccc56"
+        );
+    }
+
+    #[test]
+    fn test_slip10_ed25519_vector_1() {
+        // SLIP-10 Ed25519 test vector 1: m/0H
+        let seed = ::hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let node = PrivateNode::master(&seed, SLIP10_ED25519_SEED_KEY)
+            .derive_ed25519(HARDENED)
+            .unwrap();
+        assert_eq!(
+            ::hex::encode(*node.key),
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+        );
+        assert!(node.derive_ed25519(0).is_err());
+    }
+
+    #[test]
+    fn test_seed_addresses_bitcoin_scripts() {
+        let addresses = seed_addresses(&abandon_seed(), chain("bitcoin"), 1).unwrap();
+        let by_path = |path: &str| {
+            addresses
+                .iter()
+                .find(|(a, _)| a.path == path)
+                .map(|(a, _)| a.address.as_str())
+        };
+        assert_eq!(
+            by_path("m/44'/0'/0'/0/0"),
+            Some("1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA")
+        );
+        // BIP-49 and BIP-84 test vectors
+        assert_eq!(
+            by_path("m/49'/0'/0'/0/0"),
+            Some("37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf")
+        );
+        assert_eq!(
+            by_path("m/84'/0'/0'/0/0"),
+            Some("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu")
+        );
+        assert_eq!(addresses[2].1, EncodingType::Bech32);
+    }
+
+    #[test]
+    fn test_seed_addresses_other_chains() {
+        let seed = abandon_seed();
+        let eth = seed_addresses(&seed, chain("ethereum"), 2).unwrap();
+        assert_eq!(eth.len(), 2);
+        assert_eq!(eth[0].0.path, "m/44'/60'/0'/0/0");
+        assert_eq!(
+            eth[0].0.address,
+            "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+        );
+
+        let solana = seed_addresses(&seed, chain("solana"), 1).unwrap();
+        assert_eq!(solana[0].0.path, "m/44'/501'/0'/0'");
+        assert_eq!(
+            solana[0].0.address,
+            "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk"
+        );
+
+        // Cosmos SDK accounts are BIP-32 secp256k1 keys
+        let cosmos = seed_addresses(&seed, chain("cosmos_hub"), 1).unwrap();
+        assert_eq!(cosmos[0].0.path, "m/44'/118'/0'/0/0");
+        assert_eq!(
+            cosmos[0].0.address,
+            "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4"
+        );
+
+        assert!(seed_addresses(&seed, chain("polkadot"), 1).is_err());
+        assert!(seed_addresses(&seed, chain("cardano"), 1)
+            .unwrap_err()
+            .to_string()
+            .contains("BIP32-Ed25519"));
+    }
+}
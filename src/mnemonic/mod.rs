@@ -0,0 +1,79 @@
+//! BIP-39 mnemonic phrases
+//!
+//! Recognition of mnemonics in every official wordlist with checksum
+//! validation, and derivation of their first addresses per chain through
+//! BIP-32 (secp256k1) and SLIP-10 (Ed25519). Phrases, seeds and private
+//! keys are zeroized when dropped and never appear in errors.
+
+mod derive;
+mod phrase;
+
+use crate::bip32::{DerivedAddress, MAX_RANGE_SIZE};
+use crate::registry::Registry;
+use crate::Error;
+use zeroize::Zeroizing;
+
+pub use phrase::MnemonicInfo;
+
+pub(crate) use derive::seed_addresses;
+pub(crate) use phrase::{looks_like_mnemonic, parse_mnemonic};
+
+/// Validate a BIP-39 mnemonic and report its language and strength
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::inspect_mnemonic;
+///
+/// let info = inspect_mnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about")?;
+/// assert_eq!(info.language, "English");
+/// assert_eq!(info.word_count, 12);
+/// assert_eq!(info.strength, 128);
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn inspect_mnemonic(phrase: &str) -> Result<MnemonicInfo, Error> {
+    Ok(MnemonicInfo::of(&parse_mnemonic(phrase)?))
+}
+
+/// Derive the first `count` addresses of a BIP-39 mnemonic on a chain
+///
+/// Paths come from the chain's account path template: secp256k1 chains use
+/// BIP-32 receive addresses `<account>/0/i` for each script the chain declares
+/// (BIP-44, 49 and 84 on Bitcoin); Ed25519 chains use SLIP-10 at
+/// `m/44'/<coin>'/i'/0'`. Chains without a BIP-32/SLIP-10 path (Substrate,
+/// Cardano) are errors.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::derive_mnemonic_addresses;
+///
+/// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+/// let addresses = derive_mnemonic_addresses(phrase, "", "ethereum", 1)?;
+/// assert_eq!(addresses[0].path, "m/44'/60'/0'/0/0");
+/// assert_eq!(addresses[0].address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn derive_mnemonic_addresses(
+    phrase: &str,
+    passphrase: &str,
+    chain: &str,
+    count: u32,
+) -> Result<Vec<DerivedAddress>, Error> {
+    if count == 0 || count > MAX_RANGE_SIZE {
+        return Err(Error::InvalidInput(format!(
+            "Count must be between 1 and {}",
+            MAX_RANGE_SIZE
+        )));
+    }
+    let chain_metadata = Registry::get()
+        .chains
+        .iter()
+        .find(|c| c.id == chain)
+        .ok_or_else(|| Error::InvalidInput(format!("Unknown chain: {}", chain)))?;
+    let seed = Zeroizing::new(parse_mnemonic(phrase)?.to_seed(passphrase));
+    Ok(seed_addresses(&seed, chain_metadata, count)?
+        .into_iter()
+        .map(|(derived, _)| derived)
+        .collect())
+}
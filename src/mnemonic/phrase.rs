@@ -0,0 +1,160 @@
+//! BIP-39 phrase recognition across the official wordlists
+
+use crate::Error;
+use bip39::{Language, Mnemonic};
+use std::borrow::Cow;
+use zeroize::Zeroizing;
+
+/// Word counts defined by BIP-39 (128 to 256 bits of entropy)
+const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+/// Language and strength of a BIP-39 mnemonic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MnemonicInfo {
+    /// Wordlist language (e.g. "English", "Japanese")
+    pub language: String,
+    /// Number of words
+    pub word_count: usize,
+    /// Entropy in bits (128, 160, 192, 224 or 256)
+    pub strength: usize,
+}
+
+impl MnemonicInfo {
+    pub(crate) fn of(mnemonic: &Mnemonic) -> Self {
+        let word_count = mnemonic.word_count();
+        MnemonicInfo {
+            language: language_name(mnemonic.language()).to_string(),
+            word_count,
+            strength: word_count * 32 / 3,
+        }
+    }
+}
+
+/// Display name of a wordlist language
+fn language_name(language: Language) -> &'static str {
+    match language {
+        Language::English => "English",
+        Language::SimplifiedChinese => "Chinese (Simplified)",
+        Language::TraditionalChinese => "Chinese (Traditional)",
+        Language::Czech => "Czech",
+        Language::French => "French",
+        Language::Italian => "Italian",
+        Language::Japanese => "Japanese",
+        Language::Korean => "Korean",
+        Language::Portuguese => "Portuguese",
+        Language::Spanish => "Spanish",
+    }
+}
+
+/// Lowercase and NFKD-normalize a phrase, single-space separated
+fn normalize(input: &str) -> Zeroizing<String> {
+    let words: Zeroizing<Vec<String>> = Zeroizing::new(
+        input
+            .split_whitespace()
+            .map(|word| word.to_lowercase())
+            .collect(),
+    );
+    let mut normalized = Cow::Owned(words.join(" "));
+    Mnemonic::normalize_utf8_cow(&mut normalized);
+    Zeroizing::new(normalized.into_owned())
+}
+
+/// Languages whose wordlist holds every word of the phrase, checksum aside
+fn wordlist_languages(normalized: &str) -> Vec<Language> {
+    Language::ALL
+        .iter()
+        .copied()
+        .filter(|&language| {
+            Mnemonic::parse_in_normalized_without_checksum_check(language, normalized).is_ok()
+        })
+        .collect()
+}
+
+/// Check whether an input is 12 to 24 words that all belong to one BIP-39 wordlist
+pub(crate) fn looks_like_mnemonic(input: &str) -> bool {
+    WORD_COUNTS.contains(&input.split_whitespace().count())
+        && !wordlist_languages(&normalize(input)).is_empty()
+}
+
+/// Parse a BIP-39 mnemonic, validating its checksum
+///
+/// Errors never include the phrase or its words.
+pub(crate) fn parse_mnemonic(input: &str) -> Result<Mnemonic, Error> {
+    let normalized = normalize(input);
+    let word_count = normalized.split(' ').count();
+    if !WORD_COUNTS.contains(&word_count) {
+        return Err(Error::InvalidInput(format!(
+            "A BIP-39 mnemonic has 12, 15, 18, 21 or 24 words, got {}",
+            word_count
+        )));
+    }
+    let languages = wordlist_languages(&normalized);
+    if languages.is_empty() {
+        return Err(Error::InvalidInput(
+            "Mnemonic words are not all from one BIP-39 wordlist".to_string(),
+        ));
+    }
+    // Wordlists share a few words; the checksum settles the language
+    languages
+        .iter()
+        .find_map(|&language| Mnemonic::parse_in_normalized(language, &normalized).ok())
+        .ok_or_else(|| {
+            Error::InvalidInput(format!(
+                "Invalid BIP-39 checksum for a {}-word {} mnemonic",
+                word_count,
+                language_name(languages[0])
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABANDON: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_parse_mnemonic_english() {
+        let mnemonic = parse_mnemonic(ABANDON).unwrap();
+        assert_eq!(
+            MnemonicInfo::of(&mnemonic),
+            MnemonicInfo {
+                language: "English".to_string(),
+                word_count: 12,
+                strength: 128,
+            }
+        );
+        // Case and spacing are normalized
+        assert!(parse_mnemonic(&ABANDON.to_uppercase().replace(' ', "  ")).is_ok());
+
+        let words = "zoo ".repeat(23) + "vote";
+        let info = MnemonicInfo::of(&parse_mnemonic(&words).unwrap());
+        assert_eq!((info.word_count, info.strength), (24, 256));
+    }
+
+    #[test]
+    fn test_parse_mnemonic_other_languages() {
+        // BIP-39 Japanese test vector for all-zero entropy
+        let japanese = "あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あおぞら";
+        let info = MnemonicInfo::of(&parse_mnemonic(japanese).unwrap());
+        assert_eq!(info.language, "Japanese");
+
+        let spanish = "ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco abierto";
+        let info = MnemonicInfo::of(&parse_mnemonic(spanish).unwrap());
+        assert_eq!(info.language, "Spanish");
+    }
+
+    #[test]
+    fn test_parse_mnemonic_invalid() {
+        // Valid words, wrong checksum
+        let bad_checksum = ABANDON.replace("about", "abandon");
+        assert!(looks_like_mnemonic(&bad_checksum));
+        let err = parse_mnemonic(&bad_checksum).unwrap_err().to_string();
+        assert!(err.contains("checksum"));
+        assert!(!err.contains("abandon"));
+
+        assert!(!looks_like_mnemonic("abandon abandon abandon"));
+        assert!(!looks_like_mnemonic(&ABANDON.replace("about", "bitcoin1")));
+        assert!(parse_mnemonic(&ABANDON.replace("about", "notaword")).is_err());
+    }
+}
@@ -1,28 +1,69 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ChainConfig {
     pub id: String,
     pub name: String,
     pub curve: String,
     pub address_pipeline: String,
+    /// Keys derive with BIP32-Ed25519 (CIP-1852), which SLIP-10 does not reproduce
     #[serde(default)]
-    pub requires_stake_key: bool,
+    pub bip32_ed25519: bool,
+    /// SLIP-44 coin type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slip44: Option<u32>,
+    /// Account-level HD path template, e.g. `m/44'/{coin_type}'/{account}'`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub derivation_path: Option<String>,
+    /// EIP-155 chain id (EVM chains)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evm_chain_id: Option<u64>,
+    /// Magic prefix of signed messages (BIP-137), e.g. "Bitcoin Signed Message:\n"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_prefix: Option<String>,
     #[serde(default)]
     pub address_params: Value,
     pub public_key_formats: Vec<PublicKeyFormat>,
+    /// Transaction and block hash formats
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hash_formats: Vec<HashFormat>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PublicKeyFormat {
     pub encoding: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exact_length: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub length_range: Option<(usize, usize)>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prefixes: Vec<String>,
+    /// Curve of keys in this format when it differs from the chain's
+    /// (e.g. Ed25519 validator consensus keys on Cosmos chains)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub curve: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HashFormat {
+    /// "transaction" or "block"
+    pub kind: String,
+    pub encoding: String,
+    /// Decoded length in bytes
+    pub bytes: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub prefixes: Vec<String>,
+    /// Canonical display is uppercase hex (Tendermint)
+    #[serde(default)]
+    pub uppercase: bool,
+    /// Minimum number of leading zero bytes (proof-of-work block hashes)
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub leading_zero_bytes: usize,
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
 }
 
 #[derive(Debug, Clone, Deserialize)]
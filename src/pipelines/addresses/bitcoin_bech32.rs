@@ -2,52 +2,27 @@ use crate::shared::crypto::hash::hash160;
 use crate::shared::crypto::secp256k1;
 use crate::shared::encoding::bech32 as bech32_encoding;
 use crate::Error;
-use bech32::{u5, Variant};
 use serde_json::Value;
 
 /// Execute Bitcoin Bech32 address derivation pipeline
+///
+/// Encodes a witness version 0 program (P2WPKH) over hash160 of the key as
+/// serialized, as BIP-173 does for the compressed generator point
+/// (`bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4`). Segwit wallets only use
+/// compressed keys; uncompressed ones are hashed in their 65-byte form.
 pub fn execute_bitcoin_bech32_pipeline(pk_bytes: &[u8], params: &Value) -> Result<String, Error> {
-    // Extract 64-byte key
-    let key_64 = extract_64_bytes(pk_bytes)?;
-
-    // Hash with RIPEMD160 (which internally does SHA256 then RIPEMD160)
-    let payload = hash160(&key_64);
+    // Hash the key in its serialized form (compressed or uncompressed)
+    let serialized = secp256k1::serialize_public_key(pk_bytes)?;
+    let payload = hash160(&serialized);
 
     // Get HRP from params (default to "bc" for Bitcoin mainnet)
     let hrp = params.get("hrp").and_then(|v| v.as_str()).unwrap_or("bc");
 
-    // Convert to base32
-    let data = bech32_encoding::convert_bits(&payload, 8, 5, true)
-        .map_err(|e| Error::InvalidInput(format!("Bech32 conversion error: {}", e)))?;
-    let data_u5: Vec<u5> = bech32_encoding::bytes_to_u5(&data);
-
-    // Encode as Bech32
-    bech32_encoding::encode(hrp, &data_u5, Variant::Bech32)
+    // Encode as a witness version 0 program (P2WPKH)
+    bech32_encoding::encode_segwit(hrp, 0, &payload)
         .map_err(|e| Error::InvalidInput(format!("Bech32 encoding error: {}", e)))
 }
 
-fn extract_64_bytes(public_key: &[u8]) -> Result<Vec<u8>, Error> {
-    if public_key.len() == 33 {
-        let uncompressed = secp256k1::decompress_public_key(public_key)?;
-        if uncompressed.len() == 65 && uncompressed[0] == 0x04 {
-            Ok(uncompressed[1..65].to_vec())
-        } else {
-            Err(Error::InvalidInput(
-                "Invalid decompressed key format".to_string(),
-            ))
-        }
-    } else if public_key.len() == 65 && public_key[0] == 0x04 {
-        Ok(public_key[1..65].to_vec())
-    } else if public_key.len() == 64 {
-        Ok(public_key.to_vec())
-    } else {
-        Err(Error::InvalidInput(format!(
-            "Invalid secp256k1 key length: {} bytes",
-            public_key.len()
-        )))
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,7 +40,8 @@ mod tests {
         let result = execute_bitcoin_bech32_pipeline(&compressed_key, &params);
         assert!(result.is_ok());
         let address = result.unwrap();
-        assert!(address.starts_with("bc1"));
+        // BIP-173 P2WPKH example for the generator point
+        assert_eq!(address, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4");
     }
 
     #[test]
@@ -122,4 +98,16 @@ mod tests {
         let address = result.unwrap();
         assert!(address.starts_with("tb1"));
     }
+
+    #[test]
+    fn test_bitcoin_bech32_pipeline_testnet_vector() {
+        // BIP-173 testnet P2WPKH of the compressed generator point
+        let compressed_key =
+            hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+                .unwrap();
+        assert_eq!(
+            execute_bitcoin_bech32_pipeline(&compressed_key, &json!({"hrp": "tb"})).unwrap(),
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"
+        );
+    }
 }
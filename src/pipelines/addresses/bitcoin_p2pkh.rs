@@ -5,12 +5,16 @@ use base58::ToBase58;
 use serde_json::Value;
 
 /// Execute Bitcoin P2PKH address derivation pipeline
+///
+/// The address commits to hash160 of the key as wallets serialize it: 33
+/// bytes for compressed keys, 65 bytes for uncompressed ones (a bare 64-byte
+/// key is taken as uncompressed). The generator point gives
+/// `1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH` compressed and
+/// `1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm` uncompressed.
 pub fn execute_bitcoin_p2pkh_pipeline(pk_bytes: &[u8], params: &Value) -> Result<String, Error> {
-    // Extract 64-byte key
-    let key_64 = extract_64_bytes(pk_bytes)?;
-
-    // Hash with RIPEMD160 (which internally does SHA256 then RIPEMD160)
-    let payload = hash160(&key_64);
+    // Hash the key in its serialized form (compressed or uncompressed)
+    let serialized = secp256k1::serialize_public_key(pk_bytes)?;
+    let payload = hash160(&serialized);
 
     // Get version byte from params (default to 0x00 for Bitcoin mainnet)
     let version: u8 = params
@@ -33,28 +37,6 @@ pub fn execute_bitcoin_p2pkh_pipeline(pk_bytes: &[u8], params: &Value) -> Result
     Ok(full.as_slice().to_base58())
 }
 
-fn extract_64_bytes(public_key: &[u8]) -> Result<Vec<u8>, Error> {
-    if public_key.len() == 33 {
-        let uncompressed = secp256k1::decompress_public_key(public_key)?;
-        if uncompressed.len() == 65 && uncompressed[0] == 0x04 {
-            Ok(uncompressed[1..65].to_vec())
-        } else {
-            Err(Error::InvalidInput(
-                "Invalid decompressed key format".to_string(),
-            ))
-        }
-    } else if public_key.len() == 65 && public_key[0] == 0x04 {
-        Ok(public_key[1..65].to_vec())
-    } else if public_key.len() == 64 {
-        Ok(public_key.to_vec())
-    } else {
-        Err(Error::InvalidInput(format!(
-            "Invalid secp256k1 key length: {} bytes",
-            public_key.len()
-        )))
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,6 +55,28 @@ mod tests {
         assert!(!address.is_empty());
     }
 
+    #[test]
+    fn test_bitcoin_p2pkh_pipeline_hashes_serialized_key() {
+        // hash160 covers the prefix byte, so compressed and uncompressed keys differ
+        let params = json!({"version_byte": 0x00});
+        let compressed =
+            hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+                .unwrap();
+        let uncompressed = hex::decode("0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8").unwrap();
+        assert_eq!(
+            execute_bitcoin_p2pkh_pipeline(&compressed, &params).unwrap(),
+            "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"
+        );
+        assert_eq!(
+            execute_bitcoin_p2pkh_pipeline(&uncompressed, &params).unwrap(),
+            "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm"
+        );
+        assert_eq!(
+            execute_bitcoin_p2pkh_pipeline(&uncompressed[1..], &params).unwrap(),
+            "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm"
+        );
+    }
+
     #[test]
     fn test_bitcoin_p2pkh_pipeline_uncompressed_key() {
         let uncompressed_key = hex::decode("0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8").unwrap();
@@ -111,6 +115,18 @@ mod tests {
 
         let result = execute_bitcoin_p2pkh_pipeline(&key_64, &params);
         assert!(result.is_ok());
+
+        // Litecoin P2PKH of the compressed generator point
+        let compressed =
+            hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+                .unwrap();
+        let address = execute_bitcoin_p2pkh_pipeline(&compressed, &params).unwrap();
+        let decoded = crate::shared::checksum::base58check::decode_data(&address).unwrap();
+        assert_eq!(decoded[0], 0x30);
+        assert_eq!(
+            ::hex::encode(&decoded[1..]),
+            "751e76e8199196d454941c45d1b3a323f1433bd6"
+        );
     }
 
     #[test]
@@ -126,7 +142,7 @@ mod tests {
     fn test_bitcoin_p2pkh_pipeline_invalid_decompressed_format() {
         // Test with a key that has invalid format (wrong prefix for compressed)
         // Use 0x04 prefix which is for uncompressed, but length is 33
-        let mut invalid_key = vec![0x04u8; 33];
+        let invalid_key = vec![0x04u8; 33];
         let params = json!({"version_byte": 0x00});
 
         let result = execute_bitcoin_p2pkh_pipeline(&invalid_key, &params);
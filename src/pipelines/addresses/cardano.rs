@@ -1,44 +1,46 @@
-use crate::shared::encoding::bech32 as bech32_encoding;
+use crate::cardano::{key_hash_credential, CardanoAddress, CardanoAddressType};
 use crate::Error;
-use bech32::{u5, Variant};
 use serde_json::Value;
-use sha3::{Digest, Sha3_256};
 
 /// Execute Cardano address derivation pipeline
+///
+/// A single key gives an enterprise address (`"address_type": "enterprise"`,
+/// the default) or a reward address (`"reward"`), on `"network"` `mainnet`
+/// (the default) or `testnet`. Base addresses need a stake key as well; see
+/// `cardano_addresses`.
 pub fn execute_cardano_pipeline(pk_bytes: &[u8], params: &Value) -> Result<String, Error> {
-    if pk_bytes.len() != 32 {
-        return Err(Error::InvalidInput(format!(
-            "Invalid Ed25519 key length: {} bytes (expected 32)",
-            pk_bytes.len()
-        )));
+    // Blake2b-224 of the verification key
+    let credential = key_hash_credential(pk_bytes)?;
+
+    let network_id = match params.get("network").and_then(|v| v.as_str()) {
+        None | Some("mainnet") => 1,
+        Some("testnet") => 0,
+        Some(other) => {
+            return Err(Error::InvalidInput(format!(
+                "Unknown Cardano network: {}",
+                other
+            )))
+        }
+    };
+    let (address_type, payment, stake) = match params.get("address_type").and_then(|v| v.as_str()) {
+        None | Some("enterprise") => (CardanoAddressType::Enterprise, Some(credential), None),
+        Some("reward") => (CardanoAddressType::Reward, None, Some(credential)),
+        Some(other) => {
+            return Err(Error::InvalidInput(format!(
+                "Cardano address type {} cannot be derived from a single key",
+                other
+            )))
+        }
+    };
+
+    CardanoAddress {
+        address_type,
+        network_id,
+        payment,
+        stake,
+        pointer: None,
     }
-
-    // Hash with SHA3-256
-    let hash = Sha3_256::digest(pk_bytes);
-
-    // Slice first 28 bytes
-    let payload = &hash[..28];
-
-    // Get header and HRP from params
-    let header: u8 = params
-        .get("header")
-        .and_then(|v| v.as_u64())
-        .map(|v| v as u8)
-        .unwrap_or(0x00);
-
-    let hrp = params.get("hrp").and_then(|v| v.as_str()).unwrap_or("addr");
-
-    // Prefix with header
-    let address_bytes = [&[header], payload].concat();
-
-    // Convert to base32
-    let data_u5 = bech32_encoding::convert_bits(&address_bytes, 8, 5, true)
-        .map_err(|e| Error::InvalidInput(format!("Bech32 conversion error: {}", e)))?;
-    let data_u5_vec: Vec<u5> = bech32_encoding::bytes_to_u5(&data_u5);
-
-    // Encode as Bech32
-    bech32_encoding::encode(hrp, &data_u5_vec, Variant::Bech32)
-        .map_err(|e| Error::InvalidInput(format!("Bech32 encoding error: {}", e)))
+    .encode()
 }
 
 #[cfg(test)]
@@ -46,22 +48,26 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    // CIP-19 test vector payment and stake keys
+    const PAYMENT_KEY: &str = "73fea80d424276ad0978d4fe5310e8bc2d485f5f6bb3bf87612989f112ad5a7d";
+    const STAKE_KEY: &str = "09ab278d49b7b86a055185c474c4942281ddfa05a54684c7e8a6f230625aee57";
+
     #[test]
     fn test_cardano_pipeline_valid_key() {
-        // Use a valid 32-byte Ed25519 key
-        let key = vec![0u8; 32];
-        let params = json!({"hrp": "addr", "header": 0x00});
+        let key = hex::decode(PAYMENT_KEY).unwrap();
+        let params = json!({"address_type": "enterprise", "network": "mainnet"});
 
         let result = execute_cardano_pipeline(&key, &params);
-        assert!(result.is_ok());
-        let address = result.unwrap();
-        assert!(address.starts_with("addr1"));
+        assert_eq!(
+            result.unwrap(),
+            "addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8"
+        );
     }
 
     #[test]
     fn test_cardano_pipeline_invalid_length() {
         let invalid_key = vec![0u8; 33]; // Wrong length
-        let params = json!({"hrp": "addr"});
+        let params = json!({});
 
         let result = execute_cardano_pipeline(&invalid_key, &params);
         assert!(result.is_err());
@@ -70,35 +76,43 @@ mod tests {
     }
 
     #[test]
-    fn test_cardano_pipeline_default_hrp() {
-        let key = vec![0u8; 32];
-        let params = json!({}); // No HRP specified, should default to "addr"
+    fn test_cardano_pipeline_defaults_to_mainnet_enterprise() {
+        let key = hex::decode(PAYMENT_KEY).unwrap();
+        // Chain address_params carry neither field
+        let params = json!({"networks": {}});
 
         let result = execute_cardano_pipeline(&key, &params);
-        assert!(result.is_ok());
-        let address = result.unwrap();
-        assert!(address.starts_with("addr1"));
+        assert!(result.unwrap().starts_with("addr1v"));
     }
 
     #[test]
-    fn test_cardano_pipeline_custom_hrp() {
-        let key = vec![0u8; 32];
-        let params = json!({"hrp": "stake"});
+    fn test_cardano_pipeline_reward_address() {
+        let key = hex::decode(STAKE_KEY).unwrap();
+        let params = json!({"address_type": "reward"});
 
         let result = execute_cardano_pipeline(&key, &params);
-        assert!(result.is_ok());
-        let address = result.unwrap();
-        assert!(address.starts_with("stake1"));
+        assert_eq!(
+            result.unwrap(),
+            "stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw"
+        );
     }
 
     #[test]
-    fn test_cardano_pipeline_custom_header() {
-        let key = vec![0u8; 32];
-        let params = json!({"hrp": "addr", "header": 0x01});
+    fn test_cardano_pipeline_testnet() {
+        let key = hex::decode(PAYMENT_KEY).unwrap();
+        let params = json!({"network": "testnet"});
 
         let result = execute_cardano_pipeline(&key, &params);
-        assert!(result.is_ok());
-        let address = result.unwrap();
-        assert!(address.starts_with("addr1"));
+        assert_eq!(
+            result.unwrap(),
+            "addr_test1vz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerspjrlsz"
+        );
+    }
+
+    #[test]
+    fn test_cardano_pipeline_rejects_base_and_unknown_params() {
+        let key = hex::decode(PAYMENT_KEY).unwrap();
+        assert!(execute_cardano_pipeline(&key, &json!({"address_type": "base"})).is_err());
+        assert!(execute_cardano_pipeline(&key, &json!({"network": "devnet"})).is_err());
     }
 }
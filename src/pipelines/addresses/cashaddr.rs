@@ -0,0 +1,70 @@
+use crate::shared::crypto::hash::hash160;
+use crate::shared::crypto::secp256k1;
+use crate::shared::encoding::cashaddr;
+use crate::Error;
+use serde_json::Value;
+
+/// Execute Bitcoin Cash CashAddr (P2PKH) address derivation pipeline
+pub fn execute_cashaddr_pipeline(pk_bytes: &[u8], params: &Value) -> Result<String, Error> {
+    // Hash the key in its serialized form (compressed or uncompressed)
+    let serialized = secp256k1::serialize_public_key(pk_bytes)?;
+    let payload = hash160(&serialized);
+
+    // Get prefix from params (default to Bitcoin Cash mainnet)
+    let prefix = params
+        .get("prefix")
+        .and_then(|p| p.as_str())
+        .unwrap_or("bitcoincash");
+
+    cashaddr::encode(prefix, cashaddr::TYPE_P2PKH, &payload)
+        .map_err(|e| Error::InvalidInput(format!("CashAddr encoding error: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_cashaddr_pipeline_compressed_key() {
+        let compressed_key =
+            hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+                .unwrap();
+        let params = json!({"prefix": "bitcoincash"});
+
+        let address = execute_cashaddr_pipeline(&compressed_key, &params).unwrap();
+        assert_eq!(
+            address,
+            "bitcoincash:qp63uahgrxged4z5jswyt5dn5v3lzsem6cy4spdc2h"
+        );
+    }
+
+    #[test]
+    fn test_cashaddr_pipeline_uncompressed_and_64_byte_key_agree() {
+        let uncompressed_key = hex::decode("0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8").unwrap();
+        let params = json!({});
+
+        let from_65 = execute_cashaddr_pipeline(&uncompressed_key, &params).unwrap();
+        let from_64 = execute_cashaddr_pipeline(&uncompressed_key[1..], &params).unwrap();
+        assert_eq!(from_65, from_64);
+        assert!(from_65.starts_with("bitcoincash:q"));
+    }
+
+    #[test]
+    fn test_cashaddr_pipeline_testnet_prefix() {
+        let compressed_key =
+            hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+                .unwrap();
+        let params = json!({"prefix": "bchtest"});
+
+        let address = execute_cashaddr_pipeline(&compressed_key, &params).unwrap();
+        assert!(address.starts_with("bchtest:q"));
+    }
+
+    #[test]
+    fn test_cashaddr_pipeline_invalid_key() {
+        let params = json!({});
+        assert!(execute_cashaddr_pipeline(&[0u8; 32], &params).is_err());
+        assert!(execute_cashaddr_pipeline(&[0x04u8; 33], &params).is_err());
+    }
+}
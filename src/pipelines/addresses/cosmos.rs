@@ -1,32 +1,47 @@
-use crate::shared::crypto::hash::sha256;
+use crate::shared::crypto::hash::{hash160, sha256};
+use crate::shared::crypto::secp256k1;
 use crate::shared::encoding::bech32 as bech32_encoding;
 use crate::Error;
 use bech32::{u5, Variant};
 use serde_json::Value;
 
 /// Execute Cosmos address derivation pipeline
+///
+/// secp256k1 keys give the account address, `ripemd160(sha256(compressed key))`
+/// under `hrp`. 32-byte Ed25519 keys are Tendermint consensus keys and give the
+/// validator consensus address, `sha256(key)[..20]` under `roles.valcons.hrp`.
 pub fn execute_cosmos_pipeline(pk_bytes: &[u8], params: &Value) -> Result<String, Error> {
-    if pk_bytes.len() != 32 {
-        return Err(Error::InvalidInput(format!(
-            "Invalid Ed25519 key length: {} bytes (expected 32)",
-            pk_bytes.len()
-        )));
-    }
-
-    // Hash with SHA256
-    let hash = sha256(pk_bytes);
-
-    // Slice first 20 bytes
-    let address_bytes = &hash[..20];
-
-    // Get HRP from params (default to "cosmos")
-    let hrp = params
-        .get("hrp")
-        .and_then(|v| v.as_str())
-        .unwrap_or("cosmos");
+    let (address_bytes, hrp) = match pk_bytes.len() {
+        33 | 64 | 65 => {
+            let compressed = secp256k1::compress_public_key(pk_bytes)?;
+            // Get HRP from params (default to "cosmos")
+            let hrp = params
+                .get("hrp")
+                .and_then(|v| v.as_str())
+                .unwrap_or("cosmos");
+            (hash160(&compressed).to_vec(), hrp)
+        }
+        32 => {
+            let hrp = params
+                .get("roles")
+                .and_then(|roles| roles.get("valcons"))
+                .and_then(|valcons| valcons.get("hrp"))
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| {
+                    Error::InvalidInput("No valcons HRP for Ed25519 consensus keys".to_string())
+                })?;
+            (sha256(pk_bytes)[..20].to_vec(), hrp)
+        }
+        length => {
+            return Err(Error::InvalidInput(format!(
+                "Invalid Cosmos key length: {} bytes (expected 33 or 65 secp256k1, 32 Ed25519)",
+                length
+            )))
+        }
+    };
 
     // Convert to base32
-    let data = bech32_encoding::convert_bits(address_bytes, 8, 5, true)
+    let data = bech32_encoding::convert_bits(&address_bytes, 8, 5, true)
         .map_err(|e| Error::InvalidInput(format!("Bech32 conversion error: {}", e)))?;
     let data_u5: Vec<u5> = bech32_encoding::bytes_to_u5(&data);
 
@@ -40,34 +55,71 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    // Generator point, compressed
+    const SECP256K1_KEY: &str =
+        "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    fn secp256k1_key() -> Vec<u8> {
+        ::hex::decode(SECP256K1_KEY).unwrap()
+    }
+
     #[test]
     fn test_cosmos_pipeline_valid_key() {
-        let key = vec![0u8; 32];
         let params = json!({"hrp": "cosmos"});
 
-        let result = execute_cosmos_pipeline(&key, &params);
-        assert!(result.is_ok());
-        let address = result.unwrap();
-        assert!(address.starts_with("cosmos1"));
+        let result = execute_cosmos_pipeline(&secp256k1_key(), &params);
+        assert_eq!(
+            result.unwrap(),
+            "cosmos1w508d6qejxtdg4y5r3zarvary0c5xw7k6ah60c"
+        );
+    }
+
+    #[test]
+    fn test_cosmos_pipeline_uncompressed_key() {
+        let params = json!({"hrp": "cosmos"});
+        let uncompressed = secp256k1::decompress_public_key(&secp256k1_key()).unwrap();
+
+        // Accounts always hash the compressed key
+        assert_eq!(
+            execute_cosmos_pipeline(&uncompressed, &params).unwrap(),
+            execute_cosmos_pipeline(&secp256k1_key(), &params).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cosmos_pipeline_ed25519_valcons() {
+        // RFC 8032 test 1 public key
+        let key = ::hex::decode("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+            .unwrap();
+        let params = json!({"hrp": "cosmos", "roles": {"valcons": {"hrp": "cosmosvalcons"}}});
+
+        assert_eq!(
+            execute_cosmos_pipeline(&key, &params).unwrap(),
+            "cosmosvalcons1y8lrrhap2j3xzcntlp2qgm7jyudhhm2tfeslut"
+        );
+        // Without a valcons role there is no address for Ed25519 keys
+        assert!(execute_cosmos_pipeline(&key, &json!({"hrp": "cosmos"})).is_err());
     }
 
     #[test]
     fn test_cosmos_pipeline_invalid_length() {
-        let invalid_key = vec![0u8; 33];
+        let invalid_key = vec![0u8; 34];
         let params = json!({"hrp": "cosmos"});
 
         let result = execute_cosmos_pipeline(&invalid_key, &params);
         assert!(result.is_err());
         let error_msg = result.unwrap_err().to_string();
-        assert!(error_msg.contains("32") || error_msg.contains("Invalid"));
+        assert!(error_msg.contains("34") || error_msg.contains("Invalid"));
+
+        // Not on the curve
+        assert!(execute_cosmos_pipeline(&[0u8; 33], &params).is_err());
     }
 
     #[test]
     fn test_cosmos_pipeline_default_hrp() {
-        let key = vec![0u8; 32];
         let params = json!({}); // No HRP, should default to "cosmos"
 
-        let result = execute_cosmos_pipeline(&key, &params);
+        let result = execute_cosmos_pipeline(&secp256k1_key(), &params);
         assert!(result.is_ok());
         let address = result.unwrap();
         assert!(address.starts_with("cosmos1"));
@@ -75,12 +127,12 @@ mod tests {
 
     #[test]
     fn test_cosmos_pipeline_custom_hrp() {
-        let key = vec![0u8; 32];
         let params = json!({"hrp": "osmo"});
 
-        let result = execute_cosmos_pipeline(&key, &params);
-        assert!(result.is_ok());
-        let address = result.unwrap();
-        assert!(address.starts_with("osmo1"));
+        let result = execute_cosmos_pipeline(&secp256k1_key(), &params);
+        assert_eq!(
+            result.unwrap(),
+            "osmo1w508d6qejxtdg4y5r3zarvary0c5xw7kjxy2e2"
+        );
     }
 }
@@ -1,6 +1,8 @@
+use crate::input::extract_characteristics;
 use crate::loaders::load_pipeline;
+use crate::registry::Registry;
 use crate::Error;
-use serde_json::Value;
+use serde_json::{json, Value};
 
 /// Execute a pipeline by ID
 pub fn execute_pipeline(
@@ -21,6 +23,8 @@ pub fn execute_pipeline(
         "ss58" => ss58::execute_ss58_pipeline(pk_bytes, params),
         "cardano" => cardano::execute_cardano_pipeline(pk_bytes, params),
         "tron" => tron::execute_tron_pipeline(pk_bytes, params),
+        "cashaddr" => cashaddr::execute_cashaddr_pipeline(pk_bytes, params),
+        "ethermint" => ethermint::execute_ethermint_pipeline(pk_bytes, params),
         _ => Err(Error::InvalidInput(format!(
             "Unknown pipeline: {}",
             pipeline_id
@@ -28,5 +32,41 @@ pub fn execute_pipeline(
     }
 }
 
+/// Derive a chain's address from a public key with the chain's configured pipeline
+///
+/// The derived address must validate against one of the chain's address formats.
+/// Cardano keys give their enterprise address; base addresses also need a
+/// stake key (`cardano_addresses`).
+pub fn derive_chain_address(chain_id: &str, pk_bytes: &[u8]) -> Result<String, Error> {
+    let registry = Registry::get();
+    let chain_config = registry
+        .get_chain_config(chain_id)
+        .ok_or_else(|| Error::InvalidInput(format!("Unknown chain: {}", chain_id)))?;
+    let chain_metadata = registry
+        .chains
+        .iter()
+        .find(|c| c.id == chain_id)
+        .ok_or_else(|| Error::InvalidInput(format!("Unknown chain: {}", chain_id)))?;
+
+    let params = json!(chain_config.address_params);
+    let derived_address = execute_pipeline(&chain_config.address_pipeline, pk_bytes, &params)?;
+
+    let derived_chars = extract_characteristics(&derived_address);
+    if chain_metadata
+        .address_formats
+        .iter()
+        .any(|addr_format| addr_format.validate_raw(&derived_address, &derived_chars))
+    {
+        Ok(derived_address)
+    } else {
+        Err(Error::InvalidInput(format!(
+            "Derived address does not match any {} address format",
+            chain_id
+        )))
+    }
+}
+
 // Import pipeline executors
-use super::{bitcoin_bech32, bitcoin_p2pkh, cardano, cosmos, evm, solana, ss58, tron};
+use super::{
+    bitcoin_bech32, bitcoin_p2pkh, cardano, cashaddr, cosmos, ethermint, evm, solana, ss58, tron,
+};
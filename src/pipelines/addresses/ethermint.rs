@@ -0,0 +1,80 @@
+use super::{cosmos, evm};
+use crate::shared::encoding::bech32 as bech32_encoding;
+use crate::Error;
+use bech32::{u5, Variant};
+use serde_json::Value;
+
+/// Execute Ethermint (`eth_secp256k1`) address derivation pipeline
+///
+/// Accounts are the EVM address bytes, `keccak256(x || y)[12..]`, encoded in
+/// Bech32 under `hrp`, so `evmos1...` and `0x...` name the same account.
+/// 32-byte Ed25519 consensus keys give the `valcons` address as on other
+/// Cosmos chains.
+pub fn execute_ethermint_pipeline(pk_bytes: &[u8], params: &Value) -> Result<String, Error> {
+    if pk_bytes.len() == 32 {
+        return cosmos::execute_cosmos_pipeline(pk_bytes, params);
+    }
+
+    let address_bytes = evm::evm_address_bytes(pk_bytes)?;
+    let hrp = params
+        .get("hrp")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| Error::InvalidInput("No HRP for Ethermint accounts".to_string()))?;
+
+    // Convert to base32
+    let data = bech32_encoding::convert_bits(&address_bytes, 8, 5, true)
+        .map_err(|e| Error::InvalidInput(format!("Bech32 conversion error: {}", e)))?;
+    let data_u5: Vec<u5> = bech32_encoding::bytes_to_u5(&data);
+
+    // Encode as Bech32
+    bech32_encoding::encode(hrp, &data_u5, Variant::Bech32)
+        .map_err(|e| Error::InvalidInput(format!("Bech32 encoding error: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    // Compressed public key of secret 0x4646...46, EVM address 0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F
+    const SECP256K1_KEY: &str =
+        "024bc2a31265153f07e70e0bab08724e6b85e217f8cd628ceb62974247bb493382";
+
+    #[test]
+    fn test_ethermint_pipeline_matches_evm_bytes() {
+        let key = ::hex::decode(SECP256K1_KEY).unwrap();
+
+        assert_eq!(
+            execute_ethermint_pipeline(&key, &json!({"hrp": "evmos"})).unwrap(),
+            "evmos1nk9x9ajk4rgkzhqjjn7hr6w0k0jg2kj07me7uu"
+        );
+        assert_eq!(
+            execute_ethermint_pipeline(&key, &json!({"hrp": "inj"})).unwrap(),
+            "inj1nk9x9ajk4rgkzhqjjn7hr6w0k0jg2kj0knl55v"
+        );
+        // Uncompressed keys give the same account
+        let uncompressed = crate::shared::crypto::secp256k1::decompress_public_key(&key).unwrap();
+        assert_eq!(
+            execute_ethermint_pipeline(&uncompressed, &json!({"hrp": "evmos"})).unwrap(),
+            "evmos1nk9x9ajk4rgkzhqjjn7hr6w0k0jg2kj07me7uu"
+        );
+    }
+
+    #[test]
+    fn test_ethermint_pipeline_ed25519_valcons() {
+        let key = ::hex::decode("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+            .unwrap();
+        let params = json!({"hrp": "evmos", "roles": {"valcons": {"hrp": "evmosvalcons"}}});
+
+        assert!(execute_ethermint_pipeline(&key, &params)
+            .unwrap()
+            .starts_with("evmosvalcons1"));
+    }
+
+    #[test]
+    fn test_ethermint_pipeline_errors() {
+        let key = ::hex::decode(SECP256K1_KEY).unwrap();
+        assert!(execute_ethermint_pipeline(&key, &json!({})).is_err());
+        assert!(execute_ethermint_pipeline(&[0u8; 20], &json!({"hrp": "evmos"})).is_err());
+    }
+}
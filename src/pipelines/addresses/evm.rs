@@ -6,6 +6,15 @@ use serde_json::Value;
 
 /// Execute EVM address derivation pipeline
 pub fn execute_evm_pipeline(pk_bytes: &[u8], _params: &Value) -> Result<String, Error> {
+    let address_bytes = evm_address_bytes(pk_bytes)?;
+
+    // Encode as hex with 0x prefix
+    // hex::encode already adds "0x" prefix, so use it directly
+    Ok(hex::encode(&address_bytes))
+}
+
+/// The 20 account bytes of a secp256k1 key: last 20 bytes of `keccak256(x || y)`
+pub(crate) fn evm_address_bytes(pk_bytes: &[u8]) -> Result<Vec<u8>, Error> {
     // Extract 64-byte key
     let key_64 = extract_64_bytes(pk_bytes)?;
 
@@ -13,11 +22,7 @@ pub fn execute_evm_pipeline(pk_bytes: &[u8], _params: &Value) -> Result<String,
     let hash = keccak256(&key_64);
 
     // Slice last 20 bytes
-    let address_bytes = &hash[12..32];
-
-    // Encode as hex with 0x prefix
-    // hex::encode already adds "0x" prefix, so use it directly
-    Ok(hex::encode(address_bytes))
+    Ok(hash[12..32].to_vec())
 }
 
 fn extract_64_bytes(public_key: &[u8]) -> Result<Vec<u8>, Error> {
@@ -99,7 +104,7 @@ mod tests {
     fn test_evm_pipeline_invalid_decompressed_format() {
         // Test with a key that has invalid format (wrong prefix for compressed)
         // Use 0x04 prefix which is for uncompressed, but length is 33
-        let mut invalid_key = vec![0x04u8; 33];
+        let invalid_key = vec![0x04u8; 33];
         let params = json!({});
 
         // This should fail at length validation or format check
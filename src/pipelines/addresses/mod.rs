@@ -1,11 +1,13 @@
 pub mod bitcoin_bech32;
 pub mod bitcoin_p2pkh;
 pub mod cardano;
+pub mod cashaddr;
 pub mod cosmos;
 pub mod dispatcher;
+pub mod ethermint;
 pub mod evm;
 pub mod solana;
 pub mod ss58;
 pub mod tron;
 
-pub use dispatcher::execute_pipeline;
+pub use dispatcher::derive_chain_address;
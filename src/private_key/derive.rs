@@ -0,0 +1,255 @@
+//! Addresses controlled by a private key
+
+use crate::descriptor::Descriptor;
+use crate::pipelines::addresses::derive_chain_address;
+use crate::private_key::parser::PrivateKey;
+use crate::registry::{ChainMetadata, EncodingType, Network, PublicKeyType, Registry};
+use crate::shared::checksum::eip55;
+use crate::shared::crypto::{ed25519, secp256k1, sr25519};
+use crate::Error;
+
+/// An address controlled by a private key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ControlledAddress {
+    pub chain: String,
+    pub encoding: EncodingType,
+    pub address: String,
+    /// Hex public key the address was derived from
+    pub public_key: String,
+    /// Signature scheme and script, e.g. "secp256k1 wpkh()"
+    pub scheme: String,
+    /// Whether the key's encoding names the chain (WIF version byte, Solana keypair)
+    pub declared: bool,
+}
+
+impl PrivateKey {
+    /// Derive the addresses the key controls on every compatible chain
+    ///
+    /// secp256k1 keys produce every standard single-key script on
+    /// Bitcoin-family chains and the pipeline address elsewhere. 32-byte hex
+    /// inputs are also tried as Ed25519 seeds and Substrate Sr25519 mini secrets.
+    pub(crate) fn controlled_addresses(&self) -> Result<Vec<ControlledAddress>, Error> {
+        let registry = Registry::get();
+        let mut addresses = Vec::new();
+        // SS58 registry networks only resolve addresses (they declare no key formats)
+        let curve_chains = |curve: &'static str| {
+            registry.chains.iter().filter(move |chain| {
+                registry
+                    .get_chain_config(&chain.id)
+                    .is_some_and(|config| config.curve == curve)
+                    && !chain.public_key_formats.is_empty()
+            })
+        };
+        let ss58_chains = || {
+            registry.chains.iter().filter(|chain| {
+                registry
+                    .get_chain_config(&chain.id)
+                    .is_some_and(|config| config.address_pipeline == "ss58")
+                    && !chain.public_key_formats.is_empty()
+            })
+        };
+        // Substrate chains declare sr25519 and an Ed25519 key format for Ed25519 accounts
+        let ed25519_chains = || {
+            registry.chains.iter().filter(|chain| {
+                registry.get_chain_config(&chain.id).is_some_and(|config| {
+                    config.curve == "ed25519"
+                        || config.address_pipeline == "ss58"
+                            && chain
+                                .public_key_formats
+                                .iter()
+                                .any(|format| format.key_type == PublicKeyType::Ed25519)
+                }) && !chain.public_key_formats.is_empty()
+            })
+        };
+
+        match self {
+            PrivateKey::Wif {
+                secret,
+                compressed,
+                version,
+            } => {
+                let public_key = secp256k1::public_key_from_secret(&secret[..], *compressed)?;
+                for chain in curve_chains("secp256k1") {
+                    let declared = chain.wif_formats.iter().find(|f| f.version == *version);
+                    let network = declared.map_or(Network::Mainnet, |f| f.network);
+                    addresses.extend(secp256k1_addresses(
+                        chain,
+                        &public_key,
+                        network,
+                        declared.is_some(),
+                    )?);
+                }
+            }
+            PrivateKey::Hex { secret, .. } => {
+                // Scalars outside the group order are only seeds
+                if let Ok(public_key) = secp256k1::public_key_from_secret(&secret[..], true) {
+                    for chain in curve_chains("secp256k1") {
+                        addresses.extend(secp256k1_addresses(
+                            chain,
+                            &public_key,
+                            Network::Mainnet,
+                            false,
+                        )?);
+                    }
+                }
+                let public_key = ed25519::public_key_from_seed(secret);
+                addresses
+                    .extend(ed25519_chains().filter_map(|chain| {
+                        pipeline_address(chain, &public_key, "ed25519", false)
+                    }));
+                let public_key = sr25519::public_key_from_mini_secret(secret)?;
+                addresses
+                    .extend(ss58_chains().filter_map(|chain| {
+                        pipeline_address(chain, &public_key, "sr25519", false)
+                    }));
+            }
+            PrivateKey::SolanaKeypair { public_key } => {
+                addresses.extend(ed25519_chains().filter_map(|chain| {
+                    pipeline_address(chain, public_key, "ed25519", chain.id == "solana")
+                }));
+            }
+        }
+        Ok(addresses)
+    }
+}
+
+/// Address of a public key through the chain's address pipeline, if it derives one
+fn pipeline_address(
+    chain: &ChainMetadata,
+    public_key: &[u8],
+    scheme: &str,
+    declared: bool,
+) -> Option<ControlledAddress> {
+    let mut address = derive_chain_address(&chain.id, public_key).ok()?;
+    // EVM addresses are returned EIP-55 checksummed, as everywhere else
+    if Registry::get()
+        .get_chain_config(&chain.id)
+        .is_some_and(|config| config.address_pipeline == "evm")
+    {
+        address = eip55::normalize(&address).ok()?;
+    }
+    Some(ControlledAddress {
+        chain: chain.id.clone(),
+        encoding: chain.address_formats[0].encoding,
+        address,
+        public_key: ::hex::encode(public_key),
+        scheme: scheme.to_string(),
+        declared,
+    })
+}
+
+/// Addresses of a secp256k1 key on a chain
+///
+/// Bitcoin-family chains get every single-key script they have a format for
+/// (segwit and taproot need a compressed key); other chains use their pipeline.
+fn secp256k1_addresses(
+    chain: &ChainMetadata,
+    public_key: &[u8],
+    network: Network,
+    declared: bool,
+) -> Result<Vec<ControlledAddress>, Error> {
+    let mut descriptors = vec![Descriptor::Pkh(public_key.to_vec())];
+    if public_key.len() == 33 {
+        descriptors.extend([
+            Descriptor::ShWpkh(public_key.to_vec()),
+            Descriptor::Wpkh(public_key.to_vec()),
+            Descriptor::Tr(public_key[1..].to_vec()),
+        ]);
+    }
+
+    let mut addresses = Vec::new();
+    for descriptor in descriptors {
+        if let Some((format, address)) = descriptor.address(&chain.id, network)? {
+            addresses.push(ControlledAddress {
+                chain: chain.id.clone(),
+                encoding: format.encoding,
+                address,
+                public_key: ::hex::encode(public_key),
+                scheme: format!("secp256k1 {}", descriptor.shape()),
+                declared,
+            });
+        }
+    }
+    if addresses.is_empty() {
+        addresses.extend(pipeline_address(chain, public_key, "secp256k1", declared));
+    }
+    Ok(addresses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::private_key::parse_private_key;
+
+    fn addresses_of(input: &str) -> Vec<ControlledAddress> {
+        parse_private_key(input)
+            .unwrap()
+            .controlled_addresses()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_wif_addresses() {
+        let addresses = addresses_of("KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617");
+        let bitcoin: Vec<&str> = addresses
+            .iter()
+            .filter(|a| a.chain == "bitcoin")
+            .map(|a| a.address.as_str())
+            .collect();
+        assert!(bitcoin.contains(&"1LoVGDgRs9hTfTNJNuXKSpywcbdvwRXpmK"));
+        assert!(bitcoin.contains(&"3D9iyFHi1Zs9KoyynUfrL82rGhJfYTfSG4"));
+        assert!(bitcoin.contains(&"bc1qmy63mjadtw8nhzl69ukdepwzsyvv4yex5qlmkd"));
+        assert!(bitcoin.iter().any(|a| a.starts_with("bc1p")));
+        assert!(addresses
+            .iter()
+            .filter(|a| a.chain == "bitcoin")
+            .all(|a| a.declared));
+
+        // The same key controls accounts on every secp256k1 chain
+        let litecoin = addresses.iter().find(|a| a.chain == "litecoin").unwrap();
+        assert_eq!(litecoin.address, "Lf2SXRzFwowWvG4TZ3Wcir3hpp1D6zsqGn");
+        assert!(!litecoin.declared);
+        let ethereum = addresses.iter().find(|a| a.chain == "ethereum").unwrap();
+        assert_eq!(
+            ethereum.address,
+            eip55::normalize(&ethereum.address.to_lowercase()).unwrap()
+        );
+        assert_ne!(ethereum.address, ethereum.address.to_lowercase());
+        assert!(addresses.iter().any(|a| a.chain == "tron"));
+    }
+
+    #[test]
+    fn test_wif_uncompressed_addresses() {
+        let addresses = addresses_of("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ");
+        let bitcoin: Vec<&ControlledAddress> =
+            addresses.iter().filter(|a| a.chain == "bitcoin").collect();
+        // Uncompressed keys only have legacy P2PKH addresses
+        assert_eq!(bitcoin.len(), 1);
+        assert_eq!(bitcoin[0].address, "1GAehh7TsJAHuUAeKZcXf5CnwuGuGgyX2S");
+    }
+
+    #[test]
+    fn test_solana_keypair_addresses() {
+        let addresses = addresses_of("49W385L4rePHy6PAaQUovbD2aacgN4HsKXSMeUzRg4fmwXszN91JuMFrQRj3vMDpZuRF3ZknQBuRBoWQJEfXstMw");
+        let solana = addresses.iter().find(|a| a.chain == "solana").unwrap();
+        assert_eq!(
+            solana.address,
+            "FVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96Z"
+        );
+        assert!(solana.declared);
+        assert!(addresses.iter().all(|a| a.scheme == "ed25519"));
+    }
+
+    #[test]
+    fn test_substrate_seed_addresses() {
+        // Substrate development account //Alice
+        let addresses =
+            addresses_of("0xe5be9a5092b81bca64be81d212e7f2f9eba183bb7a90954f7b76361f6edb5c0a");
+        assert!(addresses.iter().any(|a| a.scheme == "sr25519"
+            && a.public_key == "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"));
+        assert!(addresses
+            .iter()
+            .any(|a| a.chain == "ethereum" && a.scheme == "secp256k1"));
+        assert!(addresses.iter().any(|a| a.chain == "solana"));
+    }
+}
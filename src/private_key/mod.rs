@@ -0,0 +1,10 @@
+//! Private keys
+//!
+//! Recognition of WIF keys, 32-byte hex scalars and seeds, and Solana
+//! keypairs, and derivation of the addresses they control on every
+//! compatible chain. Decoded key bytes are zeroized when dropped.
+
+mod derive;
+mod parser;
+
+pub(crate) use parser::{parse_private_key, PrivateKey};
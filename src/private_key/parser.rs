@@ -0,0 +1,206 @@
+//! Private key parsing (WIF, hex, Solana keypairs)
+
+use crate::registry::Registry;
+use crate::shared::crypto::ed25519;
+use crate::shared::crypto::hash::double_sha256;
+use crate::shared::encoding::base58;
+use zeroize::Zeroizing;
+
+/// Length of a Solana keypair: 32-byte seed followed by its public key
+const SOLANA_KEYPAIR_LENGTH: usize = 64;
+
+/// A decoded private key
+///
+/// Secret bytes are held in [`Zeroizing`] buffers and wiped when dropped.
+pub(crate) enum PrivateKey {
+    /// WIF-encoded secp256k1 key (Bitcoin-family chains)
+    Wif {
+        secret: Zeroizing<[u8; 32]>,
+        /// Whether the key is used with compressed public keys
+        compressed: bool,
+        /// Version byte, declared per chain and network in the metadata
+        version: u8,
+    },
+    /// 32 bytes of hex: a secp256k1 scalar, an Ed25519 seed or a Substrate
+    /// (Sr25519) mini secret, with or without `0x`
+    Hex {
+        secret: Zeroizing<[u8; 32]>,
+        prefixed: bool,
+    },
+    /// Base58 Solana keypair whose public half matches its seed; the seed is
+    /// only needed for that check and is not kept
+    SolanaKeypair { public_key: [u8; 32] },
+}
+
+impl PrivateKey {
+    /// Description used in reasoning strings; never includes key material
+    pub(crate) fn description(&self) -> String {
+        match self {
+            PrivateKey::Wif {
+                compressed,
+                version,
+                ..
+            } => format!(
+                "WIF private key ({}, version 0x{:02x})",
+                if *compressed {
+                    "compressed"
+                } else {
+                    "uncompressed"
+                },
+                version
+            ),
+            PrivateKey::Hex { prefixed: true, .. } => {
+                "0x-prefixed 32-byte hex private key or seed".to_string()
+            }
+            PrivateKey::Hex {
+                prefixed: false, ..
+            } => "32-byte hex private key".to_string(),
+            PrivateKey::SolanaKeypair { .. } => "Solana keypair (Base58)".to_string(),
+        }
+    }
+
+    /// Whether the encoding identifies the key unambiguously
+    ///
+    /// 32 bytes of hex are also public keys and hashes, so they are reported
+    /// next to the other interpretations of the input.
+    pub(crate) fn is_ambiguous(&self) -> bool {
+        matches!(self, PrivateKey::Hex { .. })
+    }
+}
+
+/// Parse an input as a private key
+pub(crate) fn parse_private_key(input: &str) -> Option<PrivateKey> {
+    let input = input.trim();
+    parse_wif(input)
+        .or_else(|| parse_solana_keypair(input))
+        .or_else(|| parse_hex(input))
+}
+
+/// Parse a WIF key: version byte, 32-byte secret, optional 0x01 compression flag, checksum
+fn parse_wif(input: &str) -> Option<PrivateKey> {
+    if !matches!(input.len(), 51 | 52) {
+        return None;
+    }
+    let decoded = Zeroizing::new(base58::decode(input).ok()?);
+    let compressed = match decoded.len() {
+        37 => false,
+        38 if decoded[33] == 0x01 => true,
+        _ => return None,
+    };
+    let (data, checksum) = decoded.split_at(decoded.len() - 4);
+    if double_sha256(data)[..4] != *checksum {
+        return None;
+    }
+
+    let version = data[0];
+    let declared = Registry::get()
+        .chains
+        .iter()
+        .any(|chain| chain.wif_formats.iter().any(|f| f.version == version));
+    if !declared {
+        return None;
+    }
+    let mut secret = Zeroizing::new([0u8; 32]);
+    secret.copy_from_slice(&data[1..33]);
+    Some(PrivateKey::Wif {
+        secret,
+        compressed,
+        version,
+    })
+}
+
+/// Parse a Base58 Solana keypair, checking that its public half belongs to its seed
+fn parse_solana_keypair(input: &str) -> Option<PrivateKey> {
+    if !matches!(input.len(), 86..=88) {
+        return None;
+    }
+    let decoded = Zeroizing::new(base58::decode(input).ok()?);
+    if decoded.len() != SOLANA_KEYPAIR_LENGTH {
+        return None;
+    }
+    let mut secret = Zeroizing::new([0u8; 32]);
+    secret.copy_from_slice(&decoded[..32]);
+    let public_key = ed25519::public_key_from_seed(&secret);
+    (public_key[..] == decoded[32..]).then_some(PrivateKey::SolanaKeypair { public_key })
+}
+
+/// Parse 32 bytes of hex, with or without `0x`
+fn parse_hex(input: &str) -> Option<PrivateKey> {
+    let (digits, prefixed) = match input.strip_prefix("0x") {
+        Some(digits) => (digits, true),
+        None => (input, false),
+    };
+    let mut secret = Zeroizing::new([0u8; 32]);
+    ::hex::decode_to_slice(digits, &mut *secret).ok()?;
+    Some(PrivateKey::Hex { secret, prefixed })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Bitcoin wiki WIF example, uncompressed and compressed
+    const WIF_UNCOMPRESSED: &str = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ";
+    const WIF_COMPRESSED: &str = "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617";
+    const SECRET: &str = "0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d";
+
+    #[test]
+    fn test_parse_wif() {
+        let Some(PrivateKey::Wif {
+            secret,
+            compressed,
+            version,
+        }) = parse_private_key(WIF_UNCOMPRESSED)
+        else {
+            panic!("expected a WIF key");
+        };
+        assert_eq!(::hex::encode(*secret), SECRET);
+        assert!(!compressed);
+        assert_eq!(version, 0x80);
+
+        let key = parse_private_key(WIF_COMPRESSED).unwrap();
+        assert!(matches!(
+            key,
+            PrivateKey::Wif {
+                compressed: true,
+                ..
+            }
+        ));
+        assert!(!key.is_ambiguous());
+        assert!(!key.description().contains(SECRET));
+
+        // Corrupted checksum, and an address of WIF-like length is not a key
+        assert!(parse_wif(&WIF_COMPRESSED.replace('7', "8")).is_none());
+        assert!(parse_wif("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").is_none());
+    }
+
+    #[test]
+    fn test_parse_solana_keypair() {
+        // RFC 8032 test 1 seed followed by its public key
+        let keypair = "49W385L4rePHy6PAaQUovbD2aacgN4HsKXSMeUzRg4fmwXszN91JuMFrQRj3vMDpZuRF3ZknQBuRBoWQJEfXstMw";
+        let Some(PrivateKey::SolanaKeypair { public_key }) = parse_private_key(keypair) else {
+            panic!("expected a Solana keypair");
+        };
+        assert_eq!(
+            ::hex::encode(public_key),
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        );
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        let key = parse_private_key(SECRET).unwrap();
+        assert!(matches!(
+            key,
+            PrivateKey::Hex {
+                prefixed: false,
+                ..
+            }
+        ));
+        assert!(key.is_ambiguous());
+        let key = parse_private_key(&format!("0x{}", SECRET)).unwrap();
+        assert!(matches!(key, PrivateKey::Hex { prefixed: true, .. }));
+        assert!(parse_private_key(&SECRET[2..]).is_none());
+        assert!(parse_private_key("not a key").is_none());
+    }
+}
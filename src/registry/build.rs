@@ -3,15 +3,23 @@
 //! This module builds the registry that precomputes category groups at startup,
 //! automatically organizing chains by their format signatures.
 
-use crate::loaders::{load_chain, load_index};
+use crate::loaders::{load_chain, load_index, ss58_registry_chains};
+use crate::models::chain::ChainConfig;
 use crate::registry::chain_converter::convert_chain_config;
 use crate::registry::ChainMetadata;
+use crate::Error;
 use std::collections::HashMap;
-use std::sync::OnceLock;
+use std::sync::{Mutex, OnceLock};
 
 /// Global registry instance
 static REGISTRY: OnceLock<Registry> = OnceLock::new();
 
+/// Chain configs loaded at runtime, added when the global registry is built
+///
+/// `None` once the registry is built. The lock is held for the whole build,
+/// so chains are either queued before it starts or rejected.
+static EXTRA_CHAINS: Mutex<Option<Vec<ChainConfig>>> = Mutex::new(Some(Vec::new()));
+
 /// Registry containing all chain metadata and precomputed groups
 pub struct Registry {
     /// All chain metadata
@@ -22,7 +30,10 @@ pub struct Registry {
 
 impl Registry {
     /// Build the registry with all chain metadata and automatic grouping
-    pub fn build() -> Self {
+    ///
+    /// `extra` chains (from `load_chain_directory`) are added after the
+    /// bundled ones, then the SS58 registry networks no chain declares.
+    pub fn build(extra: Vec<ChainConfig>) -> Self {
         // Load index to get all chain IDs
         let index = load_index().expect("Failed to load metadata index");
 
@@ -56,15 +67,92 @@ impl Registry {
 
         let chain_configs: HashMap<String, _> = chain_configs_vec.into_iter().collect();
 
-        Registry {
+        let mut registry = Registry {
             chains,
             chain_configs,
+        };
+        for config in extra {
+            if let Err(e) = registry.add_chain(config) {
+                eprintln!("Warning: {}", e);
+            }
+        }
+        // SS58 registry networks whose prefix no declared chain uses
+        for config in ss58_registry_chains() {
+            let declared = registry.chain_configs.contains_key(&config.id)
+                || config.address_params["prefix"]
+                    .as_u64()
+                    .and_then(|prefix| u16::try_from(prefix).ok())
+                    .is_some_and(|prefix| registry.ss58_chain(prefix).is_some());
+            if declared {
+                continue;
+            }
+            if let Err(e) = registry.add_chain(config) {
+                eprintln!("Warning: {}", e);
+            }
         }
+        registry
     }
 
     /// Get the global registry instance
     pub fn get() -> &'static Registry {
-        REGISTRY.get_or_init(Registry::build)
+        REGISTRY.get_or_init(|| {
+            let mut extra = EXTRA_CHAINS.lock().unwrap_or_else(|e| e.into_inner());
+            Registry::build(extra.take().unwrap_or_default())
+        })
+    }
+
+    /// Queue chain configs for the global registry, which must not be built yet
+    pub(crate) fn queue_chains(configs: Vec<ChainConfig>) -> Result<(), Error> {
+        EXTRA_CHAINS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_mut()
+            .ok_or_else(|| {
+                Error::InvalidInput(
+                    "Chains must be loaded before the registry is first used".to_string(),
+                )
+            })?
+            .extend(configs);
+        Ok(())
+    }
+
+    /// The chain whose SS58 address format declares `prefix`
+    pub fn ss58_chain(&self, prefix: u16) -> Option<&ChainMetadata> {
+        self.chains.iter().find(|chain| {
+            chain
+                .address_formats
+                .iter()
+                .any(|format| format.ss58_prefix == Some(prefix))
+        })
+    }
+
+    /// Add a chain unless its id or one of its HRPs is already registered
+    fn add_chain(&mut self, config: ChainConfig) -> Result<(), Error> {
+        let id = config.id.clone();
+        if self.chain_configs.contains_key(&id) {
+            return Err(Error::InvalidInput(format!(
+                "Chain {} is already registered",
+                id
+            )));
+        }
+        let chain = convert_chain_config(config.clone())
+            .map_err(|e| Error::InvalidInput(format!("Failed to convert chain {}: {}", id, e)))?;
+        let hrps = chain.address_formats.iter().flat_map(|f| f.hrps.iter());
+        for hrp in hrps {
+            if let Some(existing) = self
+                .chains
+                .iter()
+                .find(|c| c.address_formats.iter().any(|f| f.hrps.contains(hrp)))
+            {
+                return Err(Error::InvalidInput(format!(
+                    "Chain {}: HRP {} is already used by {}",
+                    id, hrp, existing.id
+                )));
+            }
+        }
+        self.chains.push(chain);
+        self.chain_configs.insert(id, config);
+        Ok(())
     }
 
     /// Find all chains that support a given address format
@@ -137,7 +225,7 @@ fn matches_address_format(
     // Check HRP
     if !metadata.hrps.is_empty() {
         if let Some(ref hrp) = chars.hrp {
-            if !metadata.hrps.iter().any(|h| hrp.starts_with(h)) {
+            if !metadata.hrps.iter().any(|h| hrp == h) {
                 return false;
             }
         } else {
@@ -166,16 +254,63 @@ mod tests {
 
     #[test]
     fn test_registry_build() {
-        let registry = Registry::build();
+        let registry = Registry::build(Vec::new());
         assert!(!registry.chains.is_empty());
     }
 
+    #[test]
+    fn test_registry_add_chain() {
+        let mut registry = Registry::build(Vec::new());
+        let mut config: ChainConfig = serde_json::from_str(
+            &crate::loaders::convert_cosmos_chain(
+                r#"{"chain_name": "dydx", "pretty_name": "dYdX", "bech32_prefix": "dydx", "slip44": 118}"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        registry.add_chain(config.clone()).unwrap();
+        assert!(registry.chains.iter().any(|c| c.id == "dydx"));
+        assert!(registry.get_chain_config("dydx").is_some());
+
+        // Duplicate id
+        assert!(registry.add_chain(config.clone()).is_err());
+        // HRP of an existing chain (the chain registry names Cosmos Hub "cosmoshub")
+        config.id = "cosmoshub".to_string();
+        config.address_params = serde_json::json!({ "hrp": "cosmos" });
+        let err = registry.add_chain(config).unwrap_err();
+        assert!(err.to_string().contains("cosmos_hub"));
+    }
+
     #[test]
     fn test_registry_get() {
         let registry = Registry::get();
         assert!(!registry.chains.is_empty());
     }
 
+    #[test]
+    fn test_registry_ss58_chains() {
+        let registry = Registry::get();
+        let chain_id = |prefix| registry.ss58_chain(prefix).map(|chain| chain.id.as_str());
+
+        // Declared chains keep their prefix, the SS58 registry fills in the rest
+        assert_eq!(chain_id(0), Some("polkadot"));
+        assert_eq!(chain_id(2), Some("kusama"));
+        assert_eq!(chain_id(10), Some("acala"));
+        assert_eq!(chain_id(13116), Some("bittensor"));
+        assert_eq!(registry.get_chain_config("acala").unwrap().name, "Acala");
+        // The generic Substrate chain is the fallback, not the owner of prefix 42
+        assert_eq!(chain_id(42), None);
+        assert_eq!(chain_id(9999), None);
+        assert_eq!(
+            registry
+                .chains
+                .iter()
+                .filter(|chain| chain.id == "polkadot" || chain.id == "substrate")
+                .count(),
+            2
+        );
+    }
+
     #[test]
     fn test_get_chain_config() {
         let registry = Registry::get();
@@ -220,6 +355,9 @@ mod tests {
             version_bytes: vec![],
             checksum: None,
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
@@ -246,6 +384,9 @@ mod tests {
             version_bytes: vec![0x00], // Bitcoin version
             checksum: Some(crate::registry::ChecksumType::Base58Check),
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"; // 34 chars, valid Bitcoin
@@ -272,6 +413,9 @@ mod tests {
             version_bytes: vec![],
             checksum: None,
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
@@ -279,7 +423,7 @@ mod tests {
         assert!(matches_address_format(&chars, &metadata));
 
         let input_no_prefix = "742d35Cc6634C0532925a3b844Bc454e4438f44e";
-        let chars_no_prefix = extract_characteristics(input_no_prefix);
+        let _chars_no_prefix = extract_characteristics(input_no_prefix);
         // Should still match if encoding is detected
         // The prefix check might pass if encoding is detected
     }
@@ -299,6 +443,9 @@ mod tests {
             version_bytes: vec![],
             checksum: None,
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         // Test with a valid Cosmos address that should match
@@ -336,6 +483,9 @@ mod tests {
             version_bytes: vec![],
             checksum: None,
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
@@ -358,6 +508,9 @@ mod tests {
             version_bytes: vec![],
             checksum: None,
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
@@ -365,7 +518,7 @@ mod tests {
         assert!(matches_address_format(&chars, &metadata));
 
         let input_base58 = "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa";
-        let chars_base58 = extract_characteristics(input_base58);
+        let _chars_base58 = extract_characteristics(input_base58);
         // Should not match if encoding doesn't match
         // But if Base58Check is detected, it might still pass other checks
     }
@@ -1,8 +1,10 @@
-use crate::models::chain::ChainConfig;
+use crate::models::chain::{ChainConfig, HashFormat};
 use crate::registry::{
-    AddressMetadata, ChainMetadata, CharSet, EncodingType, Network, PublicKeyMetadata,
-    PublicKeyType,
+    AddressMetadata, AddressRole, ChainMetadata, CharSet, ChecksumType, EncodingType,
+    ExtendedKeyMetadata, HashKind, HashMetadata, KeyScript, Network, OutputType, PublicKeyMetadata,
+    PublicKeyType, WifMetadata,
 };
+use serde_json::Value;
 
 /// Convert encoding string to EncodingType
 fn encoding_str_to_enum(s: &str) -> EncodingType {
@@ -13,6 +15,7 @@ fn encoding_str_to_enum(s: &str) -> EncodingType {
         "bech32" => EncodingType::Bech32,
         "bech32m" => EncodingType::Bech32m,
         "ss58" => EncodingType::SS58,
+        "cashaddr" => EncodingType::CashAddr,
         _ => EncodingType::Hex, // Default
     }
 }
@@ -27,119 +30,360 @@ fn curve_str_to_key_type(s: &str) -> PublicKeyType {
     }
 }
 
-/// Convert JSON ChainConfig to Rust ChainMetadata
-pub fn convert_chain_config(config: ChainConfig) -> Result<ChainMetadata, String> {
-    // Create address metadata based on pipeline type with proper characteristics
-    let address_formats = match config.address_pipeline.as_str() {
-        "evm" => vec![AddressMetadata {
-            encoding: EncodingType::Hex,
-            char_set: Some(CharSet::Hex),
-            exact_length: Some(42), // 0x + 40 hex chars
-            length_range: None,
-            prefixes: vec!["0x".to_string()],
-            hrps: vec![],
+/// Parse a network name from chain metadata
+fn network_str_to_enum(s: &str) -> Option<Network> {
+    match s {
+        "mainnet" => Some(Network::Mainnet),
+        "testnet" => Some(Network::Testnet),
+        _ => None,
+    }
+}
+
+/// SS58 prefix of a chain, unless it is the generic fallback (`"fallback": true`)
+fn ss58_prefix(params: &Value) -> Option<u16> {
+    if params.get("fallback").and_then(|v| v.as_bool()) == Some(true) {
+        return None;
+    }
+    params
+        .get("prefix")
+        .and_then(|v| v.as_u64())
+        .and_then(|v| u16::try_from(v).ok())
+}
+
+/// Read a list of version bytes from a JSON array
+fn version_bytes(value: Option<&Value>) -> Vec<u8> {
+    value
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_u64())
+                .map(|v| v as u8)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Base58Check format for a set of version bytes
+///
+/// The string length varies with leading zero bytes (25-35 chars); the
+/// decoded payload is always 25 bytes (1 version + 20 hash + 4 checksum),
+/// which `base58check::validate` enforces.
+fn base58check_format(
+    version_bytes: Vec<u8>,
+    network: Network,
+    output_type: OutputType,
+) -> AddressMetadata {
+    AddressMetadata {
+        encoding: EncodingType::Base58Check,
+        char_set: Some(CharSet::Base58),
+        exact_length: None,
+        length_range: Some((25, 35)),
+        prefixes: vec![], // Empty - version byte validation is sufficient
+        hrps: vec![],
+        version_bytes,
+        checksum: Some(ChecksumType::Base58Check),
+        network: Some(network),
+        output_type: Some(output_type),
+        role: None,
+        ss58_prefix: None,
+    }
+}
+
+/// Segwit formats for an HRP: version 0 (Bech32) and version 1+ (Bech32m)
+///
+/// Witness version and program length are checked on the decoded payload.
+fn segwit_formats(hrp: &str, network: Network) -> Vec<AddressMetadata> {
+    [
+        (
+            EncodingType::Bech32,
+            ChecksumType::Bech32,
+            OutputType::WitnessV0,
+        ),
+        (
+            EncodingType::Bech32m,
+            ChecksumType::Bech32m,
+            OutputType::WitnessV1Plus,
+        ),
+    ]
+    .into_iter()
+    .map(|(encoding, checksum, output_type)| AddressMetadata {
+        encoding,
+        char_set: Some(CharSet::Base32),
+        exact_length: None,
+        length_range: Some((14, 90)),
+        prefixes: vec![],
+        hrps: vec![hrp.to_string()], // bech32::decode returns "bc", not "bc1"
+        version_bytes: vec![],
+        checksum: Some(checksum),
+        network: Some(network),
+        output_type: Some(output_type),
+        role: None,
+        ss58_prefix: None,
+    })
+    .collect()
+}
+
+/// CashAddr formats for a prefix: P2PKH and P2SH
+///
+/// The prefix is stored in `hrps`; it may be omitted from the input, in which
+/// case it is recovered from the checksum.
+fn cashaddr_formats(prefix: &str, network: Network) -> Vec<AddressMetadata> {
+    [OutputType::P2PKH, OutputType::P2SH]
+        .into_iter()
+        .map(|output_type| AddressMetadata {
+            encoding: EncodingType::CashAddr,
+            char_set: Some(CharSet::Base32),
+            exact_length: None,
+            length_range: Some((42, 130)),
+            prefixes: vec![],
+            hrps: vec![prefix.to_string()],
             version_bytes: vec![],
-            checksum: Some(crate::registry::ChecksumType::EIP55),
-            network: Some(Network::Mainnet),
-        }],
-        "bitcoin_p2pkh" => {
-            // Extract version byte from address_params
-            let version_byte = config
-                .address_params
+            checksum: Some(ChecksumType::CashAddr),
+            network: Some(network),
+            output_type: Some(output_type),
+            role: None,
+            ss58_prefix: None,
+        })
+        .collect()
+}
+
+/// Build all address formats of a Bitcoin-derived chain
+///
+/// Reads `address_params.networks`, keyed by network name, each entry listing
+/// its `p2pkh` and `p2sh` version bytes, an optional segwit `hrp` and an
+/// optional `cashaddr_prefix`. Mainnet formats come first, CashAddr before
+/// Base58Check and P2PKH first, so derived public key addresses report the
+/// primary encoding. Chains without `networks` fall back to a
+/// single mainnet P2PKH format built from `version_byte`.
+fn bitcoin_family_formats(params: &Value) -> Vec<AddressMetadata> {
+    let networks = match params.get("networks").and_then(|n| n.as_object()) {
+        Some(networks) => networks,
+        None => {
+            let version_byte = params
                 .get("version_byte")
                 .and_then(|v| v.as_u64())
                 .map(|v| v as u8)
                 .unwrap_or(0);
-            // Bitcoin supports both P2PKH (version 0) and P2SH (version 5)
-            // Note: Prefix is determined by version byte, so we don't require prefix match
-            // For Bitcoin (version 0) it's "1", for Dogecoin (version 30) it's "D", for Litecoin (version 48) it's "L"
-            let mut formats = vec![AddressMetadata {
-                encoding: EncodingType::Base58Check,
-                char_set: Some(CharSet::Base58),
-                exact_length: Some(34), // Standard P2PKH address length
-                length_range: None,
-                prefixes: vec![], // Empty - version byte validation is sufficient
-                hrps: vec![],
-                version_bytes: vec![version_byte], // P2PKH version (0 for Bitcoin, 30 for Dogecoin, 48 for Litecoin)
-                checksum: Some(crate::registry::ChecksumType::Base58Check),
-                network: Some(Network::Mainnet),
-            }];
-            // Add P2SH format (version 5 for Bitcoin mainnet)
-            // Only add P2SH for Bitcoin (version_byte == 0), not for Dogecoin/Litecoin
-            if version_byte == 0 {
-                formats.push(AddressMetadata {
-                    encoding: EncodingType::Base58Check,
-                    char_set: Some(CharSet::Base58),
-                    exact_length: Some(34), // Standard P2SH address length
-                    length_range: None,
-                    prefixes: vec![], // Empty - version byte validation is sufficient
-                    hrps: vec![],
-                    version_bytes: vec![5], // P2SH version (5 for Bitcoin mainnet)
-                    checksum: Some(crate::registry::ChecksumType::Base58Check),
-                    network: Some(Network::Mainnet),
-                });
+            return vec![base58check_format(
+                vec![version_byte],
+                Network::Mainnet,
+                OutputType::P2PKH,
+            )];
+        }
+    };
+
+    let mut ordered: Vec<(Network, &Value)> = networks
+        .iter()
+        .filter_map(|(name, entry)| network_str_to_enum(name).map(|network| (network, entry)))
+        .collect();
+    ordered.sort_by_key(|(network, _)| *network != Network::Mainnet);
+
+    ordered
+        .into_iter()
+        .flat_map(|(network, entry)| {
+            let p2pkh = version_bytes(entry.get("p2pkh"));
+            let p2sh = version_bytes(entry.get("p2sh"));
+            let hrp = entry.get("hrp").and_then(|h| h.as_str());
+            let cashaddr_prefix = entry.get("cashaddr_prefix").and_then(|p| p.as_str());
+
+            let mut formats = Vec::new();
+            if let Some(prefix) = cashaddr_prefix {
+                formats.extend(cashaddr_formats(prefix, network));
+            }
+            if !p2pkh.is_empty() {
+                formats.push(base58check_format(p2pkh, network, OutputType::P2PKH));
+            }
+            if !p2sh.is_empty() {
+                formats.push(base58check_format(p2sh, network, OutputType::P2SH));
+            }
+            if let Some(hrp) = hrp {
+                formats.extend(segwit_formats(hrp, network));
             }
-            // Add Bech32 format for Bitcoin
-            formats.push(AddressMetadata {
-                encoding: EncodingType::Bech32,
-                char_set: Some(CharSet::Base32),
-                exact_length: None,
-                length_range: Some((14, 74)), // Bech32 addresses can vary
-                prefixes: vec![],
-                hrps: vec!["bc".to_string()], // Mainnet HRP (bech32::decode returns "bc", not "bc1")
-                version_bytes: vec![],
-                checksum: Some(crate::registry::ChecksumType::Bech32),
-                network: Some(Network::Mainnet),
-            });
             formats
-        }
-        "bitcoin_bech32" => vec![AddressMetadata {
-            encoding: EncodingType::Bech32,
-            char_set: Some(CharSet::Base32),
+        })
+        .collect()
+}
+
+/// Cardano formats, per network of `address_params.networks`
+///
+/// Each network's HRPs (`addr`, `stake` on mainnet) take Shelley addresses
+/// whose CIP-19 header carries that network's id. A network with
+/// `"byron": true` also takes Base58 Byron addresses: without a protocol
+/// magic on mainnet, with one on testnets.
+fn cardano_formats(params: &Value) -> Vec<AddressMetadata> {
+    let Some(networks) = params.get("networks").and_then(|n| n.as_object()) else {
+        return vec![];
+    };
+    let mut ordered: Vec<(Network, &Value)> = networks
+        .iter()
+        .filter_map(|(name, entry)| network_str_to_enum(name).map(|network| (network, entry)))
+        .collect();
+    ordered.sort_by_key(|(network, _)| *network != Network::Mainnet);
+
+    let shelley = ordered.iter().map(|&(network, entry)| AddressMetadata {
+        encoding: EncodingType::Bech32,
+        char_set: Some(CharSet::Base32),
+        exact_length: None,
+        length_range: Some((50, 130)), // Reward (59) to base and pointer addresses
+        prefixes: vec![],
+        hrps: entry
+            .get("hrps")
+            .and_then(|h| h.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        version_bytes: vec![],
+        checksum: Some(ChecksumType::Cardano),
+        network: Some(network),
+        output_type: None,
+        role: None,
+        ss58_prefix: None,
+    });
+    let byron = ordered
+        .iter()
+        .filter(|(_, entry)| entry.get("byron").and_then(|b| b.as_bool()) == Some(true))
+        .map(|&(network, _)| AddressMetadata {
+            encoding: EncodingType::Base58,
+            char_set: Some(CharSet::Base58),
             exact_length: None,
-            length_range: Some((14, 74)), // Bech32 addresses can vary
+            length_range: Some((50, 130)), // Icarus (59) to Daedalus addresses with a magic
             prefixes: vec![],
-            hrps: vec!["bc".to_string()], // Mainnet HRP (bech32::decode returns "bc", not "bc1")
+            hrps: vec![],
             version_bytes: vec![],
-            checksum: Some(crate::registry::ChecksumType::Bech32),
-            network: Some(Network::Mainnet),
-        }],
-        "cosmos" => {
-            // Extract HRP from address_params
-            let hrps: Vec<String> = config
-                .address_params
-                .get("hrp")
-                .and_then(|h| h.as_str())
-                .map(|h| vec![h.to_string()])
-                .unwrap_or_else(|| {
-                    // Try to get from array
-                    config
-                        .address_params
-                        .get("hrps")
-                        .and_then(|h| h.as_array())
-                        .map(|arr| {
-                            arr.iter()
-                                .filter_map(|v| v.as_str().map(|s| s.to_string()))
-                                .collect()
-                        })
-                        .unwrap_or_default()
-                });
-            vec![AddressMetadata {
-                encoding: EncodingType::Bech32,
-                char_set: Some(CharSet::Base32),
-                exact_length: None,
-                length_range: Some((20, 90)), // Cosmos addresses vary
-                prefixes: vec![],
-                hrps,
-                version_bytes: vec![],
-                checksum: Some(crate::registry::ChecksumType::Bech32),
-                network: Some(Network::Mainnet),
-            }]
-        }
-        "cardano" => {
-            // Extract HRPs from address_params
-            let hrps: Vec<String> = config
-                .address_params
+            checksum: Some(ChecksumType::CardanoByron),
+            network: Some(network),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
+        });
+    shelley.chain(byron).collect()
+}
+
+/// Parse a key script name from chain metadata
+fn key_script_str_to_enum(s: &str) -> Option<KeyScript> {
+    match s {
+        "p2pkh" => Some(KeyScript::P2PKH),
+        "p2sh-p2wpkh" => Some(KeyScript::NestedP2WPKH),
+        "p2wpkh" => Some(KeyScript::P2WPKH),
+        "p2sh-p2wsh" => Some(KeyScript::NestedP2WSH),
+        "p2wsh" => Some(KeyScript::P2WSH),
+        _ => None,
+    }
+}
+
+/// Extended public key versions declared under `networks.<network>.extended_keys`
+fn extended_key_formats(params: &Value) -> Vec<ExtendedKeyMetadata> {
+    let Some(networks) = params.get("networks").and_then(|n| n.as_object()) else {
+        return Vec::new();
+    };
+
+    networks
+        .iter()
+        .filter_map(|(name, entry)| network_str_to_enum(name).map(|network| (network, entry)))
+        .flat_map(|(network, entry)| {
+            entry
+                .get("extended_keys")
+                .and_then(|k| k.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(move |key| {
+                    let version = hex::decode(key.get("version")?.as_str()?).ok()?;
+                    Some(ExtendedKeyMetadata {
+                        prefix: key.get("prefix")?.as_str()?.to_string(),
+                        version: version.try_into().ok()?,
+                        network,
+                        script: key_script_str_to_enum(key.get("script")?.as_str()?)?,
+                    })
+                })
+        })
+        .collect()
+}
+
+/// WIF private key versions declared under `networks.<network>.wif`
+fn wif_formats(params: &Value) -> Vec<WifMetadata> {
+    let Some(networks) = params.get("networks").and_then(|n| n.as_object()) else {
+        return Vec::new();
+    };
+
+    networks
+        .iter()
+        .filter_map(|(name, entry)| network_str_to_enum(name).map(|network| (network, entry)))
+        .flat_map(|(network, entry)| {
+            version_bytes(entry.get("wif"))
+                .into_iter()
+                .map(move |version| WifMetadata { version, network })
+        })
+        .collect()
+}
+
+/// Roles declared under `roles`: role, address HRP and amino public key HRP
+///
+/// ```json
+/// "roles": { "valoper": { "hrp": "cosmosvaloper", "pub_hrp": "cosmosvaloperpub" } }
+/// ```
+fn cosmos_roles(params: &Value) -> Vec<(AddressRole, String, Option<String>)> {
+    let Some(roles) = params.get("roles") else {
+        return Vec::new();
+    };
+    [
+        AddressRole::Account,
+        AddressRole::ValidatorOperator,
+        AddressRole::ValidatorConsensus,
+    ]
+    .into_iter()
+    .filter_map(|role| {
+        let entry = roles.get(role.name())?;
+        let hrp = entry.get("hrp")?.as_str()?.to_string();
+        let pub_hrp = entry
+            .get("pub_hrp")
+            .and_then(|h| h.as_str())
+            .map(|h| h.to_string());
+        Some((role, hrp, pub_hrp))
+    })
+    .collect()
+}
+
+/// Cosmos Bech32 address format for a set of HRPs
+fn cosmos_format(hrps: Vec<String>, role: AddressRole) -> AddressMetadata {
+    AddressMetadata {
+        encoding: EncodingType::Bech32,
+        char_set: Some(CharSet::Base32),
+        exact_length: None,
+        length_range: Some((20, 90)), // Cosmos addresses vary
+        prefixes: vec![],
+        hrps,
+        version_bytes: vec![],
+        checksum: Some(ChecksumType::Bech32),
+        network: Some(Network::Mainnet),
+        output_type: None,
+        role: Some(role),
+        ss58_prefix: None,
+    }
+}
+
+/// Cosmos address formats, one per declared role (account first)
+///
+/// Chains without roles get a single account format from `hrp` or `hrps`.
+fn cosmos_formats(params: &Value) -> Vec<AddressMetadata> {
+    let roles = cosmos_roles(params);
+    if !roles.is_empty() {
+        return roles
+            .into_iter()
+            .map(|(role, hrp, _)| cosmos_format(vec![hrp], role))
+            .collect();
+    }
+
+    // Extract HRP from address_params
+    let hrps: Vec<String> = params
+        .get("hrp")
+        .and_then(|h| h.as_str())
+        .map(|h| vec![h.to_string()])
+        .unwrap_or_else(|| {
+            // Try to get from array
+            params
                 .get("hrps")
                 .and_then(|h| h.as_array())
                 .map(|arr| {
@@ -147,19 +391,117 @@ pub fn convert_chain_config(config: ChainConfig) -> Result<ChainMetadata, String
                         .filter_map(|v| v.as_str().map(|s| s.to_string()))
                         .collect()
                 })
-                .unwrap_or_default();
-            vec![AddressMetadata {
+                .unwrap_or_default()
+        });
+    vec![cosmos_format(hrps, AddressRole::Account)]
+}
+
+/// Amino Bech32 public key formats of the Cosmos roles (`cosmospub`, `cosmosvalconspub`, ...)
+///
+/// Validator consensus keys are Ed25519; the other roles use the chain's curve.
+fn cosmos_public_key_formats(params: &Value, curve: PublicKeyType) -> Vec<PublicKeyMetadata> {
+    cosmos_roles(params)
+        .into_iter()
+        .filter_map(|(role, _, pub_hrp)| {
+            Some(PublicKeyMetadata {
                 encoding: EncodingType::Bech32,
                 char_set: Some(CharSet::Base32),
                 exact_length: None,
-                length_range: Some((50, 120)), // Cardano addresses vary
+                length_range: None,
                 prefixes: vec![],
-                hrps,
-                version_bytes: vec![],
-                checksum: Some(crate::registry::ChecksumType::Bech32),
-                network: Some(Network::Mainnet),
-            }]
+                hrps: vec![pub_hrp?],
+                key_type: match role {
+                    AddressRole::ValidatorConsensus => PublicKeyType::Ed25519,
+                    _ => curve,
+                },
+                checksum: Some(ChecksumType::Bech32),
+                role: Some(role),
+            })
+        })
+        .collect()
+}
+
+/// EVM `0x` hex address format with EIP-55 checksum
+fn evm_format() -> AddressMetadata {
+    AddressMetadata {
+        encoding: EncodingType::Hex,
+        char_set: Some(CharSet::Hex),
+        exact_length: Some(42), // 0x + 40 hex chars
+        length_range: None,
+        prefixes: vec!["0x".to_string()],
+        hrps: vec![],
+        version_bytes: vec![],
+        checksum: Some(ChecksumType::EIP55),
+        network: Some(Network::Mainnet),
+        output_type: None,
+        role: None,
+        ss58_prefix: None,
+    }
+}
+
+/// SS58 format under the chain's prefix
+///
+/// The length range tells 20-byte `AccountId20` forms (24-33 chars) from
+/// 32-byte account ids and 33-byte ECDSA keys (35-51 chars).
+fn ss58_format(params: &Value, length_range: (usize, usize)) -> AddressMetadata {
+    AddressMetadata {
+        encoding: EncodingType::SS58,
+        char_set: Some(CharSet::Base58),
+        exact_length: None,
+        length_range: Some(length_range), // SS58 addresses vary with the prefix
+        prefixes: vec![],
+        hrps: vec![],
+        version_bytes: vec![],
+        checksum: Some(ChecksumType::SS58),
+        network: Some(Network::Mainnet),
+        output_type: None,
+        role: None,
+        ss58_prefix: ss58_prefix(params),
+    }
+}
+
+/// Transaction and block hash formats; entries with an unknown kind are skipped
+fn hash_formats(formats: &[HashFormat]) -> Vec<HashMetadata> {
+    formats
+        .iter()
+        .filter_map(|format| {
+            let kind = match format.kind.as_str() {
+                "transaction" => HashKind::Transaction,
+                "block" => HashKind::Block,
+                _ => return None,
+            };
+            Some(HashMetadata {
+                kind,
+                encoding: encoding_str_to_enum(&format.encoding),
+                bytes: format.bytes,
+                prefixes: format.prefixes.clone(),
+                uppercase: format.uppercase,
+                leading_zero_bytes: format.leading_zero_bytes,
+            })
+        })
+        .collect()
+}
+
+/// Convert JSON ChainConfig to Rust ChainMetadata
+pub fn convert_chain_config(config: ChainConfig) -> Result<ChainMetadata, String> {
+    // Create address metadata based on pipeline type with proper characteristics
+    let address_formats = match config.address_pipeline.as_str() {
+        // AccountId20 Substrate chains (Moonbeam) also write the 20 bytes in SS58
+        "evm" => match config.address_params.get("prefix") {
+            Some(_) => vec![evm_format(), ss58_format(&config.address_params, (24, 33))],
+            None => vec![evm_format()],
+        },
+        "bitcoin_p2pkh" | "bitcoin_bech32" | "cashaddr" => {
+            bitcoin_family_formats(&config.address_params)
         }
+        "cosmos" => cosmos_formats(&config.address_params),
+        // Bech32 roles first (the pipeline derives the account), then the 0x form
+        "ethermint" => {
+            let mut formats = cosmos_formats(&config.address_params);
+            formats.push(evm_format());
+            formats
+        }
+        "cardano" => cardano_formats(&config.address_params),
         "solana" => vec![AddressMetadata {
             encoding: EncodingType::Base58,
             char_set: Some(CharSet::Base58),
@@ -170,18 +512,11 @@ pub fn convert_chain_config(config: ChainConfig) -> Result<ChainMetadata, String
             version_bytes: vec![],
             checksum: None,
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         }],
-        "ss58" => vec![AddressMetadata {
-            encoding: EncodingType::SS58,
-            char_set: Some(CharSet::Base58),
-            exact_length: None,
-            length_range: Some((35, 48)), // SS58 addresses vary
-            prefixes: vec![],
-            hrps: vec![],
-            version_bytes: vec![],
-            checksum: Some(crate::registry::ChecksumType::SS58),
-            network: Some(Network::Mainnet),
-        }],
+        "ss58" => vec![ss58_format(&config.address_params, (35, 51))],
         "tron" => vec![AddressMetadata {
             encoding: EncodingType::Base58Check,
             char_set: Some(CharSet::Base58),
@@ -190,8 +525,11 @@ pub fn convert_chain_config(config: ChainConfig) -> Result<ChainMetadata, String
             prefixes: vec![],
             hrps: vec![],
             version_bytes: vec![0x41], // Tron version byte
-            checksum: Some(crate::registry::ChecksumType::Base58Check),
+            checksum: Some(ChecksumType::Base58Check),
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         }],
         _ => vec![AddressMetadata {
             encoding: EncodingType::Hex,
@@ -203,11 +541,14 @@ pub fn convert_chain_config(config: ChainConfig) -> Result<ChainMetadata, String
             version_bytes: vec![],
             checksum: None,
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         }],
     };
 
     // Convert public key formats
-    let public_key_formats: Vec<PublicKeyMetadata> = config
+    let mut public_key_formats: Vec<PublicKeyMetadata> = config
         .public_key_formats
         .into_iter()
         .map(|pk_fmt| PublicKeyMetadata {
@@ -221,15 +562,160 @@ pub fn convert_chain_config(config: ChainConfig) -> Result<ChainMetadata, String
             length_range: pk_fmt.length_range,
             prefixes: pk_fmt.prefixes,
             hrps: vec![],
-            key_type: curve_str_to_key_type(&config.curve),
+            key_type: curve_str_to_key_type(pk_fmt.curve.as_deref().unwrap_or(&config.curve)),
             checksum: None,
+            role: None,
         })
         .collect();
+    if matches!(config.address_pipeline.as_str(), "cosmos" | "ethermint") {
+        public_key_formats.extend(cosmos_public_key_formats(
+            &config.address_params,
+            curve_str_to_key_type(&config.curve),
+        ));
+    }
 
     Ok(ChainMetadata {
         id: config.id.clone(),
         name: config.name,
         address_formats,
         public_key_formats,
+        extended_key_formats: extended_key_formats(&config.address_params),
+        wif_formats: wif_formats(&config.address_params),
+        hash_formats: hash_formats(&config.hash_formats),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_bitcoin_family_formats_networks() {
+        let params = json!({
+            "version_byte": 48,
+            "networks": {
+                "testnet": { "p2pkh": [111], "p2sh": [58, 196], "hrp": "tltc" },
+                "mainnet": { "p2pkh": [48], "p2sh": [50, 5], "hrp": "ltc" }
+            }
+        });
+        let formats = bitcoin_family_formats(&params);
+
+        // 2 Base58Check + 2 segwit formats per network
+        assert_eq!(formats.len(), 8);
+        // Mainnet P2PKH first
+        assert_eq!(formats[0].network, Some(Network::Mainnet));
+        assert_eq!(formats[0].output_type, Some(OutputType::P2PKH));
+        assert_eq!(formats[0].version_bytes, vec![48]);
+        assert_eq!(formats[1].version_bytes, vec![50, 5]);
+        assert!(formats
+            .iter()
+            .any(|f| f.network == Some(Network::Testnet) && f.hrps == vec!["tltc".to_string()]));
+        // No fixed string length for Base58Check formats
+        assert!(formats.iter().all(|f| f.exact_length.is_none()));
+    }
+
+    #[test]
+    fn test_bitcoin_family_formats_without_segwit() {
+        let params = json!({
+            "version_byte": 30,
+            "networks": {
+                "mainnet": { "p2pkh": [30], "p2sh": [22] }
+            }
+        });
+        let formats = bitcoin_family_formats(&params);
+
+        assert_eq!(formats.len(), 2);
+        assert!(formats
+            .iter()
+            .all(|f| f.encoding == EncodingType::Base58Check));
+    }
+
+    #[test]
+    fn test_extended_key_formats() {
+        let params = json!({
+            "networks": {
+                "mainnet": {
+                    "p2pkh": [0],
+                    "extended_keys": [
+                        { "prefix": "zpub", "version": "04b24746", "script": "p2wpkh" },
+                        { "prefix": "bad", "version": "04b2", "script": "p2wpkh" }
+                    ]
+                },
+                "testnet": { "p2pkh": [111] }
+            }
+        });
+        let formats = extended_key_formats(&params);
+
+        assert_eq!(formats.len(), 1);
+        assert_eq!(formats[0].prefix, "zpub");
+        assert_eq!(formats[0].version, [0x04, 0xb2, 0x47, 0x46]);
+        assert_eq!(formats[0].network, Network::Mainnet);
+        assert_eq!(formats[0].script, KeyScript::P2WPKH);
+        assert!(extended_key_formats(&json!({ "hrp": "cosmos" })).is_empty());
+    }
+
+    #[test]
+    fn test_wif_formats() {
+        let params = json!({
+            "networks": {
+                "mainnet": { "p2pkh": [30], "wif": [158] },
+                "testnet": { "p2pkh": [113] }
+            }
+        });
+        assert_eq!(
+            wif_formats(&params),
+            vec![WifMetadata {
+                version: 158,
+                network: Network::Mainnet
+            }]
+        );
+        assert!(wif_formats(&json!({ "version_byte": 0 })).is_empty());
+    }
+
+    #[test]
+    fn test_hash_formats() {
+        let formats: Vec<HashFormat> = serde_json::from_value(json!([
+            { "kind": "transaction", "encoding": "hex", "bytes": 32, "uppercase": true },
+            { "kind": "block", "encoding": "hex", "bytes": 32, "leading_zero_bytes": 4 },
+            { "kind": "receipt", "encoding": "hex", "bytes": 32 }
+        ]))
+        .unwrap();
+        let formats = hash_formats(&formats);
+
+        assert_eq!(formats.len(), 2);
+        assert_eq!(formats[0].kind, HashKind::Transaction);
+        assert!(formats[0].uppercase);
+        assert!(formats[0].prefixes.is_empty());
+        assert_eq!(formats[1].kind, HashKind::Block);
+        assert_eq!(formats[1].leading_zero_bytes, 4);
+    }
+
+    #[test]
+    fn test_bitcoin_family_formats_fallback() {
+        let params = json!({ "version_byte": 0 });
+        let formats = bitcoin_family_formats(&params);
+
+        assert_eq!(formats.len(), 1);
+        assert_eq!(formats[0].version_bytes, vec![0]);
+        assert_eq!(formats[0].output_type, Some(OutputType::P2PKH));
+    }
+
+    #[test]
+    fn test_bitcoin_family_formats_cashaddr() {
+        let params = json!({
+            "networks": {
+                "mainnet": { "p2pkh": [0], "p2sh": [5], "cashaddr_prefix": "bitcoincash" }
+            }
+        });
+        let formats = bitcoin_family_formats(&params);
+
+        // CashAddr P2PKH/P2SH, then legacy Base58Check P2PKH/P2SH
+        assert_eq!(formats.len(), 4);
+        assert_eq!(formats[0].encoding, EncodingType::CashAddr);
+        assert_eq!(formats[0].output_type, Some(OutputType::P2PKH));
+        assert_eq!(formats[0].hrps, vec!["bitcoincash".to_string()]);
+        assert_eq!(formats[1].output_type, Some(OutputType::P2SH));
+        assert_eq!(formats[2].encoding, EncodingType::Base58Check);
+    }
+}
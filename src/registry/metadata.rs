@@ -15,6 +15,12 @@ pub struct ChainMetadata {
     pub address_formats: Vec<AddressMetadata>,
     /// All supported public key formats for this chain
     pub public_key_formats: Vec<PublicKeyMetadata>,
+    /// BIP-32 extended public key versions (Bitcoin-family chains, empty otherwise)
+    pub extended_key_formats: Vec<ExtendedKeyMetadata>,
+    /// WIF private key versions (Bitcoin-family chains, empty otherwise)
+    pub wif_formats: Vec<WifMetadata>,
+    /// Transaction and block hash formats
+    pub hash_formats: Vec<HashMetadata>,
 }
 
 /// Metadata for an address format
@@ -30,7 +36,7 @@ pub struct AddressMetadata {
     pub length_range: Option<(usize, usize)>,
     /// Required prefixes (empty vec = no prefix requirement)
     pub prefixes: Vec<String>,
-    /// Required HRPs for Bech32/Bech32m (empty vec = no HRP requirement)
+    /// Required HRPs for Bech32/Bech32m, or prefixes for CashAddr (empty vec = no HRP requirement)
     pub hrps: Vec<String>,
     /// Version bytes for Base58Check formats (empty vec = no version requirement)
     pub version_bytes: Vec<u8>,
@@ -38,6 +44,13 @@ pub struct AddressMetadata {
     pub checksum: Option<ChecksumType>,
     /// Network (mainnet/testnet)
     pub network: Option<Network>,
+    /// Output type for Bitcoin-family formats (None for other chains)
+    pub output_type: Option<OutputType>,
+    /// Cosmos SDK address role (None for other chains)
+    pub role: Option<AddressRole>,
+    /// SS58 network prefix (None for other chains, and for the generic Substrate
+    /// fallback that accepts prefixes no registered chain declares)
+    pub ss58_prefix: Option<u16>,
 }
 
 impl AddressMetadata {
@@ -74,10 +87,10 @@ impl AddressMetadata {
             }
         }
 
-        // Check HRP
-        if !self.hrps.is_empty() {
+        // Check HRP (CashAddr prefixes are optional and checked against the checksum below)
+        if !self.hrps.is_empty() && self.encoding != EncodingType::CashAddr {
             if let Some(ref hrp) = chars.hrp {
-                if !self.hrps.iter().any(|h| hrp.starts_with(h)) {
+                if !self.hrps.iter().any(|h| hrp == h) {
                     return false;
                 }
             } else {
@@ -96,7 +109,18 @@ impl AddressMetadata {
             }
             EncodingType::Bech32 | EncodingType::Bech32m => {
                 use crate::shared::encoding::bech32 as bech32_encoding;
-                bech32_encoding::decode(raw).is_ok()
+                match self.output_type {
+                    // Segwit: check witness version and program length, not just the checksum
+                    Some(OutputType::WitnessV0) | Some(OutputType::WitnessV1Plus) => {
+                        bech32_encoding::decode_segwit(raw)
+                            .map(|(_, version, _)| {
+                                (version == 0) == (self.output_type == Some(OutputType::WitnessV0))
+                            })
+                            .unwrap_or(false)
+                    }
+                    _ if self.checksum == Some(ChecksumType::Cardano) => self.matches_cardano(raw),
+                    _ => bech32_encoding::decode(raw).is_ok(),
+                }
             }
             EncodingType::Base58Check => {
                 use crate::shared::checksum::base58check;
@@ -111,15 +135,79 @@ impl AddressMetadata {
                     false
                 }
             }
-            EncodingType::SS58 => {
-                use crate::shared::encoding::ss58;
-                ss58::decode(raw).is_ok()
+            EncodingType::SS58 => self.matches_ss58(raw),
+            EncodingType::Base58 if self.checksum == Some(ChecksumType::CardanoByron) => {
+                self.matches_byron(raw)
             }
             EncodingType::Base58 => {
                 // Base58 validation - just check if it's valid Base58
                 use crate::shared::encoding::base58;
                 base58::decode(raw).is_ok()
             }
+            EncodingType::CashAddr => self.matches_cashaddr(raw),
+        }
+    }
+
+    /// Check an SS58 string's checksum and network prefix against this format
+    ///
+    /// Only account forms match (20 or 32-byte account ids, 33-byte ECDSA
+    /// keys), not account indices. A format without a prefix (the generic
+    /// Substrate fallback) takes the prefixes no registered chain declares.
+    pub fn matches_ss58(&self, raw: &str) -> bool {
+        use crate::shared::encoding::ss58;
+        match ss58::decode(raw) {
+            Ok((_, payload)) if ![20, 32, 33].contains(&payload.len()) => false,
+            Ok((prefix, _)) => match self.ss58_prefix {
+                Some(declared) => prefix == declared,
+                None => super::Registry::get().ss58_chain(prefix).is_none(),
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// Check a Shelley address's HRP and header network against this format
+    ///
+    /// The address must parse under CIP-19 (type, credentials, network id
+    /// matching its HRP) with one of the format's HRPs and its network.
+    pub fn matches_cardano(&self, raw: &str) -> bool {
+        use crate::cardano::parse_cardano_address;
+        match parse_cardano_address(raw) {
+            Ok(address) => {
+                address
+                    .hrp()
+                    .is_some_and(|hrp| self.hrps.is_empty() || self.hrps.iter().any(|h| h == hrp))
+                    && address.network() == self.network
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Check a Byron address's structure, CRC and network against this format
+    ///
+    /// Addresses without a protocol magic attribute are mainnet, those with
+    /// one belong to a testnet.
+    pub fn matches_byron(&self, raw: &str) -> bool {
+        use crate::cardano::parse_byron_address;
+        parse_byron_address(raw).is_ok_and(|address| Some(address.network()) == self.network)
+    }
+
+    /// Check a CashAddr string against this format's prefixes and output type
+    ///
+    /// An unprefixed input is accepted when its checksum verifies under one
+    /// of the format's prefixes.
+    pub fn matches_cashaddr(&self, raw: &str) -> bool {
+        use crate::shared::encoding::cashaddr;
+        match cashaddr::decode(raw, &self.hrps) {
+            Ok((prefix, address_type, _)) => {
+                let output_type = match address_type {
+                    cashaddr::TYPE_P2PKH => OutputType::P2PKH,
+                    cashaddr::TYPE_P2SH => OutputType::P2SH,
+                    _ => return false,
+                };
+                (self.hrps.is_empty() || self.hrps.contains(&prefix))
+                    && self.output_type.is_none_or(|t| t == output_type)
+            }
+            Err(_) => false,
         }
     }
 }
@@ -143,6 +231,88 @@ pub struct PublicKeyMetadata {
     pub key_type: PublicKeyType,
     /// Checksum type (if any)
     pub checksum: Option<ChecksumType>,
+    /// Role of the address the key controls, for Cosmos amino Bech32 keys (`cosmosvaloperpub`)
+    pub role: Option<AddressRole>,
+}
+
+/// Metadata for a BIP-32 extended public key version (SLIP-132)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtendedKeyMetadata {
+    /// Prefix of the Base58Check serialization (e.g. "zpub")
+    pub prefix: String,
+    /// 4-byte version
+    pub version: [u8; 4],
+    /// Network
+    pub network: Network,
+    /// Script the derived keys are used in
+    pub script: KeyScript,
+}
+
+/// Metadata for a WIF (Wallet Import Format) private key version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WifMetadata {
+    /// Version byte
+    pub version: u8,
+    /// Network
+    pub network: Network,
+}
+
+/// Metadata for a transaction or block hash format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashMetadata {
+    /// What the hash identifies
+    pub kind: HashKind,
+    /// Encoding type (Hex or Base58)
+    pub encoding: EncodingType,
+    /// Decoded length in bytes
+    pub bytes: usize,
+    /// Required prefixes (empty vec = no prefix)
+    pub prefixes: Vec<String>,
+    /// Canonical display is uppercase hex (Tendermint chains)
+    pub uppercase: bool,
+    /// Minimum number of leading zero bytes (proof-of-work block hashes, 0 = none)
+    pub leading_zero_bytes: usize,
+}
+
+/// What a hash identifies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashKind {
+    /// Transaction hash, txid or signature
+    Transaction,
+    /// Block hash
+    Block,
+}
+
+/// Script type that keys derived from an extended public key are used in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyScript {
+    /// Pay-to-public-key-hash (BIP-44)
+    P2PKH,
+    /// P2WPKH nested in P2SH (BIP-49)
+    NestedP2WPKH,
+    /// Native P2WPKH (BIP-84)
+    P2WPKH,
+    /// Multisig P2WSH nested in P2SH
+    NestedP2WSH,
+    /// Native multisig P2WSH
+    P2WSH,
+}
+
+impl KeyScript {
+    /// Whether the keys are multisig cosigner keys, whose addresses need every cosigner
+    pub fn is_multisig(self) -> bool {
+        matches!(self, KeyScript::NestedP2WSH | KeyScript::P2WSH)
+    }
+
+    /// BIP-43 purpose of the account path (BIP-44, 49, 84, or 48 for multisig)
+    pub fn purpose(self) -> u32 {
+        match self {
+            KeyScript::P2PKH => 44,
+            KeyScript::NestedP2WPKH => 49,
+            KeyScript::P2WPKH => 84,
+            KeyScript::NestedP2WSH | KeyScript::P2WSH => 48,
+        }
+    }
 }
 
 /// Encoding type for addresses and public keys
@@ -160,6 +330,8 @@ pub enum EncodingType {
     Bech32m,
     /// SS58 encoding (Substrate)
     SS58,
+    /// CashAddr encoding (Bitcoin Cash)
+    CashAddr,
 }
 
 /// Character set used in the encoding
@@ -189,6 +361,12 @@ pub enum ChecksumType {
     Bech32m,
     /// SS58 checksum (Substrate)
     SS58,
+    /// CashAddr polymod checksum (Bitcoin Cash)
+    CashAddr,
+    /// Bech32 checksum and CIP-19 header (Cardano Shelley addresses)
+    Cardano,
+    /// CBOR structure and CRC-32 (Cardano Byron addresses)
+    CardanoByron,
 }
 
 /// Network type
@@ -197,10 +375,44 @@ pub enum Network {
     /// Mainnet
     Mainnet,
     /// Testnet
-    #[allow(dead_code)] // Reserved for future use
     Testnet,
 }
 
+/// Output type encoded by a Bitcoin-family address format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutputType {
+    /// Pay-to-public-key-hash (Base58Check or CashAddr)
+    P2PKH,
+    /// Pay-to-script-hash (Base58Check or CashAddr)
+    P2SH,
+    /// Segwit version 0 program: P2WPKH or P2WSH (Bech32)
+    WitnessV0,
+    /// Segwit version 1+ program: P2TR and future versions (Bech32m)
+    WitnessV1Plus,
+}
+
+/// Role of a Cosmos SDK Bech32 address, told apart by its HRP
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressRole {
+    /// User account (`cosmos1...`)
+    Account,
+    /// Validator operator (`cosmosvaloper1...`), same bytes as the operator's account
+    ValidatorOperator,
+    /// Validator consensus node (`cosmosvalcons1...`), hash of the Ed25519 consensus key
+    ValidatorConsensus,
+}
+
+impl AddressRole {
+    /// Role key in chain metadata (`account`, `valoper`, `valcons`)
+    pub fn name(&self) -> &'static str {
+        match self {
+            AddressRole::Account => "account",
+            AddressRole::ValidatorOperator => "valoper",
+            AddressRole::ValidatorConsensus => "valcons",
+        }
+    }
+}
+
 /// Public key type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PublicKeyType {
@@ -209,7 +421,6 @@ pub enum PublicKeyType {
     /// Ed25519 public key (32 bytes)
     Ed25519,
     /// sr25519 public key (32 bytes)
-    #[allow(dead_code)] // Reserved for future use
     Sr25519,
 }
 
@@ -230,6 +441,9 @@ mod tests {
             version_bytes: vec![],
             checksum: None,
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"; // Base58, not hex
@@ -250,6 +464,9 @@ mod tests {
             version_bytes: vec![],
             checksum: None,
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "0x1234"; // Too short
@@ -270,6 +487,9 @@ mod tests {
             version_bytes: vec![0x00], // Bitcoin P2PKH version
             checksum: Some(ChecksumType::Base58Check),
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"; // 34 chars, within range, valid Bitcoin address
@@ -290,6 +510,9 @@ mod tests {
             version_bytes: vec![],
             checksum: None,
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "123"; // Too short
@@ -310,12 +533,21 @@ mod tests {
             version_bytes: vec![],
             checksum: None,
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "osmo1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"; // Wrong HRP (osmo, not cosmos)
         let chars = extract_characteristics(input);
 
         assert!(!metadata.validate_raw(input, &chars));
+
+        // Role HRPs extend the account HRP but are not the same format
+        let input = "cosmosvaloper1w508d6qejxtdg4y5r3zarvary0c5xw7klfr0rt";
+        let chars = extract_characteristics(input);
+
+        assert!(!metadata.validate_raw(input, &chars));
     }
 
     #[test]
@@ -330,6 +562,9 @@ mod tests {
             version_bytes: vec![],
             checksum: None,
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e"; // No HRP
@@ -350,6 +585,9 @@ mod tests {
             version_bytes: vec![0x00], // Bitcoin P2PKH version byte
             checksum: Some(ChecksumType::Base58Check),
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"; // Valid Bitcoin P2PKH
@@ -370,6 +608,9 @@ mod tests {
             version_bytes: vec![0x05], // P2SH version byte
             checksum: Some(ChecksumType::Base58Check),
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"; // P2PKH (version 0), not P2SH
@@ -390,6 +631,9 @@ mod tests {
             version_bytes: vec![],
             checksum: None,
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "0xgggggggggggggggggggggggggggggggggggggggg"; // Invalid hex
@@ -410,6 +654,9 @@ mod tests {
             version_bytes: vec![],
             checksum: None,
             network: Some(Network::Mainnet),
+            output_type: None,
+            role: None,
+            ss58_prefix: None,
         };
 
         let input = "cosmos1invalid"; // Invalid Bech32
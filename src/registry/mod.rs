@@ -9,6 +9,7 @@ pub mod metadata;
 
 pub use build::Registry;
 pub use metadata::{
-    AddressMetadata, ChainMetadata, CharSet, ChecksumType, EncodingType, Network,
-    PublicKeyMetadata, PublicKeyType,
+    AddressMetadata, AddressRole, ChainMetadata, CharSet, ChecksumType, EncodingType,
+    ExtendedKeyMetadata, HashKind, HashMetadata, KeyScript, Network, OutputType, PublicKeyMetadata,
+    PublicKeyType, WifMetadata,
 };
@@ -0,0 +1,17 @@
+//! Bitcoin-family output scripts
+//!
+//! Conversion between `scriptPubKey` bytes and the addresses declared in the
+//! chain metadata, and multisig redeem/witness scripts.
+
+mod multisig;
+mod script_pubkey;
+
+pub use multisig::{
+    build_multisig_script, multisig_addresses, multisig_addresses_from_script, MultisigAddresses,
+};
+pub use script_pubkey::{
+    address_to_script, decode_script_pubkey, script_to_address, ScriptPubKey, ScriptType,
+};
+
+pub(crate) use multisig::{check_redeem_script_size, check_witness_script_size, multisig_script};
+pub(crate) use script_pubkey::{encode_address, find_format};
@@ -0,0 +1,376 @@
+//! Multisig redeem/witness scripts and their P2SH, P2SH-P2WSH and P2WSH addresses
+
+use crate::registry::{Network, OutputType};
+use crate::script::script_pubkey::{encode_address, find_format};
+use crate::script::ScriptType;
+use crate::shared::crypto::hash::{hash160, sha256};
+use crate::shared::crypto::secp256k1;
+use crate::shared::encoding::hex;
+use crate::Error;
+
+/// OP_CHECKMULTISIG opcode
+const OP_CHECKMULTISIG: u8 = 0xae;
+/// Maximum size of a P2SH redeem script (consensus push limit)
+const MAX_REDEEM_SCRIPT_SIZE: usize = 520;
+/// Maximum size of a standard P2WSH witness script
+const MAX_WITNESS_SCRIPT_SIZE: usize = 3600;
+
+/// Check that a script fits in a P2SH redeem script
+pub(crate) fn check_redeem_script_size(script: &[u8]) -> Result<(), Error> {
+    if script.len() > MAX_REDEEM_SCRIPT_SIZE {
+        return Err(Error::InvalidInput(format!(
+            "Script is {} bytes, P2SH redeem scripts are limited to {}",
+            script.len(),
+            MAX_REDEEM_SCRIPT_SIZE
+        )));
+    }
+    Ok(())
+}
+
+/// Check that a script fits in a standard P2WSH witness script
+pub(crate) fn check_witness_script_size(script: &[u8]) -> Result<(), Error> {
+    if script.len() > MAX_WITNESS_SCRIPT_SIZE {
+        return Err(Error::InvalidInput(format!(
+            "Script is {} bytes, P2WSH witness scripts are limited to {}",
+            script.len(),
+            MAX_WITNESS_SCRIPT_SIZE
+        )));
+    }
+    Ok(())
+}
+
+/// Addresses locking funds to a redeem/witness script on a chain and network
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisigAddresses {
+    /// Hex redeem/witness script
+    pub script: String,
+    /// Legacy P2SH address (None if the script exceeds the 520-byte redeem script limit)
+    pub p2sh: Option<String>,
+    /// P2SH-wrapped P2WSH address (None if the chain has no segwit or keys are uncompressed)
+    pub p2sh_p2wsh: Option<String>,
+    /// Native P2WSH address (None if the chain has no segwit or keys are uncompressed)
+    pub p2wsh: Option<String>,
+}
+
+/// Build a standard m-of-n `OP_CHECKMULTISIG` script from hex public keys
+///
+/// With `sort_keys`, keys are ordered as in BIP-67 (lexicographic order of
+/// their compressed serialization), which requires compressed keys.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::build_multisig_script;
+///
+/// let script = build_multisig_script(
+///     1,
+///     &["0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"],
+///     false,
+/// )?;
+/// assert_eq!(
+///     script,
+///     "51210279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f8179851ae"
+/// );
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn build_multisig_script(
+    threshold: usize,
+    public_keys: &[&str],
+    sort_keys: bool,
+) -> Result<String, Error> {
+    Ok(::hex::encode(multisig_script(
+        threshold,
+        &decode_keys(public_keys)?,
+        sort_keys,
+    )?))
+}
+
+/// Derive P2SH, P2SH-P2WSH and P2WSH addresses from a hex redeem/witness script
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::{multisig_addresses_from_script, Network};
+///
+/// let script = "522102fe6f0a5a297eb38c391581c4413e084773ea23954d93f7753db7dc0adc188b2f\
+///               2102ff12471208c14bd580709cb2358d98975247d8765f92bc25eab3b2763ed605f852ae";
+/// let addresses = multisig_addresses_from_script(script, "bitcoin", Network::Mainnet)?;
+/// assert_eq!(addresses.p2sh.as_deref(), Some("39bgKC7RFbpoCRbtD5KEdkYKtNyhpsNa3Z"));
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn multisig_addresses_from_script(
+    script_hex: &str,
+    chain: &str,
+    network: Network,
+) -> Result<MultisigAddresses, Error> {
+    let script = hex::decode(script_hex).map_err(Error::InvalidInput)?;
+    script_addresses(&script, chain, network, true)
+}
+
+/// Derive P2SH, P2SH-P2WSH and P2WSH addresses for an m-of-n multisig
+///
+/// Segwit addresses are only returned when every key is compressed, since
+/// uncompressed keys are non-standard in witness scripts.
+pub fn multisig_addresses(
+    threshold: usize,
+    public_keys: &[&str],
+    sort_keys: bool,
+    chain: &str,
+    network: Network,
+) -> Result<MultisigAddresses, Error> {
+    let keys = decode_keys(public_keys)?;
+    let script = multisig_script(threshold, &keys, sort_keys)?;
+    let all_compressed = keys.iter().all(|k| k.len() == 33);
+    script_addresses(&script, chain, network, all_compressed)
+}
+
+/// Decode and validate hex secp256k1 public keys
+fn decode_keys(public_keys: &[&str]) -> Result<Vec<Vec<u8>>, Error> {
+    public_keys
+        .iter()
+        .map(|key| {
+            let bytes = hex::decode(key).map_err(Error::InvalidInput)?;
+            if secp256k1::is_valid_public_key(&bytes) {
+                Ok(bytes)
+            } else {
+                Err(Error::InvalidInput(format!(
+                    "Invalid secp256k1 public key: {}",
+                    key
+                )))
+            }
+        })
+        .collect()
+}
+
+/// Encode a small integer (1-16) as OP_1..OP_16
+fn small_int_opcode(n: usize) -> u8 {
+    0x50 + n as u8
+}
+
+/// Build `OP_m <keys> OP_n OP_CHECKMULTISIG`
+pub(crate) fn multisig_script(
+    threshold: usize,
+    keys: &[Vec<u8>],
+    sort_keys: bool,
+) -> Result<Vec<u8>, Error> {
+    let n = keys.len();
+    if n == 0 || n > 16 {
+        return Err(Error::InvalidInput(format!(
+            "Multisig requires 1 to 16 public keys, got {}",
+            n
+        )));
+    }
+    if threshold == 0 || threshold > n {
+        return Err(Error::InvalidInput(format!(
+            "Invalid multisig threshold {} of {}",
+            threshold, n
+        )));
+    }
+
+    let mut keys = keys.to_vec();
+    if sort_keys {
+        if keys.iter().any(|k| k.len() != 33) {
+            return Err(Error::InvalidInput(
+                "BIP-67 key sorting requires compressed public keys".to_string(),
+            ));
+        }
+        keys.sort();
+    }
+
+    let mut script = vec![small_int_opcode(threshold)];
+    for key in &keys {
+        script.push(key.len() as u8);
+        script.extend_from_slice(key);
+    }
+    script.push(small_int_opcode(n));
+    script.push(OP_CHECKMULTISIG);
+    Ok(script)
+}
+
+/// Encode the P2SH, P2SH-P2WSH and P2WSH addresses of a script
+fn script_addresses(
+    script: &[u8],
+    chain: &str,
+    network: Network,
+    allow_segwit: bool,
+) -> Result<MultisigAddresses, Error> {
+    if script.is_empty() {
+        return Err(Error::InvalidInput("Empty script".to_string()));
+    }
+    let p2sh_format = find_format(chain, network, OutputType::P2SH)?.ok_or_else(|| {
+        Error::InvalidInput(format!(
+            "{} has no P2SH address format on {:?}",
+            chain, network
+        ))
+    })?;
+    let p2sh = if check_redeem_script_size(script).is_ok() {
+        Some(encode_address(
+            p2sh_format,
+            ScriptType::P2SH,
+            &hash160(script),
+        )?)
+    } else {
+        None
+    };
+
+    let witness_format = find_format(chain, network, OutputType::WitnessV0)?
+        .filter(|_| allow_segwit && check_witness_script_size(script).is_ok());
+    let (p2sh_p2wsh, p2wsh) = match witness_format {
+        Some(format) => {
+            let program = sha256(script);
+            let nested = [&[0x00, 0x20], &program[..]].concat();
+            (
+                Some(encode_address(
+                    p2sh_format,
+                    ScriptType::P2SH,
+                    &hash160(&nested),
+                )?),
+                Some(encode_address(format, ScriptType::P2WSH, &program)?),
+            )
+        }
+        None => (None, None),
+    };
+    if p2sh.is_none() && p2wsh.is_none() {
+        return Err(Error::InvalidInput(format!(
+            "Script is {} bytes: P2SH redeem scripts are limited to {}, P2WSH needs segwit, compressed keys and at most {}",
+            script.len(),
+            MAX_REDEEM_SCRIPT_SIZE,
+            MAX_WITNESS_SCRIPT_SIZE
+        )));
+    }
+
+    Ok(MultisigAddresses {
+        script: ::hex::encode(script),
+        p2sh,
+        p2sh_p2wsh,
+        p2wsh,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // BIP-67 test vector 1
+    const KEY_A: &str = "02ff12471208c14bd580709cb2358d98975247d8765f92bc25eab3b2763ed605f8";
+    const KEY_B: &str = "02fe6f0a5a297eb38c391581c4413e084773ea23954d93f7753db7dc0adc188b2f";
+    const SORTED_SCRIPT: &str = "522102fe6f0a5a297eb38c391581c4413e084773ea23954d93f7753db7dc0adc188b2f2102ff12471208c14bd580709cb2358d98975247d8765f92bc25eab3b2763ed605f852ae";
+
+    #[test]
+    fn test_build_multisig_script_bip67_sorting() {
+        assert_eq!(
+            build_multisig_script(2, &[KEY_A, KEY_B], true).unwrap(),
+            SORTED_SCRIPT
+        );
+        // Without sorting the given order is kept
+        let unsorted = build_multisig_script(2, &[KEY_A, KEY_B], false).unwrap();
+        assert_ne!(unsorted, SORTED_SCRIPT);
+        assert!(unsorted.starts_with("522102ff"));
+    }
+
+    #[test]
+    fn test_multisig_addresses_bitcoin() {
+        let addresses =
+            multisig_addresses(2, &[KEY_A, KEY_B], true, "bitcoin", Network::Mainnet).unwrap();
+        assert_eq!(addresses.script, SORTED_SCRIPT);
+        assert_eq!(
+            addresses.p2sh.as_deref(),
+            Some("39bgKC7RFbpoCRbtD5KEdkYKtNyhpsNa3Z")
+        );
+        assert_eq!(
+            addresses.p2sh_p2wsh.as_deref(),
+            Some("3BBLivaThSP3C31jzmQJiMWBM7BLndaWfh")
+        );
+        assert_eq!(
+            addresses.p2wsh.as_deref(),
+            Some("bc1qknwt9mhqpd7hrjrvpqz57zjqk28xlp2h90te6v22en0m3uctnams3pq5ce")
+        );
+    }
+
+    #[test]
+    fn test_multisig_addresses_from_script_per_chain() {
+        let testnet =
+            multisig_addresses_from_script(SORTED_SCRIPT, "bitcoin", Network::Testnet).unwrap();
+        assert_eq!(
+            testnet.p2wsh.as_deref(),
+            Some("tb1qknwt9mhqpd7hrjrvpqz57zjqk28xlp2h90te6v22en0m3uctnamsxfkmzk")
+        );
+
+        // Dogecoin has P2SH but no segwit
+        let doge =
+            multisig_addresses_from_script(SORTED_SCRIPT, "dogecoin", Network::Mainnet).unwrap();
+        assert_eq!(
+            doge.p2sh.as_deref(),
+            Some("9zLw43BKKfhh6nyMdCyestAhaxMjqXLwtA")
+        );
+        assert_eq!(doge.p2sh_p2wsh, None);
+        assert_eq!(doge.p2wsh, None);
+    }
+
+    #[test]
+    fn test_multisig_uncompressed_keys_have_no_segwit_addresses() {
+        let uncompressed = "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+        let addresses = multisig_addresses(
+            1,
+            &[uncompressed, KEY_A],
+            false,
+            "bitcoin",
+            Network::Mainnet,
+        )
+        .unwrap();
+        assert!(addresses.p2sh.unwrap().starts_with('3'));
+        assert_eq!(addresses.p2wsh, None);
+
+        // BIP-67 only defines ordering for compressed keys
+        assert!(build_multisig_script(1, &[uncompressed, KEY_A], true).is_err());
+    }
+
+    #[test]
+    fn test_multisig_invalid_parameters() {
+        assert!(build_multisig_script(0, &[KEY_A], false).is_err());
+        assert!(build_multisig_script(3, &[KEY_A, KEY_B], false).is_err());
+        assert!(build_multisig_script(1, &[], false).is_err());
+        assert!(build_multisig_script(1, &[&format!("02{}", "ff".repeat(32))], false).is_err());
+        assert!(build_multisig_script(1, &["zz"], false).is_err());
+        assert!(multisig_addresses_from_script("", "bitcoin", Network::Mainnet).is_err());
+        assert!(
+            multisig_addresses_from_script(SORTED_SCRIPT, "ethereum", Network::Mainnet).is_err()
+        );
+        // Too large for P2SH on a chain without segwit
+        assert!(
+            multisig_addresses_from_script(&"00".repeat(521), "dogecoin", Network::Mainnet)
+                .is_err()
+        );
+        assert!(
+            multisig_addresses_from_script(&"00".repeat(3601), "bitcoin", Network::Mainnet)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_multisig_addresses_over_p2sh_limit() {
+        // 1-of-16 with compressed keys: 547 bytes, too large for P2SH only
+        let keys: Vec<String> = (1..=16u8)
+            .map(|i| {
+                let mut secret = [0u8; 32];
+                secret[31] = i;
+                ::hex::encode(secp256k1::public_key_from_secret(&secret, true).unwrap())
+            })
+            .collect();
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+        let addresses = multisig_addresses(1, &keys, false, "bitcoin", Network::Mainnet).unwrap();
+        assert_eq!(addresses.script.len(), 547 * 2);
+        assert_eq!(addresses.p2sh, None);
+        assert!(addresses.p2sh_p2wsh.unwrap().starts_with('3'));
+
+        // Same script as the wsh() descriptor
+        let descriptor = format!("wsh(multi(1,{}))", keys.join(","));
+        assert_eq!(
+            addresses.p2wsh,
+            Some(crate::descriptor_address(&descriptor, "bitcoin", Network::Mainnet).unwrap())
+        );
+
+        // Without segwit no address type is possible
+        assert!(multisig_addresses(1, &keys, false, "dogecoin", Network::Mainnet).is_err());
+    }
+}
@@ -0,0 +1,381 @@
+//! scriptPubKey ⇄ address conversion
+//!
+//! Addresses are encoded with the first format a chain declares for the
+//! requested network and output type, the same order `identify` reports them
+//! in (e.g. CashAddr before legacy Base58Check for Bitcoin Cash).
+
+use crate::input::{extract_characteristics, CategorySignature};
+use crate::registry::{AddressMetadata, EncodingType, Network, OutputType, Registry};
+use crate::shared::checksum::base58check;
+use crate::shared::encoding::{bech32 as bech32_encoding, cashaddr, hex};
+use crate::Error;
+
+/// Standard output script types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScriptType {
+    /// `OP_DUP OP_HASH160 <20 bytes> OP_EQUALVERIFY OP_CHECKSIG`
+    P2PKH,
+    /// `OP_HASH160 <20 bytes> OP_EQUAL`
+    P2SH,
+    /// `OP_0 <20 bytes>`
+    P2WPKH,
+    /// `OP_0 <32 bytes>`
+    P2WSH,
+    /// `OP_1 <32 bytes>`
+    P2TR,
+    /// `OP_1..OP_16 <2-40 bytes>` other than P2TR
+    WitnessUnknown {
+        /// Witness version (1-16)
+        version: u8,
+    },
+    /// `OP_RETURN ...` data carrier, which has no address
+    OpReturn,
+}
+
+impl ScriptType {
+    /// Output type of the address format encoding this script, if any
+    pub(crate) fn output_type(self) -> Option<OutputType> {
+        match self {
+            ScriptType::P2PKH => Some(OutputType::P2PKH),
+            ScriptType::P2SH => Some(OutputType::P2SH),
+            ScriptType::P2WPKH | ScriptType::P2WSH => Some(OutputType::WitnessV0),
+            ScriptType::P2TR | ScriptType::WitnessUnknown { .. } => Some(OutputType::WitnessV1Plus),
+            ScriptType::OpReturn => None,
+        }
+    }
+}
+
+/// A decoded scriptPubKey
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptPubKey {
+    /// Script type
+    pub script_type: ScriptType,
+    /// Address for the chain and network (None for OP_RETURN outputs)
+    pub address: Option<String>,
+}
+
+/// Split a script into its type and the hash or witness program it commits to
+fn parse_script(script: &[u8]) -> Result<(ScriptType, &[u8]), Error> {
+    match script {
+        [0x76, 0xa9, 0x14, hash @ .., 0x88, 0xac] if hash.len() == 20 => {
+            Ok((ScriptType::P2PKH, hash))
+        }
+        [0xa9, 0x14, hash @ .., 0x87] if hash.len() == 20 => Ok((ScriptType::P2SH, hash)),
+        [0x00, 0x14, program @ ..] if program.len() == 20 => Ok((ScriptType::P2WPKH, program)),
+        [0x00, 0x20, program @ ..] if program.len() == 32 => Ok((ScriptType::P2WSH, program)),
+        [op @ 0x51..=0x60, len, program @ ..]
+            if *len as usize == program.len() && (2..=40).contains(&program.len()) =>
+        {
+            let version = op - 0x50;
+            if version == 1 && program.len() == 32 {
+                Ok((ScriptType::P2TR, program))
+            } else {
+                Ok((ScriptType::WitnessUnknown { version }, program))
+            }
+        }
+        [0x6a, data @ ..] => Ok((ScriptType::OpReturn, data)),
+        _ => Err(Error::InvalidInput(
+            "Unsupported scriptPubKey: not a standard output script".to_string(),
+        )),
+    }
+}
+
+/// Witness version of a segwit script type
+fn witness_version(script_type: ScriptType) -> u8 {
+    match script_type {
+        ScriptType::P2TR => 1,
+        ScriptType::WitnessUnknown { version } => version,
+        _ => 0,
+    }
+}
+
+/// Decode a hex scriptPubKey into its type and its address on a chain and network
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::{decode_script_pubkey, Network, ScriptType};
+///
+/// let script = "0014751e76e8199196d454941c45d1b3a323f1433bd6";
+/// let decoded = decode_script_pubkey(script, "bitcoin", Network::Mainnet)?;
+/// assert_eq!(decoded.script_type, ScriptType::P2WPKH);
+/// assert_eq!(
+///     decoded.address.as_deref(),
+///     Some("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4")
+/// );
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn decode_script_pubkey(
+    script_hex: &str,
+    chain: &str,
+    network: Network,
+) -> Result<ScriptPubKey, Error> {
+    let script = hex::decode(script_hex).map_err(Error::InvalidInput)?;
+    let (script_type, payload) = parse_script(&script)?;
+
+    let output_type = match script_type.output_type() {
+        Some(output_type) => output_type,
+        None => {
+            return Ok(ScriptPubKey {
+                script_type,
+                address: None,
+            })
+        }
+    };
+
+    let format = find_format(chain, network, output_type)?.ok_or_else(|| {
+        Error::InvalidInput(format!(
+            "{} has no {:?} address format on {:?}",
+            chain, script_type, network
+        ))
+    })?;
+
+    let address = encode_address(format, script_type, payload)?;
+    Ok(ScriptPubKey {
+        script_type,
+        address: Some(address),
+    })
+}
+
+/// Find the primary address format of a chain for a network and output type
+///
+/// Returns `Ok(None)` when the chain exists but has no such format.
+pub(crate) fn find_format(
+    chain: &str,
+    network: Network,
+    output_type: OutputType,
+) -> Result<Option<&'static AddressMetadata>, Error> {
+    let chain_metadata = Registry::get()
+        .chains
+        .iter()
+        .find(|c| c.id == chain)
+        .ok_or_else(|| Error::InvalidInput(format!("Unknown chain: {}", chain)))?;
+    Ok(chain_metadata
+        .address_formats
+        .iter()
+        .find(|f| f.network == Some(network) && f.output_type == Some(output_type)))
+}
+
+/// Convert a hex scriptPubKey to its address on a chain and network
+///
+/// Fails for scripts without an address, such as OP_RETURN outputs.
+pub fn script_to_address(script_hex: &str, chain: &str, network: Network) -> Result<String, Error> {
+    let decoded = decode_script_pubkey(script_hex, chain, network)?;
+    decoded.address.ok_or_else(|| {
+        Error::InvalidInput(format!("{:?} output has no address", decoded.script_type))
+    })
+}
+
+/// Encode a script payload with an address format
+pub(crate) fn encode_address(
+    format: &AddressMetadata,
+    script_type: ScriptType,
+    payload: &[u8],
+) -> Result<String, Error> {
+    let missing = || Error::InvalidInput(format!("Incomplete {:?} format", format.encoding));
+    match format.encoding {
+        EncodingType::Base58Check => {
+            let version = format.version_bytes.first().ok_or_else(missing)?;
+            Ok(base58check::encode(*version, payload))
+        }
+        EncodingType::CashAddr => {
+            let prefix = format.hrps.first().ok_or_else(missing)?;
+            let address_type = match script_type {
+                ScriptType::P2SH => cashaddr::TYPE_P2SH,
+                _ => cashaddr::TYPE_P2PKH,
+            };
+            cashaddr::encode(prefix, address_type, payload).map_err(Error::InvalidInput)
+        }
+        EncodingType::Bech32 | EncodingType::Bech32m => {
+            let hrp = format.hrps.first().ok_or_else(missing)?;
+            bech32_encoding::encode_segwit(hrp, witness_version(script_type), payload)
+                .map_err(Error::InvalidInput)
+        }
+        other => Err(Error::InvalidInput(format!(
+            "{:?} addresses have no output script",
+            other
+        ))),
+    }
+}
+
+/// Build the scriptPubKey for an output type and hash or witness program
+fn build_script(format: &AddressMetadata, address: &str) -> Option<Vec<u8>> {
+    match format.encoding {
+        EncodingType::Base58Check | EncodingType::CashAddr => {
+            let hash = if format.encoding == EncodingType::CashAddr {
+                cashaddr::decode(address, &format.hrps).ok()?.2
+            } else {
+                base58check::validate(address).ok()??.1
+            };
+            if hash.len() != 20 {
+                return None;
+            }
+            match format.output_type? {
+                OutputType::P2PKH => Some([&[0x76, 0xa9, 0x14], &hash[..], &[0x88, 0xac]].concat()),
+                OutputType::P2SH => Some([&[0xa9, 0x14], &hash[..], &[0x87]].concat()),
+                _ => None,
+            }
+        }
+        EncodingType::Bech32 | EncodingType::Bech32m => {
+            let (_, version, program) = bech32_encoding::decode_segwit(address).ok()?;
+            let op = if version == 0 { 0x00 } else { 0x50 + version };
+            Some([&[op, program.len() as u8], &program[..]].concat())
+        }
+        _ => None,
+    }
+}
+
+/// Convert a Bitcoin-family address back into its hex scriptPubKey
+///
+/// The address is matched against the registry the same way `identify` does;
+/// chains sharing a format (e.g. Bitcoin and Bitcoin Cash legacy addresses)
+/// yield the same script.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::address_to_script;
+///
+/// let script = address_to_script("1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu")?;
+/// assert_eq!(script, "76a91476a04053bda0a88bda5177b86a15c3b29f55987388ac");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn address_to_script(address: &str) -> Result<String, Error> {
+    let chars = extract_characteristics(address);
+
+    let mut scripts: Vec<Vec<u8>> = Registry::get()
+        .chains
+        .iter()
+        .flat_map(|chain| chain.address_formats.iter())
+        .filter(|f| f.output_type.is_some())
+        .filter(|f| CategorySignature::from_metadata(f).matches(&chars))
+        .filter(|f| f.validate_raw(address, &chars))
+        .filter_map(|f| build_script(f, address))
+        .collect();
+    scripts.dedup();
+
+    match scripts.as_slice() {
+        [script] => Ok(::hex::encode(script)),
+        [] => Err(Error::InvalidInput(format!(
+            "Not a Bitcoin-family address: {}",
+            address
+        ))),
+        _ => Err(Error::InvalidInput(format!(
+            "Address maps to conflicting scripts: {}",
+            address
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const P2PKH_SCRIPT: &str = "76a91476a04053bda0a88bda5177b86a15c3b29f55987388ac";
+    const P2SH_SCRIPT: &str = "a91476a04053bda0a88bda5177b86a15c3b29f55987387";
+    const P2WPKH_SCRIPT: &str = "0014751e76e8199196d454941c45d1b3a323f1433bd6";
+    const P2WSH_SCRIPT: &str =
+        "00201863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262";
+    const P2TR_SCRIPT: &str =
+        "512079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    #[test]
+    fn test_script_to_address_bitcoin_mainnet() {
+        let cases = [
+            (P2PKH_SCRIPT, "1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu"),
+            (P2SH_SCRIPT, "3CWFddi6m4ndiGyKqzYvsFYagqDLPVMTzC"),
+            (P2WPKH_SCRIPT, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"),
+            (
+                P2WSH_SCRIPT,
+                "bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3",
+            ),
+            (
+                P2TR_SCRIPT,
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+            ),
+        ];
+        for (script, address) in cases {
+            assert_eq!(
+                script_to_address(script, "bitcoin", Network::Mainnet).unwrap(),
+                address
+            );
+            assert_eq!(address_to_script(address).unwrap(), script);
+        }
+    }
+
+    #[test]
+    fn test_script_types() {
+        let decoded = decode_script_pubkey(P2WSH_SCRIPT, "bitcoin", Network::Mainnet).unwrap();
+        assert_eq!(decoded.script_type, ScriptType::P2WSH);
+        let decoded = decode_script_pubkey(P2TR_SCRIPT, "bitcoin", Network::Mainnet).unwrap();
+        assert_eq!(decoded.script_type, ScriptType::P2TR);
+
+        let decoded = decode_script_pubkey("5202751e", "bitcoin", Network::Mainnet).unwrap();
+        assert_eq!(
+            decoded.script_type,
+            ScriptType::WitnessUnknown { version: 2 }
+        );
+        assert_eq!(decoded.address.as_deref(), Some("bc1zw50qjl4auq"));
+    }
+
+    #[test]
+    fn test_op_return_has_no_address() {
+        let decoded =
+            decode_script_pubkey("6a0b68656c6c6f20776f726c64", "bitcoin", Network::Mainnet)
+                .unwrap();
+        assert_eq!(decoded.script_type, ScriptType::OpReturn);
+        assert_eq!(decoded.address, None);
+        assert!(script_to_address("6a", "bitcoin", Network::Mainnet).is_err());
+    }
+
+    #[test]
+    fn test_script_to_address_per_chain_and_network() {
+        assert_eq!(
+            script_to_address(P2WPKH_SCRIPT, "bitcoin", Network::Testnet).unwrap(),
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"
+        );
+        assert_eq!(
+            script_to_address(P2WPKH_SCRIPT, "litecoin", Network::Mainnet).unwrap(),
+            "ltc1qw508d6qejxtdg4y5r3zarvary0c5xw7kgmn4n9"
+        );
+        assert_eq!(
+            script_to_address(P2PKH_SCRIPT, "litecoin", Network::Mainnet).unwrap(),
+            "LW3ByJXVHpiJsuy3u2sdieFQkXHtuk93Yi"
+        );
+        assert_eq!(
+            script_to_address(P2PKH_SCRIPT, "dogecoin", Network::Mainnet).unwrap(),
+            "DFxLFMAJWaNYA7TVTUstzPMFRSevAwTSLq"
+        );
+        // Bitcoin Cash reports its primary CashAddr form
+        assert_eq!(
+            script_to_address(P2SH_SCRIPT, "bitcoin_cash", Network::Mainnet).unwrap(),
+            "bitcoincash:ppm2qsznhks23z7629mms6s4cwef74vcwvn0h829pq"
+        );
+    }
+
+    #[test]
+    fn test_script_to_address_unsupported() {
+        // Dogecoin has no segwit
+        assert!(script_to_address(P2WPKH_SCRIPT, "dogecoin", Network::Mainnet).is_err());
+        assert!(script_to_address(P2PKH_SCRIPT, "ethereum", Network::Mainnet).is_err());
+        assert!(script_to_address(P2PKH_SCRIPT, "unknown", Network::Mainnet).is_err());
+        // Truncated and non-standard scripts
+        assert!(script_to_address("76a914", "bitcoin", Network::Mainnet).is_err());
+        assert!(script_to_address("51", "bitcoin", Network::Mainnet).is_err());
+        assert!(script_to_address("zz", "bitcoin", Network::Mainnet).is_err());
+    }
+
+    #[test]
+    fn test_address_to_script_other_encodings() {
+        assert_eq!(
+            address_to_script("bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a").unwrap(),
+            P2PKH_SCRIPT
+        );
+        assert_eq!(
+            address_to_script("LW3ByJXVHpiJsuy3u2sdieFQkXHtuk93Yi").unwrap(),
+            P2PKH_SCRIPT
+        );
+        assert!(address_to_script("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045").is_err());
+        assert!(address_to_script("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t").is_err());
+    }
+}
@@ -6,6 +6,30 @@
 use crate::shared::crypto::hash::double_sha256;
 use crate::shared::encoding::base58::decode;
 use crate::Error;
+use base58::ToBase58;
+
+/// Encode a version byte and payload as Base58Check
+pub fn encode(version: u8, payload: &[u8]) -> String {
+    encode_data(&[&[version], payload].concat())
+}
+
+/// Encode arbitrary data with a 4-byte double-SHA256 checksum as Base58
+pub fn encode_data(data: &[u8]) -> String {
+    let checksum = double_sha256(data);
+    [data, &checksum[..4]].concat().to_base58()
+}
+
+/// Decode Base58Check data of any length, returning it without the checksum
+///
+/// Returns None if the input is not Base58 or the checksum does not match.
+pub fn decode_data(input: &str) -> Option<Vec<u8>> {
+    let decoded = decode(input).ok()?;
+    if decoded.len() < 4 {
+        return None;
+    }
+    let (data, checksum) = decoded.split_at(decoded.len() - 4);
+    (double_sha256(data)[..4] == *checksum).then(|| data.to_vec())
+}
 
 /// Validate Base58Check encoding and extract version byte and hash
 ///
@@ -44,6 +68,15 @@ pub fn validate(input: &str) -> Result<Option<(u8, Vec<u8>)>, Error> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_encode_decode_data_roundtrip() {
+        let data = [0x04, 0x88, 0xb2, 0x1e, 0x00, 0xff];
+        let encoded = encode_data(&data);
+        assert_eq!(decode_data(&encoded).as_deref(), Some(&data[..]));
+        assert_eq!(decode_data("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3"), None);
+        assert_eq!(decode_data("0OIl"), None);
+    }
+
     #[test]
     fn test_validate_valid_bitcoin_address() {
         let input = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
@@ -79,6 +112,14 @@ mod tests {
         assert_eq!(hash.len(), 20);
     }
 
+    #[test]
+    fn test_encode_roundtrip() {
+        let (version, hash) = validate("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2")
+            .unwrap()
+            .unwrap();
+        assert_eq!(encode(version, &hash), "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2");
+    }
+
     #[test]
     fn test_validate_invalid_length() {
         let input = "1"; // Too short
@@ -102,8 +143,8 @@ mod tests {
         let version = 0x00u8;
         let address_bytes = vec![0u8; 20];
         let payload = [&[version], address_bytes.as_slice()].concat();
-        let mut wrong_checksum = vec![0xFFu8; 4]; // Wrong checksum
-        let full_bytes = [payload, wrong_checksum].concat();
+        let wrong_checksum = [0xFFu8; 4]; // Wrong checksum
+        let full_bytes = [payload, wrong_checksum.to_vec()].concat();
         let invalid_addr = full_bytes.to_base58();
 
         let result = validate(&invalid_addr);
@@ -115,7 +156,7 @@ mod tests {
     fn test_validate_wrong_length_decoded() {
         // Create Base58 string that decodes to wrong length
         use base58::ToBase58;
-        let short_bytes = vec![0u8; 20]; // 20 bytes, not 25
+        let short_bytes = [0u8; 20]; // 20 bytes, not 25
         let base58_short = short_bytes.to_base58();
 
         let result = validate(&base58_short);
@@ -0,0 +1,84 @@
+//! CashAddr checksum (Bitcoin Cash)
+//!
+//! CashAddr uses a 40-bit BCH code over the prefix and the 5-bit payload,
+//! encoded as 8 trailing base32 characters.
+
+/// Generator constants of the CashAddr BCH code
+const GENERATORS: [u64; 5] = [
+    0x98f2bc8e61,
+    0x79b76d99e2,
+    0xf33e5fb3c4,
+    0xae2eabe2a8,
+    0x1e4f43e470,
+];
+
+/// Compute the CashAddr polymod over 5-bit values
+pub fn polymod(values: &[u8]) -> u64 {
+    let mut c: u64 = 1;
+    for &d in values {
+        let c0 = (c >> 35) as u8;
+        c = ((c & 0x07_ffff_ffff) << 5) ^ u64::from(d);
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if c0 & (1 << i) != 0 {
+                c ^= generator;
+            }
+        }
+    }
+    c ^ 1
+}
+
+/// Expand the prefix into the checksum input: lower 5 bits of each char, then a zero separator
+fn expand_prefix(prefix: &str) -> Vec<u8> {
+    prefix
+        .bytes()
+        .map(|b| b & 0x1f)
+        .chain(std::iter::once(0))
+        .collect()
+}
+
+/// Calculate the 8 checksum values (5 bits each) for a prefix and payload
+pub fn calculate(prefix: &str, payload: &[u8]) -> Vec<u8> {
+    let mut values = expand_prefix(prefix);
+    values.extend_from_slice(payload);
+    values.extend_from_slice(&[0u8; 8]);
+    let checksum = polymod(&values);
+    (0..8)
+        .map(|i| ((checksum >> (5 * (7 - i))) & 0x1f) as u8)
+        .collect()
+}
+
+/// Validate the checksum of a payload that includes its 8 trailing checksum values
+pub fn validate(prefix: &str, payload_with_checksum: &[u8]) -> bool {
+    let mut values = expand_prefix(prefix);
+    values.extend_from_slice(payload_with_checksum);
+    polymod(&values) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_then_validate() {
+        let payload = vec![0u8; 34];
+        let checksum = calculate("bitcoincash", &payload);
+        assert_eq!(checksum.len(), 8);
+        let full = [payload, checksum].concat();
+        assert!(validate("bitcoincash", &full));
+    }
+
+    #[test]
+    fn test_validate_wrong_prefix() {
+        let payload = vec![1u8; 34];
+        let full = [payload.clone(), calculate("bitcoincash", &payload)].concat();
+        assert!(!validate("bchtest", &full));
+    }
+
+    #[test]
+    fn test_validate_corrupted_payload() {
+        let payload = vec![3u8; 34];
+        let mut full = [payload.clone(), calculate("bitcoincash", &payload)].concat();
+        full[5] ^= 1;
+        assert!(!validate("bitcoincash", &full));
+    }
+}
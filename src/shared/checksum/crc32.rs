@@ -0,0 +1,41 @@
+//! CRC-32 checksum (IEEE 802.3, as in zlib)
+
+/// Reflected CRC-32 polynomial
+const POLYNOMIAL: u32 = 0xedb8_8320;
+
+/// Calculate the CRC-32 of `data`
+pub fn calculate(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, &byte| {
+        (0..8).fold(crc ^ u32::from(byte), |crc, _| {
+            if crc & 1 != 0 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+/// Validate a CRC-32 checksum
+///
+/// Returns true if checksum is valid
+pub fn validate(data: &[u8], checksum: u32) -> bool {
+    calculate(data) == checksum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_value() {
+        assert_eq!(calculate(b""), 0);
+        assert_eq!(calculate(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(b"123456789", 0xcbf4_3926));
+        assert!(!validate(b"123456780", 0xcbf4_3926));
+    }
+}
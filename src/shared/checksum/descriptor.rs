@@ -0,0 +1,100 @@
+//! Output descriptor checksum (BIP-380)
+//!
+//! A 40-bit BCH code over the descriptor string, appended as `#` followed by
+//! 8 characters of the Bech32 alphabet.
+
+/// Characters allowed in a descriptor, ordered so that common ones share a group
+const INPUT_CHARSET: &str =
+    "0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+
+/// Alphabet of the checksum characters (same as Bech32)
+const CHECKSUM_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Generator constants of the descriptor BCH code
+const GENERATORS: [u64; 5] = [
+    0xf5dee51989,
+    0xa9fdca3312,
+    0x1bab10e32d,
+    0x3706b1677a,
+    0x644d626ffd,
+];
+
+/// Compute the descriptor polymod over 5-bit symbols
+fn polymod(symbols: &[u64]) -> u64 {
+    let mut chk: u64 = 1;
+    for &value in symbols {
+        let top = chk >> 35;
+        chk = ((chk & 0x07_ffff_ffff) << 5) ^ value;
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if (top >> i) & 1 != 0 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+/// Expand a descriptor into symbols: the low 5 bits of each character, plus
+/// the high bits of every group of three characters as an extra symbol
+fn expand(descriptor: &str) -> Option<Vec<u64>> {
+    let mut symbols = Vec::with_capacity(descriptor.len() * 4 / 3 + 1);
+    let mut groups = Vec::with_capacity(3);
+    for c in descriptor.chars() {
+        let value = INPUT_CHARSET.find(c)? as u64;
+        symbols.push(value & 31);
+        groups.push(value >> 5);
+        if groups.len() == 3 {
+            symbols.push(groups[0] * 9 + groups[1] * 3 + groups[2]);
+            groups.clear();
+        }
+    }
+    match groups.as_slice() {
+        [g0] => symbols.push(*g0),
+        [g0, g1] => symbols.push(g0 * 3 + g1),
+        _ => {}
+    }
+    Some(symbols)
+}
+
+/// Calculate the 8-character checksum of a descriptor (without `#`)
+///
+/// Returns `None` if the descriptor contains characters outside the BIP-380 charset.
+pub fn calculate(descriptor: &str) -> Option<String> {
+    let mut symbols = expand(descriptor)?;
+    symbols.extend_from_slice(&[0; 8]);
+    let checksum = polymod(&symbols) ^ 1;
+    Some(
+        (0..8)
+            .map(|i| CHECKSUM_CHARSET[((checksum >> (5 * (7 - i))) & 31) as usize] as char)
+            .collect(),
+    )
+}
+
+/// Validate a descriptor checksum
+pub fn validate(descriptor: &str, checksum: &str) -> bool {
+    calculate(descriptor).is_some_and(|expected| expected == checksum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_bip380_vector() {
+        assert_eq!(calculate("raw(deadbeef)").as_deref(), Some("89f8spxm"));
+        assert!(validate("raw(deadbeef)", "89f8spxm"));
+    }
+
+    #[test]
+    fn test_validate_rejects_wrong_checksum() {
+        assert!(!validate("raw(deadbeef)", "89f8spxn"));
+        assert!(!validate("raw(deadbeef)", "89f8spx"));
+        // Descriptor characters are case sensitive
+        assert!(!validate("raw(DEADBEEF)", "89f8spxm"));
+    }
+
+    #[test]
+    fn test_calculate_rejects_invalid_characters() {
+        assert_eq!(calculate("raw(deadbeef)é"), None);
+    }
+}
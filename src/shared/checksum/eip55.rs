@@ -1,8 +1,9 @@
 //! EIP-55 checksum validation and normalization
 //!
 //! EIP-55 specifies that addresses should use mixed-case checksumming:
-//! - If the i-th character is a letter, it should be uppercase if the i-th bit
-//!   of the hash of the lowercase address is 1, lowercase otherwise.
+//! - If the i-th character is a letter, it should be uppercase if the i-th
+//!   nibble of the Keccak-256 hash of the lowercase hex (without `0x`) is 8 or
+//!   more, lowercase otherwise.
 
 use crate::shared::crypto::hash::keccak256;
 use crate::shared::encoding::hex::decode;
@@ -18,11 +19,10 @@ pub fn validate(address: &str) -> bool {
     }
 
     // Compute checksum hash
-    let lowercase = address.to_lowercase();
-    let hash = keccak256(lowercase.as_bytes());
+    let hex_part = &address[2..]; // Skip "0x"
+    let hash = keccak256(hex_part.to_lowercase().as_bytes());
 
     // Check each character
-    let hex_part = &address[2..]; // Skip "0x"
     for (i, char) in hex_part.chars().enumerate() {
         if char.is_alphabetic() {
             let byte_index = i / 2;
@@ -56,8 +56,8 @@ pub fn normalize(address: &str) -> Result<String, Error> {
         return Err(Error::InvalidInput("Address must be 20 bytes".to_string()));
     }
 
-    // Compute checksum
-    let hash = keccak256(lowercase.as_bytes());
+    // Compute checksum over the hex digits only
+    let hash = keccak256(hex_part.as_bytes());
     let mut normalized = String::from("0x");
 
     for (i, char) in hex_part.chars().enumerate() {
@@ -100,6 +100,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_normalize_eip55_vectors() {
+        // Test vectors from EIP-55
+        for address in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+            "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+        ] {
+            assert_eq!(normalize(&address.to_lowercase()).unwrap(), address);
+            assert!(validate(address));
+        }
+    }
+
     #[test]
     fn test_validate_lowercase_not_checksummed() {
         let address = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
@@ -2,5 +2,8 @@
 
 pub mod base58check;
 pub mod bech32;
+pub mod cashaddr;
+pub mod crc32;
+pub mod descriptor;
 pub mod eip55;
 pub mod ss58;
@@ -1,4 +1,29 @@
 //! Ed25519 cryptographic utilities
 
-// Placeholder for future Ed25519-specific helpers
-// Currently, Ed25519 operations are handled directly in modules that need them
+use ed25519_dalek::SigningKey;
+
+/// Compute the public key of a 32-byte Ed25519 seed (RFC 8032)
+///
+/// The expanded signing key is zeroized when dropped.
+pub fn public_key_from_seed(seed: &[u8; 32]) -> [u8; 32] {
+    SigningKey::from_bytes(seed).verifying_key().to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_public_key_from_seed_rfc8032() {
+        // RFC 8032 section 7.1, test 1
+        let seed: [u8; 32] =
+            ::hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        assert_eq!(
+            ::hex::encode(public_key_from_seed(&seed)),
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        );
+    }
+}
@@ -1,8 +1,10 @@
 //! Hash functions (SHA256, Keccak, RIPEMD160, Blake2b)
 
-use blake2::{Blake2b512, Digest as Blake2Digest};
+use blake2::digest::consts::{U28, U32};
+use blake2::{Blake2b, Digest as Blake2Digest};
+use hmac::{Hmac, Mac};
 use ripemd::Ripemd160;
-use sha2::Sha256;
+use sha2::{Sha256, Sha512};
 use tiny_keccak::{Hasher, Keccak};
 
 /// Compute SHA256 hash
@@ -30,19 +32,46 @@ pub fn hash160(data: &[u8]) -> [u8; 20] {
     Ripemd160::digest(sha256_hash).into()
 }
 
-/// Compute Blake2b-512 hash and return first 32 bytes
-/// Used for Substrate secp256k1 account ID derivation
+/// Compute HMAC-SHA512
+pub fn hmac_sha512(key: &[u8], data: &[u8]) -> [u8; 64] {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
+
+/// Compute a BIP-340 tagged hash: SHA256(SHA256(tag) || SHA256(tag) || data)
+pub fn tagged_hash(tag: &str, data: &[u8]) -> [u8; 32] {
+    let tag_hash = sha256(tag.as_bytes());
+    sha256(&[&tag_hash[..], &tag_hash[..], data].concat())
+}
+
+/// Compute Blake2b-256 hash (Blake2b with a 32-byte digest, Substrate's `blake2_256`)
+/// Used for Substrate secp256k1, multisig and pure proxy account IDs
 pub fn blake2b_256(data: &[u8]) -> [u8; 32] {
-    let hash = Blake2b512::digest(data);
-    let mut result = [0u8; 32];
-    result.copy_from_slice(&hash[..32]);
-    result
+    Blake2b::<U32>::digest(data).into()
+}
+
+/// Compute Blake2b-224 hash (Blake2b with a 28-byte digest)
+/// Used for Cardano key hash credentials
+pub fn blake2b_224(data: &[u8]) -> [u8; 28] {
+    Blake2b::<U28>::digest(data).into()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_hmac_sha512_rfc4231() {
+        // RFC 4231 test case 1
+        let mac = hmac_sha512(&[0x0b; 20], b"Hi There");
+        assert_eq!(
+            ::hex::encode(mac),
+            "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cde\
+             daa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854"
+        );
+    }
+
     #[test]
     fn test_sha256() {
         let data = b"hello world";
@@ -147,6 +176,27 @@ mod tests {
         assert_eq!(hash, hash2);
     }
 
+    #[test]
+    fn test_blake2b_256_known_answer() {
+        assert_eq!(
+            hex::encode(blake2b_256(b"abc")),
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+        );
+        // A 32-byte digest, not the first half of Blake2b-512 (786a02f7...)
+        assert_eq!(
+            hex::encode(blake2b_256(b"")),
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        );
+    }
+
+    #[test]
+    fn test_blake2b_224_known_answer() {
+        assert_eq!(
+            hex::encode(blake2b_224(b"abc")),
+            "9bd237b02a29e43bdd6738afa5b53ff0eee178d6210b618e4511aec8"
+        );
+    }
+
     #[test]
     fn test_blake2b_256_empty() {
         let data = b"";
@@ -3,3 +3,4 @@
 pub mod ed25519;
 pub mod hash;
 pub mod secp256k1;
+pub mod sr25519;
@@ -1,7 +1,10 @@
 //! secp256k1 cryptographic utilities
 
+use crate::shared::crypto::hash::tagged_hash;
 use crate::Error;
-use secp256k1::PublicKey;
+use secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
+use secp256k1::{Message, PublicKey, Scalar, Secp256k1, SecretKey, XOnlyPublicKey};
+use zeroize::Zeroizing;
 
 /// Decompress a compressed secp256k1 public key
 ///
@@ -43,10 +46,191 @@ pub fn decompress_public_key(compressed_key: &[u8]) -> Result<Vec<u8>, Error> {
     Ok(uncompressed.to_vec())
 }
 
+/// Compress a secp256k1 public key
+///
+/// Accepts a 33-byte compressed, 65-byte uncompressed or raw 64-byte key and
+/// returns the 33-byte compressed serialization.
+pub fn compress_public_key(public_key: &[u8]) -> Result<[u8; 33], Error> {
+    let serialized = match public_key.len() {
+        64 => [&[0x04], public_key].concat(),
+        _ => public_key.to_vec(),
+    };
+    PublicKey::from_slice(&serialized)
+        .map(|key| key.serialize())
+        .map_err(|e| Error::InvalidInput(format!("Invalid secp256k1 public key: {}", e)))
+}
+
+/// Serialize a public key the way it is hashed into Bitcoin-family addresses
+///
+/// Compressed (33-byte) and uncompressed (65-byte) keys keep their form; a raw
+/// 64-byte key is treated as uncompressed and gets the 0x04 prefix.
+pub fn serialize_public_key(public_key: &[u8]) -> Result<Vec<u8>, Error> {
+    match public_key.len() {
+        33 => {
+            // Reject keys that are not on the curve
+            decompress_public_key(public_key)?;
+            Ok(public_key.to_vec())
+        }
+        65 if public_key[0] == 0x04 => Ok(public_key.to_vec()),
+        64 => Ok([&[0x04], public_key].concat()),
+        _ => Err(Error::InvalidInput(format!(
+            "Invalid secp256k1 key length: {} bytes",
+            public_key.len()
+        ))),
+    }
+}
+
+/// Check that bytes are a serialized secp256k1 point (33-byte compressed or 65-byte uncompressed)
+pub fn is_valid_public_key(key: &[u8]) -> bool {
+    matches!(key.len(), 33 | 65) && PublicKey::from_slice(key).is_ok()
+}
+
+/// Add `tweak * G` to a public key, returning the compressed result (BIP-32 public derivation)
+pub fn tweak_add_public_key(public_key: &[u8], tweak: &[u8; 32]) -> Result<[u8; 33], Error> {
+    let key = PublicKey::from_slice(public_key)
+        .map_err(|e| Error::InvalidInput(format!("Invalid public key: {}", e)))?;
+    let scalar = Scalar::from_be_bytes(*tweak)
+        .map_err(|_| Error::InvalidInput("Tweak is not a valid scalar".to_string()))?;
+    let tweaked = key
+        .add_exp_tweak(&Secp256k1::verification_only(), &scalar)
+        .map_err(|e| Error::InvalidInput(format!("Invalid tweaked key: {}", e)))?;
+    Ok(tweaked.serialize())
+}
+
+/// Compute the public key of a 32-byte secret scalar
+///
+/// Returns the 33-byte compressed or 65-byte uncompressed serialization. The
+/// parsed secret is erased before returning.
+pub fn public_key_from_secret(secret: &[u8], compressed: bool) -> Result<Vec<u8>, Error> {
+    let mut secret_key = SecretKey::from_slice(secret)
+        .map_err(|_| Error::InvalidInput("Not a valid secp256k1 secret key".to_string()))?;
+    let public_key = PublicKey::from_secret_key(&Secp256k1::signing_only(), &secret_key);
+    secret_key.non_secure_erase();
+    Ok(if compressed {
+        public_key.serialize().to_vec()
+    } else {
+        public_key.serialize_uncompressed().to_vec()
+    })
+}
+
+/// Add a tweak to a secret scalar modulo the group order (BIP-32 private derivation)
+///
+/// The child secret is returned in a [`Zeroizing`] buffer and the
+/// intermediate keys are erased before returning.
+pub fn tweak_add_secret_key(secret: &[u8], tweak: &[u8; 32]) -> Result<Zeroizing<[u8; 32]>, Error> {
+    let mut secret_key = SecretKey::from_slice(secret)
+        .map_err(|_| Error::InvalidInput("Not a valid secp256k1 secret key".to_string()))?;
+    let scalar = Scalar::from_be_bytes(*tweak)
+        .map_err(|_| Error::InvalidInput("Tweak is not a valid scalar".to_string()))?;
+    let mut tweaked = secret_key
+        .add_tweak(&scalar)
+        .map_err(|_| Error::InvalidInput("Invalid tweaked secret key".to_string()))?;
+    let bytes = Zeroizing::new(tweaked.secret_bytes());
+    secret_key.non_secure_erase();
+    tweaked.non_secure_erase();
+    Ok(bytes)
+}
+
+/// Compute the BIP-341 taproot output key for an x-only internal key without a script tree
+///
+/// The internal key is tweaked by `tagged_hash("TapTweak", internal_key)` (BIP-86).
+pub fn taproot_output_key(internal_key: &[u8]) -> Result<[u8; 32], Error> {
+    let internal = XOnlyPublicKey::from_slice(internal_key)
+        .map_err(|e| Error::InvalidInput(format!("Invalid x-only public key: {}", e)))?;
+    let tweak = Scalar::from_be_bytes(tagged_hash("TapTweak", &internal.serialize()))
+        .map_err(|e| Error::InvalidInput(format!("Invalid taproot tweak: {}", e)))?;
+    let (output, _parity) = internal
+        .add_tweak(&Secp256k1::verification_only(), &tweak)
+        .map_err(|e| Error::InvalidInput(format!("Taproot tweak failed: {}", e)))?;
+    Ok(output.serialize())
+}
+
+/// Recover the public key that produced an ECDSA signature (`ecrecover`)
+///
+/// `signature` is `r || s` and `recovery_id` the parity of the nonce point
+/// (0 or 1; 2 and 3 are only valid for astronomically rare `r` values).
+/// Returns the 65-byte uncompressed public key.
+pub fn recover_public_key(
+    message_hash: &[u8; 32],
+    signature: &[u8; 64],
+    recovery_id: u8,
+) -> Result<[u8; 65], Error> {
+    let recovery_id = RecoveryId::from_i32(recovery_id as i32)
+        .map_err(|_| Error::InvalidInput(format!("Invalid recovery id: {}", recovery_id)))?;
+    let signature = RecoverableSignature::from_compact(signature, recovery_id)
+        .map_err(|e| Error::InvalidInput(format!("Invalid ECDSA signature: {}", e)))?;
+    let public_key = Secp256k1::verification_only()
+        .recover_ecdsa(&Message::from_digest(*message_hash), &signature)
+        .map_err(|e| Error::InvalidInput(format!("Public key recovery failed: {}", e)))?;
+    Ok(public_key.serialize_uncompressed())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_valid_public_key() {
+        use crate::shared::encoding::hex;
+        let compressed =
+            hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+                .unwrap();
+        assert!(is_valid_public_key(&compressed));
+        assert!(is_valid_public_key(
+            &decompress_public_key(&compressed).unwrap()
+        ));
+        assert!(!is_valid_public_key(&compressed[1..]));
+        assert!(!is_valid_public_key(&[&[0x02u8][..], &[0xFF; 32]].concat()));
+    }
+
+    #[test]
+    fn test_public_key_from_secret() {
+        let mut secret = [0u8; 32];
+        secret[31] = 1;
+        assert_eq!(
+            ::hex::encode(public_key_from_secret(&secret, true).unwrap()),
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        );
+        assert_eq!(public_key_from_secret(&secret, false).unwrap().len(), 65);
+        // Zero and the group order are not valid scalars
+        assert!(public_key_from_secret(&[0u8; 32], true).is_err());
+        assert!(public_key_from_secret(&[0xff; 32], true).is_err());
+    }
+
+    #[test]
+    fn test_tweak_add_secret_key() {
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        let mut two = [0u8; 32];
+        two[31] = 2;
+        assert_eq!(*tweak_add_secret_key(&one, &one).unwrap(), two);
+        assert!(tweak_add_secret_key(&[0u8; 32], &one).is_err());
+    }
+
+    #[test]
+    fn test_taproot_output_key_bip86() {
+        use crate::shared::encoding::hex;
+        // BIP-86 test vector: first receiving address of account 0
+        let internal =
+            hex::decode("cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115")
+                .unwrap();
+        assert_eq!(
+            ::hex::encode(taproot_output_key(&internal).unwrap()),
+            "a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c"
+        );
+        assert!(taproot_output_key(&internal[1..]).is_err());
+    }
+
+    #[test]
+    fn test_compress_public_key() {
+        let compressed = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let uncompressed = decompress_public_key(&::hex::decode(compressed).unwrap()).unwrap();
+        for key in [&uncompressed[..], &uncompressed[1..]] {
+            assert_eq!(::hex::encode(compress_public_key(key).unwrap()), compressed);
+        }
+        assert!(compress_public_key(&[0x05; 33]).is_err());
+    }
+
     #[test]
     fn test_decompress_public_key_0x02() {
         // Test with 0x02 prefix (even y coordinate)
@@ -149,4 +333,33 @@ mod tests {
             .to_string()
             .contains("Invalid compressed public key"));
     }
+
+    #[test]
+    fn test_recover_public_key() {
+        // EIP-155 example transaction, signed by key 0x4646...46
+        let hash: [u8; 32] =
+            ::hex::decode("daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let signature: [u8; 64] = ::hex::decode(
+            "28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276\
+             67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        let public_key = recover_public_key(&hash, &signature, 0).unwrap();
+        assert_eq!(
+            ::hex::encode(public_key),
+            "044bc2a31265153f07e70e0bab08724e6b85e217f8cd628ceb62974247bb493382\
+             ce28cab79ad7119ee1ad3ebcdb98a16805211530ecc6cfefa1b88e6dff99232a"
+        );
+        // The other parity recovers a different key
+        assert_ne!(
+            recover_public_key(&hash, &signature, 1).unwrap(),
+            public_key
+        );
+        assert!(recover_public_key(&hash, &signature, 4).is_err());
+    }
 }
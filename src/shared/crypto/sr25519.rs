@@ -0,0 +1,35 @@
+//! Sr25519 (Schnorrkel) cryptographic utilities
+
+use crate::Error;
+use schnorrkel::{ExpansionMode, MiniSecretKey};
+
+/// Compute the public key of a 32-byte Sr25519 mini secret key
+///
+/// Uses the Ed25519 expansion mode, as Substrate does for seeds. The mini
+/// secret is zeroized when dropped.
+pub fn public_key_from_mini_secret(seed: &[u8; 32]) -> Result<[u8; 32], Error> {
+    let mini_secret = MiniSecretKey::from_bytes(seed)
+        .map_err(|e| Error::InvalidInput(format!("Invalid sr25519 mini secret: {}", e)))?;
+    Ok(mini_secret
+        .expand_to_public(ExpansionMode::Ed25519)
+        .to_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_public_key_from_mini_secret_alice() {
+        // Substrate development account //Alice
+        let seed: [u8; 32] =
+            ::hex::decode("e5be9a5092b81bca64be81d212e7f2f9eba183bb7a90954f7b76361f6edb5c0a")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        assert_eq!(
+            ::hex::encode(public_key_from_mini_secret(&seed).unwrap()),
+            "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+        );
+    }
+}
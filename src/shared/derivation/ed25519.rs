@@ -1,6 +1,6 @@
 //! Ed25519 public key derivation functions
 
-use crate::shared::crypto::hash::sha256;
+use crate::shared::crypto::hash::{blake2b_224, sha256};
 use crate::shared::encoding::bech32 as bech32_encoding;
 use crate::Error;
 use base58::ToBase58;
@@ -49,9 +49,8 @@ pub fn derive_cardano_address(key_bytes: &[u8], header: u8, hrp: &str) -> Result
         )));
     }
 
-    let hash = sha3_256(key_bytes);
-    let payload = &hash[..28];
-    create_cardano_address(payload, header, hrp)
+    let payload = blake2b_224(key_bytes);
+    create_cardano_address(&payload, header, hrp)
 }
 
 /// Derive SS58 address from Ed25519 public key
@@ -70,12 +69,6 @@ pub fn derive_ss58_address(key_bytes: &[u8], prefix: u16) -> Result<String, Erro
         .map_err(|e| Error::InvalidInput(format!("SS58 encoding error: {}", e)))
 }
 
-/// Compute SHA3-256 hash
-fn sha3_256(data: &[u8]) -> [u8; 32] {
-    use sha3::{Digest, Sha3_256};
-    Sha3_256::digest(data).into()
-}
-
 /// Create a Cardano address
 fn create_cardano_address(
     payload: &[u8],
@@ -1,18 +1,212 @@
 //! Public key decoding utilities
 //!
 //! This module provides utilities for decoding public keys from various encodings.
+//! Besides the raw hex/Base58/Bech32 forms it unwraps the containers keys are
+//! exported in: Cosmos JSON (`{"@type": ..., "key": ...}` and legacy amino
+//! `{"type": ..., "value": ...}`), amino Bech32 (`cosmospub1...`), PEM and DER
+//! SubjectPublicKeyInfo, and bare Base64.
 //! Address derivation is now handled by the pipeline system.
 
 use crate::input::DetectedKeyType;
+use crate::shared::encoding::{base64, bech32 as bech32_encoding, hex};
 use crate::Error;
 
+/// DER SubjectPublicKeyInfo headers (everything before the key bytes)
+const SPKI_ED25519: &str = "302a300506032b6570032100";
+const SPKI_SECP256K1_COMPRESSED: &str = "3036301006072a8648ce3d020106052b8104000a032200";
+const SPKI_SECP256K1_UNCOMPRESSED: &str = "3056301006072a8648ce3d020106052b8104000a034200";
+
+/// Amino prefixes (type prefix and length byte) of Cosmos public keys
+const AMINO_SECP256K1: [u8; 5] = [0xeb, 0x5a, 0xe9, 0x87, 0x21];
+const AMINO_ED25519: [u8; 5] = [0x16, 0x24, 0xde, 0x64, 0x20];
+
+/// Key type named by a Cosmos JSON or amino container
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CosmosKeyType {
+    /// Cosmos SDK `secp256k1` account key
+    Secp256k1,
+    /// Ethermint `eth_secp256k1` account key
+    EthSecp256k1,
+    /// Tendermint `ed25519` consensus key
+    Ed25519,
+}
+
+impl CosmosKeyType {
+    /// Address pipelines that derive addresses of this key type
+    ///
+    /// Ed25519 consensus keys give `valcons` addresses through both Cosmos
+    /// pipelines.
+    pub fn pipelines(self) -> &'static [&'static str] {
+        match self {
+            CosmosKeyType::Secp256k1 => &["cosmos"],
+            CosmosKeyType::EthSecp256k1 => &["ethermint"],
+            CosmosKeyType::Ed25519 => &["cosmos", "ethermint"],
+        }
+    }
+}
+
+/// A public key unwrapped from a container that names its curve
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrappedPublicKey {
+    /// Raw key bytes
+    pub bytes: Vec<u8>,
+    /// Curve declared by the container
+    pub key_type: DetectedKeyType,
+    /// Cosmos key type of JSON and amino containers; `None` for PEM and DER,
+    /// which only name a curve
+    pub cosmos_type: Option<CosmosKeyType>,
+    /// HRP of an amino Bech32 key, which names the chain and address role
+    pub hrp: Option<String>,
+}
+
+/// Unwrap a public key from Cosmos JSON, amino Bech32, PEM or DER (hex or Base64)
+///
+/// Returns `None` when the input is not one of these containers or the
+/// declared key does not have the expected length.
+pub fn decode_wrapped_public_key(input: &str) -> Option<WrappedPublicKey> {
+    let trimmed = input.trim();
+    if trimmed.starts_with('{') {
+        return decode_cosmos_json(trimmed);
+    }
+    if let Some(body) = trimmed
+        .strip_prefix("-----BEGIN PUBLIC KEY-----")
+        .and_then(|rest| rest.strip_suffix("-----END PUBLIC KEY-----"))
+    {
+        let base64_body: String = body.split_whitespace().collect();
+        return decode_spki(&base64::decode(&base64_body).ok()?);
+    }
+    if let Some(key) = decode_amino_bech32(trimmed) {
+        return Some(key);
+    }
+    let der = hex::decode(trimmed)
+        .ok()
+        .or_else(|| base64::decode(trimmed).ok())?;
+    decode_spki(&der)
+}
+
+/// Decode a bare Base64 public key, the fallback when no other encoding applies
+pub fn decode_base64_public_key(input: &str) -> Option<Vec<u8>> {
+    base64::decode(input.trim()).ok()
+}
+
+/// Cosmos protobuf JSON (`@type`/`key`) or legacy amino JSON (`type`/`value`)
+fn decode_cosmos_json(input: &str) -> Option<WrappedPublicKey> {
+    let value: serde_json::Value = serde_json::from_str(input).ok()?;
+    let (type_url, key) = match (value.get("@type"), value.get("type")) {
+        (Some(type_url), _) => (type_url.as_str()?, value.get("key")?.as_str()?),
+        (None, Some(type_name)) => (type_name.as_str()?, value.get("value")?.as_str()?),
+        (None, None) => return None,
+    };
+    let bytes = base64::decode(key).ok()?;
+    let cosmos_type = match type_url {
+        "/cosmos.crypto.secp256k1.PubKey" | "tendermint/PubKeySecp256k1" => {
+            CosmosKeyType::Secp256k1
+        }
+        "/ethermint.crypto.v1.ethsecp256k1.PubKey"
+        | "/injective.crypto.v1beta1.ethsecp256k1.PubKey"
+        | "ethermint/PubKeyEthSecp256k1" => CosmosKeyType::EthSecp256k1,
+        "/cosmos.crypto.ed25519.PubKey" | "tendermint/PubKeyEd25519" => CosmosKeyType::Ed25519,
+        _ => return None,
+    };
+    cosmos_wrapped(bytes, cosmos_type)
+}
+
+/// Amino-prefixed key in Bech32, e.g. `cosmospub1...` or `cosmosvalconspub1...`
+fn decode_amino_bech32(input: &str) -> Option<WrappedPublicKey> {
+    let (hrp, data, _) = bech32_encoding::decode(input).ok()?;
+    if !hrp.ends_with("pub") {
+        return None;
+    }
+    let u5_bytes: Vec<u8> = data.iter().map(|u5| u8::from(*u5)).collect();
+    let bytes = bech32_encoding::convert_bits(&u5_bytes, 5, 8, false).ok()?;
+    let key = if let Some(key) = bytes.strip_prefix(&AMINO_SECP256K1) {
+        cosmos_wrapped(key.to_vec(), CosmosKeyType::Secp256k1)
+    } else if let Some(key) = bytes.strip_prefix(&AMINO_ED25519) {
+        cosmos_wrapped(key.to_vec(), CosmosKeyType::Ed25519)
+    } else {
+        None
+    }?;
+    Some(WrappedPublicKey {
+        hrp: Some(hrp),
+        ..key
+    })
+}
+
+/// DER SubjectPublicKeyInfo for Ed25519 (RFC 8410) or secp256k1 (RFC 5480)
+fn decode_spki(der: &[u8]) -> Option<WrappedPublicKey> {
+    let encoded = ::hex::encode(der);
+    let (key, key_type) = if let Some(key) = encoded.strip_prefix(SPKI_ED25519) {
+        (key, DetectedKeyType::Ed25519)
+    } else if let Some(key) = encoded.strip_prefix(SPKI_SECP256K1_COMPRESSED) {
+        (key, DetectedKeyType::Secp256k1 { compressed: true })
+    } else if let Some(key) = encoded.strip_prefix(SPKI_SECP256K1_UNCOMPRESSED) {
+        (key, DetectedKeyType::Secp256k1 { compressed: false })
+    } else {
+        return None;
+    };
+    wrapped(::hex::decode(key).ok()?, key_type)
+}
+
+/// Keep an unwrapped key only if its length fits the declared curve
+fn wrapped(bytes: Vec<u8>, key_type: DetectedKeyType) -> Option<WrappedPublicKey> {
+    let valid = match key_type {
+        DetectedKeyType::Secp256k1 { compressed: true } => {
+            bytes.len() == 33 && matches!(bytes[0], 0x02 | 0x03)
+        }
+        DetectedKeyType::Secp256k1 { compressed: false } => bytes.len() == 65 && bytes[0] == 0x04,
+        DetectedKeyType::Ed25519 | DetectedKeyType::Sr25519 | DetectedKeyType::Ed25519OrSr25519 => {
+            bytes.len() == 32
+        }
+    };
+    valid.then_some(WrappedPublicKey {
+        bytes,
+        key_type,
+        cosmos_type: None,
+        hrp: None,
+    })
+}
+
+/// Keep a Cosmos key only if its length fits the curve of its type
+fn cosmos_wrapped(bytes: Vec<u8>, cosmos_type: CosmosKeyType) -> Option<WrappedPublicKey> {
+    let key_type = match cosmos_type {
+        CosmosKeyType::Secp256k1 | CosmosKeyType::EthSecp256k1 => DetectedKeyType::Secp256k1 {
+            compressed: bytes.len() == 33,
+        },
+        CosmosKeyType::Ed25519 => DetectedKeyType::Ed25519,
+    };
+    Some(WrappedPublicKey {
+        cosmos_type: Some(cosmos_type),
+        ..wrapped(bytes, key_type)?
+    })
+}
+
 /// Decode public key from input string based on encoding
 pub fn decode_public_key(
     input: &str,
     chars: &crate::input::InputCharacteristics,
     key_type: DetectedKeyType,
 ) -> Result<Vec<u8>, Error> {
-    use crate::shared::encoding::{base58, bech32 as bech32_encoding, hex};
+    use crate::shared::encoding::base58;
+
+    // Containers declare the curve; it must agree with the requested key type
+    if let Some(wrapped) = decode_wrapped_public_key(input) {
+        let same_curve = matches!(
+            (wrapped.key_type, key_type),
+            (
+                DetectedKeyType::Secp256k1 { .. },
+                DetectedKeyType::Secp256k1 { .. }
+            ) | (DetectedKeyType::Ed25519, DetectedKeyType::Ed25519)
+                | (DetectedKeyType::Sr25519, DetectedKeyType::Sr25519)
+        );
+        return if same_curve {
+            Ok(wrapped.bytes)
+        } else {
+            Err(Error::InvalidInput(format!(
+                "Public key is declared as {:?}, not {:?}",
+                wrapped.key_type, key_type
+            )))
+        };
+    }
 
     // Try all possible encodings to decode the input
     let mut bytes = None;
@@ -35,6 +229,9 @@ pub fn decode_public_key(
             }
             crate::registry::EncodingType::SS58 => base58::decode(input)
                 .map_err(|e| Error::InvalidInput(format!("Base58 decode error: {}", e))),
+            crate::registry::EncodingType::CashAddr => Err(Error::InvalidInput(
+                "CashAddr encodes a hash, not a public key".to_string(),
+            )),
         };
 
         match decoded {
@@ -48,11 +245,13 @@ pub fn decode_public_key(
         }
     }
 
-    let bytes = bytes.ok_or_else(|| {
-        last_error.unwrap_or_else(|| {
-            Error::InvalidInput("Unknown encoding type or decode failed".to_string())
-        })
-    })?;
+    let bytes = bytes
+        .or_else(|| decode_base64_public_key(input))
+        .ok_or_else(|| {
+            last_error.unwrap_or_else(|| {
+                Error::InvalidInput("Unknown encoding type or decode failed".to_string())
+            })
+        })?;
 
     // Validate key length matches key type
     match key_type {
@@ -64,7 +263,7 @@ pub fn decode_public_key(
                 )));
             }
         }
-        DetectedKeyType::Ed25519 | DetectedKeyType::Sr25519 => {
+        DetectedKeyType::Ed25519 | DetectedKeyType::Sr25519 | DetectedKeyType::Ed25519OrSr25519 => {
             if bytes.len() != 32 {
                 return Err(Error::InvalidInput(format!(
                     "Invalid Ed25519/sr25519 key length: {} bytes (expected 32)",
@@ -166,4 +365,136 @@ mod tests {
         let result = decode_public_key(input, &chars, key_type);
         assert!(result.is_err());
     }
+
+    // Key of secret 0x4646...46 and the RFC 8032 test 1 Ed25519 key
+    const SECP256K1_KEY: &str =
+        "024bc2a31265153f07e70e0bab08724e6b85e217f8cd628ceb62974247bb493382";
+    const ED25519_KEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+    fn unwrap(input: &str) -> (String, DetectedKeyType) {
+        let key = decode_wrapped_public_key(input).unwrap();
+        (::hex::encode(key.bytes), key.key_type)
+    }
+
+    #[test]
+    fn test_decode_cosmos_json() {
+        let secp256k1 = (
+            SECP256K1_KEY.to_string(),
+            DetectedKeyType::Secp256k1 { compressed: true },
+        );
+        assert_eq!(
+            unwrap(
+                r#"{"@type":"/cosmos.crypto.secp256k1.PubKey","key":"AkvCoxJlFT8H5w4LqwhyTmuF4hf4zWKM62KXQke7STOC"}"#
+            ),
+            secp256k1
+        );
+        assert_eq!(
+            unwrap(
+                r#"{"@type":"/ethermint.crypto.v1.ethsecp256k1.PubKey","key":"AkvCoxJlFT8H5w4LqwhyTmuF4hf4zWKM62KXQke7STOC"}"#
+            ),
+            secp256k1
+        );
+        assert_eq!(
+            unwrap(
+                r#"{"type": "tendermint/PubKeyEd25519", "value": "11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo="}"#
+            ),
+            (ED25519_KEY.to_string(), DetectedKeyType::Ed25519)
+        );
+        // Unknown type, wrong length for the declared curve
+        assert!(decode_wrapped_public_key(r#"{"@type":"/cosmos.crypto.multisig.LegacyAminoPubKey","key":"AkvCoxJlFT8H5w4LqwhyTmuF4hf4zWKM62KXQke7STOC"}"#).is_none());
+        assert!(decode_wrapped_public_key(r#"{"@type":"/cosmos.crypto.ed25519.PubKey","key":"AkvCoxJlFT8H5w4LqwhyTmuF4hf4zWKM62KXQke7STOC"}"#).is_none());
+    }
+
+    #[test]
+    fn test_decode_cosmos_key_type() {
+        let cosmos_type = |input: &str| decode_wrapped_public_key(input).unwrap().cosmos_type;
+        assert_eq!(
+            cosmos_type(
+                r#"{"@type":"/cosmos.crypto.secp256k1.PubKey","key":"AkvCoxJlFT8H5w4LqwhyTmuF4hf4zWKM62KXQke7STOC"}"#
+            ),
+            Some(CosmosKeyType::Secp256k1)
+        );
+        assert_eq!(
+            cosmos_type(
+                r#"{"@type":"/injective.crypto.v1beta1.ethsecp256k1.PubKey","key":"AkvCoxJlFT8H5w4LqwhyTmuF4hf4zWKM62KXQke7STOC"}"#
+            ),
+            Some(CosmosKeyType::EthSecp256k1)
+        );
+        assert_eq!(
+            cosmos_type("cosmosvalconspub1zcjduepq6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydq22knlp"),
+            Some(CosmosKeyType::Ed25519)
+        );
+        // PEM and DER only name a curve
+        assert_eq!(
+            cosmos_type(&format!("{}{}", SPKI_ED25519, ED25519_KEY)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_decode_amino_bech32() {
+        assert_eq!(
+            unwrap("cosmospub1addwnpepqf9u9gcjv52n7pl8pc96kzrjfe4ctcshlrxk9r8tv2t5y3amfyecyrxe3kt"),
+            (
+                SECP256K1_KEY.to_string(),
+                DetectedKeyType::Secp256k1 { compressed: true }
+            )
+        );
+        assert_eq!(
+            unwrap("cosmosvalconspub1zcjduepq6adfsqvzky9t042tlmfujeq88g8wzuhnm2nzxfd0qgdx3ac82ydq22knlp"),
+            (ED25519_KEY.to_string(), DetectedKeyType::Ed25519)
+        );
+        // Account addresses are not keys
+        assert!(
+            decode_wrapped_public_key("cosmos1y8lrrhap2j3xzcntlp2qgm7jyudhhm2tc7hkue").is_none()
+        );
+    }
+
+    #[test]
+    fn test_decode_spki() {
+        let pem = "-----BEGIN PUBLIC KEY-----\nMCowBQYDK2VwAyEA11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo=\n-----END PUBLIC KEY-----\n";
+        assert_eq!(
+            unwrap(pem),
+            (ED25519_KEY.to_string(), DetectedKeyType::Ed25519)
+        );
+
+        let pem = "-----BEGIN PUBLIC KEY-----\nMFYwEAYHKoZIzj0CAQYFK4EEAAoDQgAES8KjEmUVPwfnDgurCHJOa4XiF/jNYozr\nYpdCR7tJM4LOKMq3mtcRnuGtPrzbmKFoBSEVMOzGz++huI5t/5kjKg==\n-----END PUBLIC KEY-----";
+        let (key, key_type) = unwrap(pem);
+        assert!(key.starts_with("044bc2a3"));
+        assert_eq!(key_type, DetectedKeyType::Secp256k1 { compressed: false });
+
+        // Bare DER, Base64 and hex
+        assert_eq!(
+            unwrap("MDYwEAYHKoZIzj0CAQYFK4EEAAoDIgACS8KjEmUVPwfnDgurCHJOa4XiF/jNYozrYpdCR7tJM4I=")
+                .0,
+            SECP256K1_KEY
+        );
+        assert_eq!(
+            unwrap(&format!("{}{}", SPKI_ED25519, ED25519_KEY)).0,
+            ED25519_KEY
+        );
+        // P-256 keys are not supported
+        assert!(decode_wrapped_public_key("MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAE").is_none());
+    }
+
+    #[test]
+    fn test_decode_public_key_containers() {
+        let input = r#"{"@type":"/cosmos.crypto.secp256k1.PubKey","key":"AkvCoxJlFT8H5w4LqwhyTmuF4hf4zWKM62KXQke7STOC"}"#;
+        let chars = extract_characteristics(input);
+        let bytes = decode_public_key(
+            input,
+            &chars,
+            DetectedKeyType::Secp256k1 { compressed: true },
+        )
+        .unwrap();
+        assert_eq!(::hex::encode(bytes), SECP256K1_KEY);
+        // The declared curve wins over the requested one
+        assert!(decode_public_key(input, &chars, DetectedKeyType::Ed25519).is_err());
+
+        // Bare Base64
+        let input = "11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo=";
+        let chars = extract_characteristics(input);
+        let bytes = decode_public_key(input, &chars, DetectedKeyType::Ed25519).unwrap();
+        assert_eq!(::hex::encode(bytes), ED25519_KEY);
+    }
 }
@@ -0,0 +1,64 @@
+//! Base64 encoding utilities (RFC 4648 standard alphabet)
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Decode a standard Base64 string; padding is optional
+pub fn decode(input: &str) -> Result<Vec<u8>, String> {
+    let trimmed = input.trim_end_matches('=');
+    if input.len() - trimmed.len() > 2 || trimmed.len() % 4 == 1 {
+        return Err("Invalid Base64 length".to_string());
+    }
+    if !input.len().is_multiple_of(4) && input.len() != trimmed.len() {
+        return Err("Invalid Base64 padding".to_string());
+    }
+
+    let mut bytes = Vec::with_capacity(trimmed.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in trimmed.bytes() {
+        let value = ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .ok_or_else(|| format!("Invalid Base64 character: {}", c as char))?;
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    // Leftover bits must be zero in a canonical encoding
+    if buffer & ((1 << bits) - 1) != 0 {
+        return Err("Invalid Base64 trailing bits".to_string());
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc4648_vectors() {
+        for (plain, encoded) in [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(decode(encoded).unwrap(), plain.as_bytes());
+        }
+        assert_eq!(decode("Zm8").unwrap(), b"fo");
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert!(decode("Zm9v!").is_err());
+        assert!(decode("Z").is_err());
+        assert!(decode("Zh==").is_err()); // non-zero trailing bits
+        assert!(decode("Zm9v===").is_err());
+    }
+}
@@ -12,6 +12,66 @@ pub fn encode(hrp: &str, data: &[u5], variant: Variant) -> Result<String, String
     bech32::encode(hrp, data, variant).map_err(|e| format!("Bech32 encode error: {}", e))
 }
 
+/// Decode a segwit address into (HRP, witness version, witness program)
+///
+/// Enforces BIP-173/BIP-350 rules: version 0 must use Bech32 with a 20 or
+/// 32-byte program, versions 1-16 must use Bech32m with a 2-40 byte program.
+pub fn decode_segwit(input: &str) -> Result<(String, u8, Vec<u8>), String> {
+    let (hrp, data, variant) = decode(input)?;
+    let (version, program_u5) = data
+        .split_first()
+        .ok_or_else(|| "Missing witness version".to_string())?;
+    let version = version.to_u8();
+    if version > 16 {
+        return Err(format!("Invalid witness version: {}", version));
+    }
+
+    let program_bytes: Vec<u8> = program_u5.iter().map(|u| u.to_u8()).collect();
+    let program = convert_bits(&program_bytes, 5, 8, false)?;
+
+    let expected_variant = if version == 0 {
+        Variant::Bech32
+    } else {
+        Variant::Bech32m
+    };
+    if variant != expected_variant {
+        return Err(format!(
+            "Witness version {} must use {:?} encoding",
+            version, expected_variant
+        ));
+    }
+    let valid_length = if version == 0 {
+        program.len() == 20 || program.len() == 32
+    } else {
+        (2..=40).contains(&program.len())
+    };
+    if !valid_length {
+        return Err(format!(
+            "Invalid witness program length: {} bytes",
+            program.len()
+        ));
+    }
+
+    Ok((hrp, version, program))
+}
+
+/// Encode a segwit address from (HRP, witness version, witness program)
+///
+/// Version 0 uses Bech32, versions 1-16 use Bech32m (BIP-350).
+pub fn encode_segwit(hrp: &str, version: u8, program: &[u8]) -> Result<String, String> {
+    if version > 16 {
+        return Err(format!("Invalid witness version: {}", version));
+    }
+    let variant = if version == 0 {
+        Variant::Bech32
+    } else {
+        Variant::Bech32m
+    };
+    let mut data = vec![u5::try_from_u8(version).map_err(|e| e.to_string())?];
+    data.extend(bytes_to_u5(&convert_bits(program, 8, 5, true)?));
+    encode(hrp, &data, variant)
+}
+
 /// Convert bits from one base to another
 pub fn convert_bits(
     data: &[u8],
@@ -73,6 +133,55 @@ mod tests {
         assert!(encoded.starts_with("bc1"));
     }
 
+    #[test]
+    fn test_decode_segwit_v0() {
+        let (hrp, version, program) =
+            decode_segwit("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").unwrap();
+        assert_eq!(hrp, "bc");
+        assert_eq!(version, 0);
+        assert_eq!(program.len(), 20);
+    }
+
+    #[test]
+    fn test_decode_segwit_taproot() {
+        let (hrp, version, program) =
+            decode_segwit("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0")
+                .unwrap();
+        assert_eq!(hrp, "bc");
+        assert_eq!(version, 1);
+        assert_eq!(program.len(), 32);
+    }
+
+    #[test]
+    fn test_decode_segwit_rejects_wrong_variant() {
+        // Version 1 program encoded with Bech32 instead of Bech32m (BIP-350 invalid vector)
+        let result = decode_segwit(
+            "bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7k7grplx",
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decode_segwit_rejects_non_segwit_payload() {
+        // Cosmos-style payload: first u5 is not a witness version followed by a valid program
+        let data = vec![0u8; 20];
+        let data_u5 = data.to_base32();
+        let address = encode("cosmos", &data_u5, Variant::Bech32).unwrap();
+        assert!(decode_segwit(&address).is_err());
+    }
+
+    #[test]
+    fn test_encode_segwit_roundtrip() {
+        for address in [
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+        ] {
+            let (hrp, version, program) = decode_segwit(address).unwrap();
+            assert_eq!(encode_segwit(&hrp, version, &program).unwrap(), address);
+        }
+        assert!(encode_segwit("bc", 17, &[0u8; 32]).is_err());
+    }
+
     #[test]
     fn test_convert_bits_5_to_8() {
         // Convert 5-bit groups to 8-bit bytes
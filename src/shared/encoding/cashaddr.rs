@@ -0,0 +1,181 @@
+//! CashAddr encoding utilities (Bitcoin Cash)
+//!
+//! Format: `prefix:payload`, where the prefix (e.g. "bitcoincash") is optional
+//! when written and the payload is base32 over a version byte, the hash and an
+//! 8-character checksum. The version byte carries the address type (bits 3-6)
+//! and the hash size (bits 0-2).
+
+use crate::shared::checksum::cashaddr as cashaddr_checksum;
+use crate::shared::encoding::bech32::convert_bits;
+
+/// Base32 alphabet shared with Bech32
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Address type: pay-to-public-key-hash
+pub const TYPE_P2PKH: u8 = 0;
+/// Address type: pay-to-script-hash
+pub const TYPE_P2SH: u8 = 1;
+
+/// Hash sizes in bytes, indexed by the size bits of the version byte
+const HASH_SIZES: [usize; 8] = [20, 24, 28, 32, 40, 48, 56, 64];
+
+/// Decode a CashAddr string into (prefix, address type, hash)
+///
+/// When the input carries no prefix, each of `default_prefixes` is tried
+/// against the checksum and the first match is returned.
+pub fn decode(input: &str, default_prefixes: &[String]) -> Result<(String, u8, Vec<u8>), String> {
+    if input.chars().any(|c| c.is_ascii_lowercase())
+        && input.chars().any(|c| c.is_ascii_uppercase())
+    {
+        return Err("CashAddr must not use mixed case".to_string());
+    }
+    let lower = input.to_lowercase();
+
+    let (prefixes, payload) = match lower.rsplit_once(':') {
+        Some((prefix, payload)) => {
+            if prefix.is_empty() {
+                return Err("Empty CashAddr prefix".to_string());
+            }
+            (vec![prefix.to_string()], payload)
+        }
+        None => (default_prefixes.to_vec(), lower.as_str()),
+    };
+
+    let values = payload
+        .bytes()
+        .map(|b| {
+            CHARSET
+                .iter()
+                .position(|&c| c == b)
+                .map(|p| p as u8)
+                .ok_or_else(|| format!("Invalid CashAddr character: {}", b as char))
+        })
+        .collect::<Result<Vec<u8>, String>>()?;
+    if values.len() <= 8 {
+        return Err("CashAddr payload too short".to_string());
+    }
+
+    let prefix = prefixes
+        .into_iter()
+        .find(|prefix| cashaddr_checksum::validate(prefix, &values))
+        .ok_or_else(|| "Invalid CashAddr checksum".to_string())?;
+
+    let data = convert_bits(&values[..values.len() - 8], 5, 8, false)?;
+    let (&version, hash) = data
+        .split_first()
+        .ok_or_else(|| "Missing CashAddr version byte".to_string())?;
+    if version & 0x80 != 0 {
+        return Err("Reserved CashAddr version bit set".to_string());
+    }
+    let expected_size = HASH_SIZES[(version & 0x07) as usize];
+    if hash.len() != expected_size {
+        return Err(format!(
+            "CashAddr hash length {} does not match version byte ({})",
+            hash.len(),
+            expected_size
+        ));
+    }
+
+    Ok((prefix, (version >> 3) & 0x0f, hash.to_vec()))
+}
+
+/// Encode a hash as a prefixed CashAddr string
+pub fn encode(prefix: &str, address_type: u8, hash: &[u8]) -> Result<String, String> {
+    if address_type > 0x0f {
+        return Err(format!("Invalid CashAddr type: {}", address_type));
+    }
+    let size_bits = HASH_SIZES
+        .iter()
+        .position(|&size| size == hash.len())
+        .ok_or_else(|| format!("Invalid CashAddr hash length: {} bytes", hash.len()))?;
+    let version = (address_type << 3) | size_bits as u8;
+
+    let payload = convert_bits(&[&[version], hash].concat(), 8, 5, true)?;
+    let checksum = cashaddr_checksum::calculate(prefix, &payload);
+    let encoded: String = payload
+        .iter()
+        .chain(checksum.iter())
+        .map(|&v| CHARSET[v as usize] as char)
+        .collect();
+
+    Ok(format!("{}:{}", prefix, encoded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prefixes() -> Vec<String> {
+        vec!["bitcoincash".to_string(), "bchtest".to_string()]
+    }
+
+    #[test]
+    fn test_decode_prefixed_p2pkh() {
+        let (prefix, address_type, hash) = decode(
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a",
+            &[],
+        )
+        .unwrap();
+        assert_eq!(prefix, "bitcoincash");
+        assert_eq!(address_type, TYPE_P2PKH);
+        assert_eq!(hash.len(), 20);
+    }
+
+    #[test]
+    fn test_decode_without_prefix() {
+        let (prefix, address_type, _) =
+            decode("ppm2qsznhks23z7629mms6s4cwef74vcwvn0h829pq", &prefixes()).unwrap();
+        assert_eq!(prefix, "bitcoincash");
+        assert_eq!(address_type, TYPE_P2SH);
+
+        let (prefix, _, _) =
+            decode("qpm2qsznhks23z7629mms6s4cwef74vcwvqcw003ap", &prefixes()).unwrap();
+        assert_eq!(prefix, "bchtest");
+    }
+
+    #[test]
+    fn test_decode_uppercase() {
+        assert!(decode(
+            "BITCOINCASH:QPM2QSZNHKS23Z7629MMS6S4CWEF74VCWVY22GDX6A",
+            &[]
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_decode_rejects_mixed_case_and_bad_checksum() {
+        assert!(decode(
+            "bitcoincash:Qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a",
+            &[]
+        )
+        .is_err());
+        assert!(decode(
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6b",
+            &[]
+        )
+        .is_err());
+        assert!(decode("bchtest:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a", &[]).is_err());
+    }
+
+    #[test]
+    fn test_encode_roundtrip() {
+        let (_, _, hash) = decode(
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a",
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            encode("bitcoincash", TYPE_P2SH, &hash).unwrap(),
+            "bitcoincash:ppm2qsznhks23z7629mms6s4cwef74vcwvn0h829pq"
+        );
+        assert_eq!(
+            encode("bchtest", TYPE_P2PKH, &hash).unwrap(),
+            "bchtest:qpm2qsznhks23z7629mms6s4cwef74vcwvqcw003ap"
+        );
+    }
+
+    #[test]
+    fn test_encode_invalid_hash_length() {
+        assert!(encode("bitcoincash", TYPE_P2PKH, &[0u8; 21]).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+//! Minimal CBOR (RFC 8949) decoding
+//!
+//! Covers the definite-length items address formats are built from:
+//! unsigned integers, byte and text strings, arrays, maps and tags.
+
+/// A decoded CBOR data item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    /// Major type 0
+    Unsigned(u64),
+    /// Major type 2
+    Bytes(Vec<u8>),
+    /// Major type 3
+    Text(String),
+    /// Major type 4
+    Array(Vec<Value>),
+    /// Major type 5, entries in encoding order
+    Map(Vec<(Value, Value)>),
+    /// Major type 6: tag number and tagged item
+    Tag(u64, Box<Value>),
+}
+
+/// Nesting limit, so hostile input cannot exhaust the stack
+const MAX_DEPTH: usize = 16;
+
+/// Decode a single CBOR item that spans all of `bytes`
+pub fn decode(bytes: &[u8]) -> Result<Value, String> {
+    let mut rest = bytes;
+    let value = read_value(&mut rest, 0)?;
+    if !rest.is_empty() {
+        return Err(format!("{} trailing bytes after CBOR item", rest.len()));
+    }
+    Ok(value)
+}
+
+fn read_value(rest: &mut &[u8], depth: usize) -> Result<Value, String> {
+    if depth > MAX_DEPTH {
+        return Err("CBOR nesting too deep".to_string());
+    }
+    let (&initial, tail) = rest
+        .split_first()
+        .ok_or_else(|| "Truncated CBOR item".to_string())?;
+    *rest = tail;
+    let major = initial >> 5;
+    let argument = read_argument(rest, initial & 0x1f)?;
+
+    match major {
+        0 => Ok(Value::Unsigned(argument)),
+        2 => Ok(Value::Bytes(take(rest, argument)?.to_vec())),
+        3 => String::from_utf8(take(rest, argument)?.to_vec())
+            .map(Value::Text)
+            .map_err(|_| "Invalid UTF-8 in CBOR text string".to_string()),
+        4 => {
+            // Every item takes at least one byte, which bounds the allocation
+            let count = bounded_count(rest, argument)?;
+            let mut items = Vec::with_capacity(count);
+            for _ in 0..count {
+                items.push(read_value(rest, depth + 1)?);
+            }
+            Ok(Value::Array(items))
+        }
+        5 => {
+            let count = bounded_count(rest, argument)?;
+            let mut entries = Vec::with_capacity(count);
+            for _ in 0..count {
+                let key = read_value(rest, depth + 1)?;
+                let value = read_value(rest, depth + 1)?;
+                entries.push((key, value));
+            }
+            Ok(Value::Map(entries))
+        }
+        6 => Ok(Value::Tag(argument, Box::new(read_value(rest, depth + 1)?))),
+        _ => Err(format!("Unsupported CBOR major type {}", major)),
+    }
+}
+
+/// Read the argument following the initial byte (additional info 0-27)
+fn read_argument(rest: &mut &[u8], info: u8) -> Result<u64, String> {
+    let len = match info {
+        0..=23 => return Ok(u64::from(info)),
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        31 => return Err("Indefinite-length CBOR items are not supported".to_string()),
+        _ => return Err(format!("Reserved CBOR additional information {}", info)),
+    };
+    Ok(take(rest, len as u64)?
+        .iter()
+        .fold(0, |value, &byte| (value << 8) | u64::from(byte)))
+}
+
+fn take<'a>(rest: &mut &'a [u8], len: u64) -> Result<&'a [u8], String> {
+    let len = usize::try_from(len)
+        .ok()
+        .filter(|&len| len <= rest.len())
+        .ok_or_else(|| "Truncated CBOR item".to_string())?;
+    let (head, tail) = rest.split_at(len);
+    *rest = tail;
+    Ok(head)
+}
+
+fn bounded_count(rest: &[u8], count: u64) -> Result<usize, String> {
+    usize::try_from(count)
+        .ok()
+        .filter(|&count| count <= rest.len())
+        .ok_or_else(|| "Truncated CBOR item".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_scalars() {
+        assert_eq!(decode(&[0x17]), Ok(Value::Unsigned(23)));
+        assert_eq!(decode(&[0x18, 0x18]), Ok(Value::Unsigned(24)));
+        assert_eq!(
+            decode(&[0x1a, 0x90, 0x26, 0xda, 0x5b]),
+            Ok(Value::Unsigned(0x9026da5b))
+        );
+        assert_eq!(
+            decode(&[0x42, 0x01, 0x02]),
+            Ok(Value::Bytes(vec![0x01, 0x02]))
+        );
+        assert_eq!(
+            decode(&[0x62, b'h', b'i']),
+            Ok(Value::Text("hi".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_decode_nested() {
+        // 24([h'01', {2: 0}])
+        let bytes = [0xd8, 0x18, 0x82, 0x41, 0x01, 0xa1, 0x02, 0x00];
+        assert_eq!(
+            decode(&bytes),
+            Ok(Value::Tag(
+                24,
+                Box::new(Value::Array(vec![
+                    Value::Bytes(vec![0x01]),
+                    Value::Map(vec![(Value::Unsigned(2), Value::Unsigned(0))]),
+                ]))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_malformed() {
+        assert!(decode(&[]).is_err());
+        assert!(decode(&[0x00, 0x00]).unwrap_err().contains("trailing"));
+        assert!(decode(&[0x43, 0x01]).is_err()); // 3-byte string, 1 byte present
+        assert!(decode(&[0x9b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]).is_err());
+        assert!(decode(&[0x9f, 0xff]).is_err()); // indefinite-length array
+        assert!(decode(&[0x20]).is_err()); // negative integer
+        assert!(decode(&[0x81; 32]).unwrap_err().contains("too deep"));
+    }
+}
@@ -1,7 +1,10 @@
-//! Encoding utilities (Base58, Bech32, Hex, SS58)
+//! Encoding utilities (Base58, Base64, Bech32, CashAddr, CBOR, Hex, SS58)
 
 pub mod base58;
+pub mod base64;
 pub mod bech32;
+pub mod cashaddr;
+pub mod cbor;
 pub mod hex;
 pub mod ss58;
 
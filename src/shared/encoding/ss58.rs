@@ -4,25 +4,77 @@ use base58::{FromBase58, ToBase58};
 
 use crate::shared::checksum::ss58 as ss58_checksum;
 
-/// Decode SS58 string to bytes
-pub fn decode(input: &str) -> Result<Vec<u8>, String> {
-    input
+/// Checksum length of an SS58 payload, or None for lengths SS58 does not define
+///
+/// 1, 2, 4 and 8-byte account indices carry a 1-byte checksum; 20-byte
+/// (`AccountId20`) and 32-byte account ids and 33-byte compressed ECDSA
+/// public keys a 2-byte checksum.
+pub fn checksum_len(payload_len: usize) -> Option<usize> {
+    match payload_len {
+        1 | 2 | 4 | 8 => Some(1),
+        20 | 32 | 33 => Some(2),
+        _ => None,
+    }
+}
+
+/// Decode an SS58 address into its network prefix and payload
+///
+/// Checks the one- or two-byte prefix, the payload length (see
+/// [`checksum_len`]) and the `blake2b_512("SS58PRE" || prefix || payload)`
+/// checksum.
+pub fn decode(input: &str) -> Result<(u16, Vec<u8>), String> {
+    let bytes = input
         .from_base58()
-        .map_err(|_| "Invalid SS58 encoding".to_string())
+        .map_err(|_| "Invalid SS58 encoding".to_string())?;
+    let (prefix, prefix_len) = decode_prefix(&bytes)?;
+    // Payload and checksum lengths are implied by the total length
+    let rest_len = bytes.len() - prefix_len;
+    let payload_len = [1, 2, 4, 8, 20, 32, 33]
+        .into_iter()
+        .find(|&len| checksum_len(len).is_some_and(|checksum| len + checksum == rest_len))
+        .ok_or_else(|| format!("Invalid SS58 length: {} bytes", bytes.len()))?;
+
+    let (prefix_bytes, rest) = bytes.split_at(prefix_len);
+    let (payload, checksum) = rest.split_at(payload_len);
+    if !ss58_checksum::validate(prefix_bytes, payload, checksum) {
+        return Err("Invalid SS58 checksum".to_string());
+    }
+    Ok((prefix, payload.to_vec()))
+}
+
+/// Read the network prefix and its length in bytes
+///
+/// Prefixes 0-63 take one byte; 64-16383 take two bytes, with the lower six
+/// bits of the first byte and the whole second byte holding the 14-bit value.
+fn decode_prefix(bytes: &[u8]) -> Result<(u16, usize), String> {
+    match bytes {
+        [first @ 0..=63, ..] => Ok((u16::from(*first), 1)),
+        [first @ 64..=127, second, ..] => {
+            let lower = (first << 2) | (second >> 6);
+            let upper = second & 0b0011_1111;
+            Ok((u16::from(lower) | (u16::from(upper) << 8), 2))
+        }
+        [] | [64..=127] => Err("SS58 input too short".to_string()),
+        _ => Err("Invalid SS58 prefix".to_string()),
+    }
 }
 
-/// Encode account ID as SS58 address with given prefix
+/// Encode an account id, account index or public key as SS58 address with given prefix
 ///
 /// # Arguments
 /// * `prefix` - SS58 prefix (0 for Polkadot, 2 for Kusama, 42 for Generic Substrate)
-/// * `account_id` - 32-byte account ID
+/// * `account_id` - 1, 2, 4 or 8-byte account index, 20 or 32-byte account id,
+///   or 33-byte compressed ECDSA public key
 ///
 /// # Returns
 /// SS58-encoded address string
 pub fn encode(prefix: u16, account_id: &[u8]) -> Result<String, String> {
-    if account_id.len() != 32 {
-        return Err("Account ID must be 32 bytes".to_string());
-    }
+    let checksum_len = checksum_len(account_id.len()).ok_or_else(|| {
+        format!(
+            "Unsupported SS58 payload length: {} bytes",
+            account_id.len()
+        )
+    })?;
 
     // Encode prefix bytes
     let prefix_bytes = if prefix < 64 {
@@ -30,16 +82,16 @@ pub fn encode(prefix: u16, account_id: &[u8]) -> Result<String, String> {
         vec![prefix as u8]
     } else if prefix < 16384 {
         // Two-byte prefix (64-16383)
-        // Format: first_byte = 0x40 + (prefix >> 8) & 0x3f, second_byte = prefix & 0xff
-        let first_byte = 0x40u8 + ((prefix >> 8) & 0x3f) as u8;
-        let second_byte = (prefix & 0xff) as u8;
+        // Format: first_byte = 0x40 | bits 2-7, second_byte = bits 0-1 << 6 | bits 8-13
+        let first_byte = 0x40u8 | ((prefix & 0b1111_1100) >> 2) as u8;
+        let second_byte = ((prefix >> 8) as u8) | (((prefix & 0b11) as u8) << 6);
         vec![first_byte, second_byte]
     } else {
         return Err("Prefix must be less than 16384".to_string());
     };
 
-    // Calculate checksum (2 bytes for standard addresses)
-    let checksum = ss58_checksum::calculate(&prefix_bytes, account_id, 2);
+    // Calculate checksum (2 bytes for account ids, 1 for account indices)
+    let checksum = ss58_checksum::calculate(&prefix_bytes, account_id, checksum_len);
 
     // Combine: prefix + account_id + checksum
     let mut payload = Vec::new();
@@ -57,11 +109,23 @@ mod tests {
 
     #[test]
     fn test_decode_valid_ss58() {
-        // Valid SS58 address (Polkadot format)
-        let input = "15oF4uVJwmo4TdGW7VfQxNLavjXviYtpYNRY9YzXg6WZ1";
-        let result = decode(input);
-        // This might fail if checksum is invalid, but decoding should work
-        let _ = result;
+        // Alice's account under the Polkadot prefix
+        let (prefix, account_id) =
+            decode("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5").unwrap();
+        assert_eq!(prefix, 0);
+        assert_eq!(
+            ::hex::encode(account_id),
+            "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+        );
+    }
+
+    #[test]
+    fn test_decode_bad_checksum() {
+        // Last character changed
+        let result = decode("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp6");
+        assert!(result.unwrap_err().contains("checksum"));
+        // Valid Base58 of the wrong length
+        assert!(decode("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6").is_err());
     }
 
     #[test]
@@ -76,9 +140,7 @@ mod tests {
     fn test_decode_empty() {
         let input = "";
         let result = decode(input);
-        assert!(result.is_ok());
-        let bytes = result.unwrap();
-        assert_eq!(bytes.len(), 0);
+        assert!(result.is_err());
     }
 
     #[test]
@@ -105,13 +167,39 @@ mod tests {
         assert!(!address.is_empty());
     }
 
+    #[test]
+    fn test_encode_two_byte_prefix_vector() {
+        // Alice's account under the Bittensor prefix (13116)
+        let account_id =
+            ::hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+                .unwrap();
+        assert_eq!(
+            encode(13116, &account_id).unwrap(),
+            "bt7zDqc4kAcKZ6kWup5yyuvRAbeYPhXGxwXY4CguG476A9MCE"
+        );
+    }
+
+    #[test]
+    fn test_decode_round_trip_prefixes() {
+        let account_id = vec![7u8; 32];
+        for prefix in [0, 2, 42, 63, 64, 1284, 13116, 16383] {
+            let address = encode(prefix, &account_id).unwrap();
+            assert_eq!(decode(&address).unwrap(), (prefix, account_id.clone()));
+        }
+        assert!(decode_prefix(&[]).is_err());
+        assert!(decode_prefix(&[0x40]).is_err());
+        assert!(decode_prefix(&[0x80, 0]).is_err());
+    }
+
     #[test]
     fn test_encode_invalid_account_id_length() {
         // Test encoding with invalid account ID length
         let account_id = vec![0u8; 31]; // Too short
         let result = encode(0, &account_id);
         assert!(result.is_err());
-        assert!(result.unwrap_err().contains("Account ID must be 32 bytes"));
+        assert!(result
+            .unwrap_err()
+            .contains("Unsupported SS58 payload length: 31 bytes"));
     }
 
     #[test]
@@ -144,4 +232,32 @@ mod tests {
         let address = result.unwrap();
         assert!(!address.is_empty());
     }
+
+    #[test]
+    fn test_encode_decode_payload_lengths() {
+        for len in [1, 2, 4, 8, 20, 32, 33] {
+            let payload: Vec<u8> = (1..=len as u8).collect();
+            for prefix in [0, 42, 1284] {
+                let address = encode(prefix, &payload).unwrap();
+                assert_eq!(decode(&address).unwrap(), (prefix, payload.clone()));
+            }
+        }
+        assert!(encode(0, &[0u8; 16]).is_err());
+    }
+
+    #[test]
+    fn test_ss58_vectors_short_payloads() {
+        // 1-byte account index with a 1-byte checksum
+        assert_eq!(encode(0, &[1]).unwrap(), "17z");
+        assert_eq!(decode("17z").unwrap(), (0, vec![1]));
+        assert_eq!(encode(2, &[0, 1, 2, 3]).unwrap(), "zmNeeYM");
+        // AccountId20 under the Moonbeam prefix
+        let h160 = ::hex::decode("9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f").unwrap();
+        assert_eq!(
+            encode(1284, &h160).unwrap(),
+            "6voedjU2oqLPWe1E9HrSPXJ7kw6GCAbEK"
+        );
+        // Index with a corrupted checksum
+        assert!(decode("17y").is_err());
+    }
 }
@@ -0,0 +1,392 @@
+//! Signed message recovery
+//!
+//! Recovery of the secp256k1 public key behind a message signature and of
+//! the addresses it maps to: Ethereum `personal_sign` (EIP-191), EIP-712
+//! typed data, Bitcoin signed messages (BIP-137 and Electrum) and Tron
+//! `signMessageV2`.
+
+use crate::descriptor::Descriptor;
+use crate::pipelines::addresses::derive_chain_address;
+use crate::registry::{Network, Registry};
+use crate::shared::checksum::eip55;
+use crate::shared::crypto::hash::{double_sha256, keccak256};
+use crate::shared::crypto::secp256k1;
+use crate::shared::encoding::base64;
+use crate::Error;
+
+/// Magic prefix of Bitcoin signed messages
+const BITCOIN_MESSAGE_PREFIX: &str = "Bitcoin Signed Message:\n";
+
+/// Message signing scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureScheme {
+    /// Ethereum `personal_sign` (EIP-191 version `0x45`)
+    PersonalSign,
+    /// EIP-712 typed data; the message is `domainSeparator || hashStruct(message)`
+    /// (64 bytes), optionally preceded by `0x1901`
+    TypedData,
+    /// Bitcoin signed message (BIP-137 header byte, also used by Electrum)
+    BitcoinMessage,
+    /// Tron `signMessageV2`
+    TronMessage,
+}
+
+/// An address of a recovered signer on a chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignerAddress {
+    /// Chain identifier
+    pub chain: String,
+    /// Address, normalized as `identify` reports it
+    pub address: String,
+}
+
+/// The signer of a message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoveredSigner {
+    /// Recovered secp256k1 public key, hex (compressed when a Bitcoin signature says so)
+    pub public_key: String,
+    /// Addresses of the key on every chain the scheme applies to
+    pub addresses: Vec<SignerAddress>,
+}
+
+/// Recover the signer of a message and its addresses
+///
+/// The signature is 65 bytes, hex (with or without `0x`) or Base64:
+/// `r || s || v` for Ethereum and Tron (`v` is 27/28 or 0/1), and
+/// `header || r || s` for Bitcoin. The BIP-137 header selects the script:
+/// 27-30 uncompressed P2PKH, 31-34 compressed P2PKH (Electrum also uses it
+/// for segwit, so segwit addresses are listed too), 35-38 P2SH-P2WPKH and
+/// 39-42 P2WPKH.
+///
+/// Recovery always yields some key; a signature is valid for an address only
+/// if the address is in the result.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::{recover_signer, SignatureScheme};
+///
+/// let signer = recover_signer(
+///     b"foxchain proof of ownership",
+///     "0x28b7f3a019749cce6fc677afa8fae72ec10e811ed4b04e1963143cef87654b7561247864757e9244fdbb4e5f9dc8173067ac303fa3bc721b05269e1a28524ef31c",
+///     SignatureScheme::PersonalSign,
+/// )?;
+/// assert!(signer
+///     .addresses
+///     .iter()
+///     .any(|a| a.chain == "ethereum" && a.address == "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"));
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn recover_signer(
+    message: &[u8],
+    signature: &str,
+    scheme: SignatureScheme,
+) -> Result<RecoveredSigner, Error> {
+    let signature = decode_signature(signature)?;
+    match scheme {
+        SignatureScheme::PersonalSign => {
+            let digest = keccak256(&prefixed(b"\x19Ethereum Signed Message:\n", message));
+            recover_pipeline_signer(&digest, &signature, "evm")
+        }
+        SignatureScheme::TypedData => {
+            // Only a 66-byte message carries the prefix; a domain separator
+            // may itself start with 0x1901
+            let hashes = match message.len() {
+                66 => message.strip_prefix(b"\x19\x01").unwrap_or(message),
+                _ => message,
+            };
+            if hashes.len() != 64 {
+                return Err(Error::InvalidInput(format!(
+                    "EIP-712 message must be domainSeparator || hashStruct (64 bytes), got {}",
+                    hashes.len()
+                )));
+            }
+            let digest = keccak256(&[&b"\x19\x01"[..], hashes].concat());
+            recover_pipeline_signer(&digest, &signature, "evm")
+        }
+        SignatureScheme::TronMessage => {
+            let digest = keccak256(&prefixed(b"\x19TRON Signed Message:\n", message));
+            recover_pipeline_signer(&digest, &signature, "tron")
+        }
+        SignatureScheme::BitcoinMessage => recover_bitcoin_signer(message, &signature),
+    }
+}
+
+/// Decode a 65-byte signature from hex or Base64
+fn decode_signature(signature: &str) -> Result<[u8; 65], Error> {
+    let trimmed = signature.trim();
+    let hex_digits = trimmed.strip_prefix("0x").unwrap_or(trimmed);
+    if hex_digits.is_empty() {
+        return Err(Error::InvalidInput("Signature is empty".to_string()));
+    }
+    let bytes = if hex_digits.len() == 130 {
+        ::hex::decode(hex_digits).map_err(|e| Error::InvalidInput(format!("Invalid hex: {}", e)))?
+    } else {
+        base64::decode(trimmed).map_err(Error::InvalidInput)?
+    };
+    let length = bytes.len();
+    bytes
+        .try_into()
+        .map_err(|_| Error::InvalidInput(format!("Signature must be 65 bytes, got {}", length)))
+}
+
+/// EIP-191 style message: prefix, decimal message length, message
+fn prefixed(prefix: &[u8], message: &[u8]) -> Vec<u8> {
+    [prefix, message.len().to_string().as_bytes(), message].concat()
+}
+
+/// Recover an `r || s || v` signature and derive addresses on chains using an address pipeline
+fn recover_pipeline_signer(
+    digest: &[u8; 32],
+    signature: &[u8; 65],
+    pipeline: &str,
+) -> Result<RecoveredSigner, Error> {
+    let recovery_id = match signature[64] {
+        v @ (27 | 28) => v - 27,
+        v @ (0 | 1) => v,
+        v => return Err(Error::InvalidInput(format!("Invalid signature v: {}", v))),
+    };
+    let public_key =
+        secp256k1::recover_public_key(digest, signature[..64].try_into().unwrap(), recovery_id)?;
+
+    let registry = Registry::get();
+    let addresses = registry
+        .chains
+        .iter()
+        .filter(|chain| {
+            registry
+                .get_chain_config(&chain.id)
+                .is_some_and(|config| config.address_pipeline == pipeline)
+        })
+        .filter_map(|chain| {
+            let address = derive_chain_address(&chain.id, &public_key).ok()?;
+            Some(SignerAddress {
+                chain: chain.id.clone(),
+                address: if pipeline == "evm" {
+                    eip55::normalize(&address).ok()?
+                } else {
+                    address
+                },
+            })
+        })
+        .collect();
+    Ok(RecoveredSigner {
+        public_key: ::hex::encode(public_key),
+        addresses,
+    })
+}
+
+/// Bitcoin compact size encoding of a length
+fn compact_size(length: usize) -> Vec<u8> {
+    match length {
+        0..=0xfc => vec![length as u8],
+        0xfd..=0xffff => [&[0xfd][..], &(length as u16).to_le_bytes()].concat(),
+        0x10000..=0xffff_ffff => [&[0xfe][..], &(length as u32).to_le_bytes()].concat(),
+        _ => [&[0xff][..], &(length as u64).to_le_bytes()].concat(),
+    }
+}
+
+/// Recover a BIP-137 signature and derive the addresses its header designates
+fn recover_bitcoin_signer(message: &[u8], signature: &[u8; 65]) -> Result<RecoveredSigner, Error> {
+    let header = signature[0];
+    if !(27..=42).contains(&header) {
+        return Err(Error::InvalidInput(format!(
+            "Invalid signed message header: {}",
+            header
+        )));
+    }
+    let prefix = BITCOIN_MESSAGE_PREFIX.as_bytes();
+    let digest = double_sha256(
+        &[
+            &compact_size(prefix.len())[..],
+            prefix,
+            &compact_size(message.len()),
+            message,
+        ]
+        .concat(),
+    );
+    let uncompressed = secp256k1::recover_public_key(
+        &digest,
+        signature[1..].try_into().unwrap(),
+        (header - 27) % 4,
+    )?;
+    let public_key = if header >= 31 {
+        secp256k1::compress_public_key(&uncompressed)?.to_vec()
+    } else {
+        uncompressed.to_vec()
+    };
+
+    let descriptors = match header {
+        27..=30 => vec![Descriptor::Pkh(public_key.clone())],
+        31..=34 => vec![
+            Descriptor::Pkh(public_key.clone()),
+            Descriptor::ShWpkh(public_key.clone()),
+            Descriptor::Wpkh(public_key.clone()),
+        ],
+        35..=38 => vec![Descriptor::ShWpkh(public_key.clone())],
+        _ => vec![Descriptor::Wpkh(public_key.clone())],
+    };
+
+    let registry = Registry::get();
+    let mut addresses = Vec::new();
+    for chain in &registry.chains {
+        let uses_prefix = registry
+            .get_chain_config(&chain.id)
+            .and_then(|config| config.message_prefix.as_deref())
+            == Some(BITCOIN_MESSAGE_PREFIX);
+        if !uses_prefix {
+            continue;
+        }
+        for descriptor in &descriptors {
+            if let Some((_, address)) = descriptor.address(&chain.id, Network::Mainnet)? {
+                addresses.push(SignerAddress {
+                    chain: chain.id.clone(),
+                    address,
+                });
+            }
+        }
+    }
+    Ok(RecoveredSigner {
+        public_key: ::hex::encode(public_key),
+        addresses,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Signatures by key 0x4646...46
+    const MESSAGE: &[u8] = b"foxchain proof of ownership";
+    const EVM_ADDRESS: &str = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+
+    fn addresses_on(signer: &RecoveredSigner, chain: &str) -> Vec<String> {
+        signer
+            .addresses
+            .iter()
+            .filter(|a| a.chain == chain)
+            .map(|a| a.address.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_recover_personal_sign() {
+        let signer = recover_signer(MESSAGE, "0x28b7f3a019749cce6fc677afa8fae72ec10e811ed4b04e1963143cef87654b7561247864757e9244fdbb4e5f9dc8173067ac303fa3bc721b05269e1a28524ef31c", SignatureScheme::PersonalSign).unwrap();
+        assert_eq!(addresses_on(&signer, "ethereum"), vec![EVM_ADDRESS]);
+        assert_eq!(addresses_on(&signer, "polygon"), vec![EVM_ADDRESS]);
+        assert!(signer.public_key.starts_with("044bc2a312"));
+
+        // Another message recovers another key
+        let other = recover_signer(b"other", "0x28b7f3a019749cce6fc677afa8fae72ec10e811ed4b04e1963143cef87654b7561247864757e9244fdbb4e5f9dc8173067ac303fa3bc721b05269e1a28524ef31c", SignatureScheme::PersonalSign).unwrap();
+        assert_ne!(addresses_on(&other, "ethereum"), vec![EVM_ADDRESS]);
+    }
+
+    #[test]
+    fn test_recover_typed_data() {
+        let hashes = ::hex::decode("c5d3ba30d3ac69f3f095a61e99369d9450502ca0c2f4768b2c39ee277faa631da6790d66da1d2a209ce21a198ec78ee13a5b7ccf6c756db6ba4e84da2021f9a2").unwrap();
+        let signature = "71b357df56cfc77291b75e5f550d72687c1bef84ff9eadb4946eb55516a89d6f1dd1978d2f4f43d4c9565cf61a0b13a07954066851ad3eaa7bcd476591ff2e5b1c";
+        let signer = recover_signer(&hashes, signature, SignatureScheme::TypedData).unwrap();
+        assert_eq!(addresses_on(&signer, "ethereum"), vec![EVM_ADDRESS]);
+
+        let encoded = [&b"\x19\x01"[..], &hashes].concat();
+        assert_eq!(
+            recover_signer(&encoded, signature, SignatureScheme::TypedData).unwrap(),
+            signer
+        );
+        assert!(recover_signer(&hashes[..32], signature, SignatureScheme::TypedData).is_err());
+    }
+
+    #[test]
+    fn test_recover_typed_data_domain_separator_starting_with_prefix() {
+        use ::secp256k1::{Message, Secp256k1, SecretKey};
+
+        let mut hashes = [0x42u8; 64];
+        hashes[..2].copy_from_slice(b"\x19\x01");
+        let digest = keccak256(&[&b"\x19\x01"[..], &hashes].concat());
+        let (recovery_id, compact) = Secp256k1::new()
+            .sign_ecdsa_recoverable(
+                &Message::from_digest(digest),
+                &SecretKey::from_slice(&[0x46; 32]).unwrap(),
+            )
+            .serialize_compact();
+        let signature = ::hex::encode([&compact[..], &[27 + recovery_id.to_i32() as u8]].concat());
+
+        let signer = recover_signer(&hashes, &signature, SignatureScheme::TypedData).unwrap();
+        assert_eq!(addresses_on(&signer, "ethereum"), vec![EVM_ADDRESS]);
+    }
+
+    #[test]
+    fn test_recover_tron_message() {
+        let signer = recover_signer(MESSAGE, "0x4cecb33a915e2c3b189eb6ee45fa4eb3eddeff09729945c600f893bd4381b294382ad794eff26b57d821f6bfd64208025f924efe9e9ac9180283a278b7373f341c", SignatureScheme::TronMessage).unwrap();
+        assert_eq!(
+            signer.addresses,
+            vec![SignerAddress {
+                chain: "tron".to_string(),
+                address: "TQLCsShbQNXMTVCjprY64qZmEA4rBarpQp".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_recover_bitcoin_message() {
+        let recover = |signature: &str| {
+            recover_signer(MESSAGE, signature, SignatureScheme::BitcoinMessage).unwrap()
+        };
+
+        // Compressed P2PKH header (Electrum): legacy and segwit addresses
+        let signer = recover("IE1N1MKtlz4QBlpZPQID3aQXQpK7NuTpn7L7sTc6F4dLYmD+wX1wn7p67dViLXgfhf79ar1wGJL9tqCeaxybkpg=");
+        assert_eq!(
+            signer.public_key,
+            "024bc2a31265153f07e70e0bab08724e6b85e217f8cd628ceb62974247bb493382"
+        );
+        assert_eq!(
+            addresses_on(&signer, "bitcoin"),
+            vec![
+                "1JHMeqKunF2Up6zxnMQGhJu5667BXz98YQ",
+                "3QcXwJW5QKR7F2Jje6LY6ZL1WFZ3MNz6Uf",
+                "bc1qhkfq3zahaqkkzx5mjnamwjsfpq2jk7z00ppggv",
+            ]
+        );
+        // Bitcoin Cash shares the message prefix but has no segwit
+        assert_eq!(addresses_on(&signer, "bitcoin_cash").len(), 1);
+        assert!(addresses_on(&signer, "litecoin").is_empty());
+
+        let signer = recover("G3mLgZUaaox++L7aCbBqIwMdfvrze2YyZeAYirN4EkBGK2EE3zqmYNkM+6JqsuGaOdyOZxwhuPHDKpB3B5WaNh8=");
+        assert_eq!(
+            addresses_on(&signer, "bitcoin"),
+            vec!["191EQkjT83LL2pxfy7NWUkDTv2fFRPs39r"]
+        );
+        let signer = recover("I9XkEGXq+JDp6QRpNgrPakNZeVx6GTnY7UKUGoxu8xNkPbUpPvDDxo1sT+nJ6yzYhdgzbO/+6NvMV8PKnWbq7WY=");
+        assert_eq!(
+            addresses_on(&signer, "bitcoin"),
+            vec!["3QcXwJW5QKR7F2Jje6LY6ZL1WFZ3MNz6Uf"]
+        );
+        let signer = recover("KPECbGY3oxSvt28rf6GTe/PBFKR+Mo9wqC8RWbRsHJ4FY7o3mBnxiG2wXnayRIgZFtWpjYe9WmePQdVL/Ff4sHA=");
+        assert_eq!(
+            addresses_on(&signer, "bitcoin"),
+            vec!["bc1qhkfq3zahaqkkzx5mjnamwjsfpq2jk7z00ppggv"]
+        );
+    }
+
+    #[test]
+    fn test_recover_empty_signature() {
+        for empty in ["", "0x", "  "] {
+            let error = recover_signer(MESSAGE, empty, SignatureScheme::PersonalSign).unwrap_err();
+            assert_eq!(error.to_string(), "Invalid input: Signature is empty");
+        }
+    }
+
+    #[test]
+    fn test_recover_invalid_signature() {
+        assert!(recover_signer(MESSAGE, "0x1234", SignatureScheme::PersonalSign).is_err());
+        let bad_v = format!("0x{}{}", "11".repeat(64), "05");
+        assert!(recover_signer(MESSAGE, &bad_v, SignatureScheme::PersonalSign).is_err());
+        assert!(recover_signer(MESSAGE, &bad_v, SignatureScheme::BitcoinMessage).is_err());
+    }
+
+    #[test]
+    fn test_compact_size() {
+        assert_eq!(compact_size(24), vec![24]);
+        assert_eq!(compact_size(300), vec![0xfd, 0x2c, 0x01]);
+    }
+}
@@ -0,0 +1,269 @@
+//! Pallet, parachain sovereign, multisig and pure proxy account ids
+
+use crate::registry::Registry;
+use crate::shared::crypto::hash::blake2b_256;
+use crate::shared::encoding::ss58;
+use crate::Error;
+
+/// `PalletId` account prefix (`PalletId::into_account_truncating`)
+const PALLET_PREFIX: &[u8; 4] = b"modl";
+/// Sovereign account of a child parachain on its relay chain
+const CHILD_PREFIX: &[u8; 4] = b"para";
+/// Sovereign account of a sibling parachain on another parachain
+const SIBLING_PREFIX: &[u8; 4] = b"sibl";
+/// `pallet_multisig` entropy prefix
+const MULTISIG_PREFIX: &[u8; 16] = b"modlpy/utilisuba";
+/// `pallet_proxy` pure proxy entropy prefix
+const PURE_PROXY_PREFIX: &[u8; 16] = b"modlpy/proxy____";
+
+/// Compute the account of a pallet from its 8-byte `PalletId` (`py/trsry` for the treasury)
+///
+/// The account id is `"modl" || pallet_id`, zero-padded to 32 bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::pallet_account;
+///
+/// let treasury = pallet_account("py/trsry", "polkadot")?;
+/// assert_eq!(treasury, "13UVJyLnbVp9RBZYFwFGyDvVd1y27Tt8tkntv6Q7JVPhFsTB");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn pallet_account(pallet_id: &str, chain: &str) -> Result<String, Error> {
+    if pallet_id.len() != 8 {
+        return Err(Error::InvalidInput(format!(
+            "PalletId must be 8 bytes, got {}",
+            pallet_id.len()
+        )));
+    }
+    encode_account(&padded_account(PALLET_PREFIX, pallet_id.as_bytes()), chain)
+}
+
+/// Compute the sovereign account of a parachain on its relay chain (`"para" || para_id`)
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::parachain_sovereign_account;
+///
+/// // Acala (2000) on Polkadot
+/// let acala = parachain_sovereign_account(2000, "polkadot")?;
+/// assert_eq!(acala, "13YMK2eYoAvStnzReuxBjMrAvPXmmdsURwZvc62PrdXimbNy");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn parachain_sovereign_account(para_id: u32, chain: &str) -> Result<String, Error> {
+    encode_account(&padded_account(CHILD_PREFIX, &para_id.to_le_bytes()), chain)
+}
+
+/// Compute the sovereign account of a parachain on a sibling parachain (`"sibl" || para_id`)
+pub fn sibling_sovereign_account(para_id: u32, chain: &str) -> Result<String, Error> {
+    encode_account(
+        &padded_account(SIBLING_PREFIX, &para_id.to_le_bytes()),
+        chain,
+    )
+}
+
+/// Compute the `pallet_multisig` account of signatories and a threshold
+///
+/// Signatories are SS58 addresses (any prefix) or `0x` hex account ids, in
+/// any order: the account id is
+/// `blake2_256("modlpy/utilisuba" || sorted signatories || threshold)`, SCALE-encoded.
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::substrate_multisig_account;
+///
+/// // Alice, Bob and Charlie, 2 of 3
+/// let multisig = substrate_multisig_account(
+///     &[
+///         "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+///         "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty",
+///         "5FLSigC9HGRKVhB9FiEo4Y3koPsNmBmLJbpXg2mp1hXcS59Y",
+///     ],
+///     2,
+///     "substrate",
+/// )?;
+/// assert_eq!(multisig, "5DjYJStmdZ2rcqXbXGX7TW85JsrW6uG4y9MUcLq2BoPMpRA7");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn substrate_multisig_account(
+    signatories: &[&str],
+    threshold: u16,
+    chain: &str,
+) -> Result<String, Error> {
+    let mut accounts = signatories
+        .iter()
+        .map(|signatory| decode_account(signatory))
+        .collect::<Result<Vec<_>, _>>()?;
+    if threshold == 0 || usize::from(threshold) > accounts.len() {
+        return Err(Error::InvalidInput(format!(
+            "Threshold must be between 1 and {}",
+            accounts.len()
+        )));
+    }
+    accounts.sort_unstable();
+    if accounts.windows(2).any(|pair| pair[0] == pair[1]) {
+        return Err(Error::InvalidInput(
+            "Signatories must be distinct".to_string(),
+        ));
+    }
+
+    let mut entropy = MULTISIG_PREFIX.to_vec();
+    entropy.extend(compact_length(accounts.len()));
+    for account in &accounts {
+        entropy.extend_from_slice(account);
+    }
+    entropy.extend_from_slice(&threshold.to_le_bytes());
+    encode_account(&blake2b_256(&entropy), chain)
+}
+
+/// Compute the account of a pure proxy created by `spawner`
+///
+/// `proxy_type` is the index of the runtime's `ProxyType` variant (0 for
+/// `Any`), `index` the disambiguation index passed to `create_pure`, and
+/// `height`/`extrinsic_index` locate the creating extrinsic. The account id is
+/// `blake2_256("modlpy/proxy____" || spawner || height || extrinsic_index || proxy_type || index)`.
+pub fn pure_proxy_account(
+    spawner: &str,
+    proxy_type: u8,
+    index: u16,
+    height: u32,
+    extrinsic_index: u32,
+    chain: &str,
+) -> Result<String, Error> {
+    let mut entropy = PURE_PROXY_PREFIX.to_vec();
+    entropy.extend_from_slice(&decode_account(spawner)?);
+    entropy.extend_from_slice(&height.to_le_bytes());
+    entropy.extend_from_slice(&extrinsic_index.to_le_bytes());
+    entropy.push(proxy_type);
+    entropy.extend_from_slice(&index.to_le_bytes());
+    encode_account(&blake2b_256(&entropy), chain)
+}
+
+/// `prefix || data`, zero-padded to a 32-byte account id
+fn padded_account(prefix: &[u8; 4], data: &[u8]) -> [u8; 32] {
+    let mut account = [0u8; 32];
+    account[..4].copy_from_slice(prefix);
+    account[4..4 + data.len()].copy_from_slice(data);
+    account
+}
+
+/// SCALE compact encoding of a sequence length
+fn compact_length(len: usize) -> Vec<u8> {
+    match len {
+        0..=0x3f => vec![(len as u8) << 2],
+        0x40..=0x3fff => ((len as u16) << 2 | 0b01).to_le_bytes().to_vec(),
+        _ => ((len as u32) << 2 | 0b10).to_le_bytes().to_vec(),
+    }
+}
+
+/// 32-byte account id of an SS58 address or `0x` hex account id
+fn decode_account(account: &str) -> Result<[u8; 32], Error> {
+    let bytes = match account.strip_prefix("0x") {
+        Some(hex_part) => ::hex::decode(hex_part)
+            .map_err(|e| Error::InvalidInput(format!("Invalid hex: {}", e)))?,
+        None => {
+            ss58::decode(account)
+                .map_err(|e| Error::InvalidInput(format!("SS58 decode error: {}", e)))?
+                .1
+        }
+    };
+    bytes
+        .try_into()
+        .map_err(|_| Error::InvalidInput(format!("Not a 32-byte account: {}", account)))
+}
+
+/// SS58-encode an account id with the prefix of an `ss58` chain
+fn encode_account(account_id: &[u8; 32], chain: &str) -> Result<String, Error> {
+    let prefix = Registry::get()
+        .get_chain_config(chain)
+        .filter(|config| config.address_pipeline == "ss58")
+        .and_then(|config| config.address_params.get("prefix")?.as_u64())
+        .and_then(|prefix| u16::try_from(prefix).ok())
+        .ok_or_else(|| Error::InvalidInput(format!("{} has no 32-byte SS58 accounts", chain)))?;
+    ss58::encode(prefix, account_id)
+        .map_err(|e| Error::InvalidInput(format!("SS58 encoding error: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+    const BOB: &str = "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty";
+    const CHARLIE: &str = "5FLSigC9HGRKVhB9FiEo4Y3koPsNmBmLJbpXg2mp1hXcS59Y";
+
+    #[test]
+    fn test_pallet_and_sovereign_accounts() {
+        assert_eq!(
+            pallet_account("py/trsry", "polkadot").unwrap(),
+            "13UVJyLnbVp9RBZYFwFGyDvVd1y27Tt8tkntv6Q7JVPhFsTB"
+        );
+        assert_eq!(
+            parachain_sovereign_account(2000, "polkadot").unwrap(),
+            "13YMK2eYoAvStnzReuxBjMrAvPXmmdsURwZvc62PrdXimbNy"
+        );
+        assert_eq!(
+            sibling_sovereign_account(2000, "kusama").unwrap(),
+            "FBeL7EAeUroLWXW1yfKboiqTqVfbRBcsUKd6QqVf4kGBySS"
+        );
+        assert!(pallet_account("py/trsy", "polkadot").is_err());
+    }
+
+    #[test]
+    fn test_substrate_multisig_account() {
+        let expected = "5DjYJStmdZ2rcqXbXGX7TW85JsrW6uG4y9MUcLq2BoPMpRA7";
+        assert_eq!(
+            substrate_multisig_account(&[ALICE, BOB, CHARLIE], 2, "substrate").unwrap(),
+            expected
+        );
+        // Order-independent, any SS58 prefix or hex account id
+        let alice_hex = "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+        assert_eq!(
+            substrate_multisig_account(&[CHARLIE, alice_hex, BOB], 2, "substrate").unwrap(),
+            expected
+        );
+        assert_eq!(
+            substrate_multisig_account(&[ALICE, BOB, CHARLIE], 2, "polkadot").unwrap(),
+            "12fqSn9qVLJL4NY7Uua7bexEAVr9oCpD3e5xmdpNjtQszzBt"
+        );
+    }
+
+    #[test]
+    fn test_substrate_multisig_account_errors() {
+        assert!(substrate_multisig_account(&[ALICE, BOB], 0, "polkadot").is_err());
+        assert!(substrate_multisig_account(&[ALICE, BOB], 3, "polkadot").is_err());
+        assert!(substrate_multisig_account(&[ALICE, ALICE], 1, "polkadot").is_err());
+        assert!(substrate_multisig_account(&[ALICE, "0x1234"], 1, "polkadot").is_err());
+    }
+
+    #[test]
+    fn test_pure_proxy_account() {
+        assert_eq!(
+            pure_proxy_account(ALICE, 0, 0, 100, 1, "substrate").unwrap(),
+            "5CXe15GjPv6EVMyXnHHRUL1nF5Fj2vCYX746y8SsgXnALh7Z"
+        );
+        assert_ne!(
+            pure_proxy_account(ALICE, 0, 1, 100, 1, "substrate").unwrap(),
+            pure_proxy_account(ALICE, 0, 0, 100, 1, "substrate").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_derived_accounts_need_32_byte_ss58_chain() {
+        // AccountId20 and non-Substrate chains
+        assert!(pallet_account("py/trsry", "moonbeam").is_err());
+        assert!(pallet_account("py/trsry", "ethereum").is_err());
+        assert!(pallet_account("py/trsry", "unknown").is_err());
+        // Registry networks
+        assert!(parachain_sovereign_account(2000, "acala").is_ok());
+    }
+
+    #[test]
+    fn test_compact_length() {
+        assert_eq!(compact_length(3), vec![0x0c]);
+        assert_eq!(compact_length(64), vec![0x01, 0x01]);
+        assert_eq!(compact_length(16384), vec![0x02, 0x00, 0x01, 0x00]);
+    }
+}
@@ -0,0 +1,12 @@
+//! Substrate accounts derived from on-chain rules
+//!
+//! Pallet, parachain sovereign, multisig and pure proxy accounts have no key:
+//! their account ids are computed from a pallet id, parachain id or the
+//! accounts that control them, and SS58-encoded for a network.
+
+mod accounts;
+
+pub use accounts::{
+    pallet_account, parachain_sovereign_account, pure_proxy_account, sibling_sovereign_account,
+    substrate_multisig_account,
+};
@@ -0,0 +1,443 @@
+//! Raw signed EVM transactions: legacy, EIP-2930, EIP-1559 and EIP-4844
+
+use super::rlp::{self, Item};
+use crate::shared::checksum::eip55;
+use crate::shared::crypto::hash::keccak256;
+use crate::shared::crypto::secp256k1;
+use crate::Error;
+
+/// EVM transaction envelope type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvmTransactionType {
+    /// Untyped RLP transaction, with or without EIP-155 replay protection
+    Legacy,
+    /// EIP-2930 access list transaction (type 1)
+    AccessList,
+    /// EIP-1559 dynamic fee transaction (type 2)
+    DynamicFee,
+    /// EIP-4844 blob transaction (type 3)
+    Blob,
+}
+
+impl EvmTransactionType {
+    /// Human-readable name, e.g. "EIP-1559"
+    pub fn name(self) -> &'static str {
+        match self {
+            EvmTransactionType::Legacy => "legacy",
+            EvmTransactionType::AccessList => "EIP-2930",
+            EvmTransactionType::DynamicFee => "EIP-1559",
+            EvmTransactionType::Blob => "EIP-4844",
+        }
+    }
+}
+
+/// An access list entry (EIP-2930)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessListItem {
+    /// EIP-55 address
+    pub address: String,
+    /// 32-byte storage keys, `0x`-prefixed hex
+    pub storage_keys: Vec<String>,
+}
+
+/// A decoded, signed EVM transaction
+///
+/// Amounts are JSON-RPC quantities (`0x`-prefixed hex without leading zeros).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvmTransaction {
+    /// Envelope type
+    pub tx_type: EvmTransactionType,
+    /// Chain id; `None` for legacy transactions without EIP-155 replay protection
+    pub chain_id: Option<u64>,
+    pub nonce: u64,
+    /// Gas price (legacy and EIP-2930)
+    pub gas_price: Option<String>,
+    /// Priority fee cap (EIP-1559 and EIP-4844)
+    pub max_priority_fee_per_gas: Option<String>,
+    /// Fee cap (EIP-1559 and EIP-4844)
+    pub max_fee_per_gas: Option<String>,
+    pub gas_limit: u64,
+    /// EIP-55 recipient; `None` for contract creation
+    pub to: Option<String>,
+    pub value: String,
+    /// Call data, `0x`-prefixed hex
+    pub data: String,
+    pub access_list: Vec<AccessListItem>,
+    /// Blob fee cap (EIP-4844)
+    pub max_fee_per_blob_gas: Option<String>,
+    /// Blob versioned hashes (EIP-4844), `0x`-prefixed hex
+    pub blob_versioned_hashes: Vec<String>,
+    /// EIP-55 sender address, recovered from the signature
+    pub from: String,
+    /// Transaction hash, `0x`-prefixed hex
+    pub hash: String,
+}
+
+/// Decode a raw signed EVM transaction and recover its sender
+///
+/// Accepts hex with or without `0x`: legacy RLP transactions (EIP-155 `v`
+/// encodes the chain id), typed EIP-2930/1559/4844 envelopes, and EIP-4844
+/// transactions in their network form (with blobs, commitments and proofs).
+///
+/// # Example
+///
+/// ```rust
+/// use foxchain_id::decode_evm_transaction;
+///
+/// // EIP-155 example transaction
+/// let tx = decode_evm_transaction("0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83")?;
+/// assert_eq!(tx.chain_id, Some(1));
+/// assert_eq!(tx.from, "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F");
+/// # Ok::<(), foxchain_id::Error>(())
+/// ```
+pub fn decode_evm_transaction(input: &str) -> Result<EvmTransaction, Error> {
+    let trimmed = input.trim();
+    let raw = ::hex::decode(trimmed.strip_prefix("0x").unwrap_or(trimmed))
+        .map_err(|e| Error::InvalidInput(format!("Invalid hex: {}", e)))?;
+    let (&first, payload) = raw
+        .split_first()
+        .ok_or_else(|| Error::InvalidInput("Empty transaction".to_string()))?;
+
+    match first {
+        0xc0..=0xff => decode_legacy(&raw),
+        0x01 => decode_typed(EvmTransactionType::AccessList, payload),
+        0x02 => decode_typed(EvmTransactionType::DynamicFee, payload),
+        0x03 => decode_typed(EvmTransactionType::Blob, payload),
+        _ => Err(Error::InvalidInput(format!(
+            "Unsupported transaction type: 0x{:02x}",
+            first
+        ))),
+    }
+}
+
+fn field_count_error(tx_type: EvmTransactionType, expected: usize, got: usize) -> Error {
+    Error::InvalidInput(format!(
+        "{} transaction has {} fields, expected {}",
+        tx_type.name(),
+        got,
+        expected
+    ))
+}
+
+/// Legacy transaction: `rlp([nonce, gasPrice, gas, to, value, data, v, r, s])`
+fn decode_legacy(raw: &[u8]) -> Result<EvmTransaction, Error> {
+    let tx_type = EvmTransactionType::Legacy;
+    let item = rlp::decode(raw)?;
+    let fields = item.as_list()?;
+    if fields.len() != 9 {
+        return Err(field_count_error(tx_type, 9, fields.len()));
+    }
+
+    // EIP-155: v = chain_id * 2 + 35 + parity; pre-EIP-155: v = 27 + parity
+    let v = fields[6].as_u64()?;
+    let (chain_id, parity) = match v {
+        27 | 28 => (None, (v - 27) as u8),
+        35.. => (Some((v - 35) / 2), ((v - 35) % 2) as u8),
+        _ => {
+            return Err(Error::InvalidInput(format!(
+                "Invalid legacy signature v: {}",
+                v
+            )))
+        }
+    };
+    let unsigned: Vec<&[u8]> = fields[..6].iter().map(|f| f.raw).collect();
+    let signing_payload = match chain_id {
+        Some(chain_id) => {
+            let chain_id = rlp::encode_u64(chain_id);
+            let zero = rlp::encode_u64(0);
+            rlp::encode_list(&[&unsigned[..], &[&chain_id, &zero, &zero]].concat())
+        }
+        None => rlp::encode_list(&unsigned),
+    };
+
+    Ok(EvmTransaction {
+        tx_type,
+        chain_id,
+        nonce: fields[0].as_u64()?,
+        gas_price: Some(fields[1].as_quantity()?),
+        max_priority_fee_per_gas: None,
+        max_fee_per_gas: None,
+        gas_limit: fields[2].as_u64()?,
+        to: recipient(&fields[3])?,
+        value: fields[4].as_quantity()?,
+        data: hex_string(fields[5].as_bytes()?),
+        access_list: Vec::new(),
+        max_fee_per_blob_gas: None,
+        blob_versioned_hashes: Vec::new(),
+        from: recover_sender(&signing_payload, parity, &fields[7], &fields[8])?,
+        hash: hex_string(&keccak256(raw)),
+    })
+}
+
+/// Typed transaction: `type || rlp([chainId, ..., yParity, r, s])`
+fn decode_typed(tx_type: EvmTransactionType, payload: &[u8]) -> Result<EvmTransaction, Error> {
+    let type_byte = payload_type_byte(tx_type);
+    let item = rlp::decode(payload)?;
+    let outer = item.as_list()?;
+
+    // EIP-4844 network form: rlp([tx_payload_body, blobs, commitments, proofs])
+    let body = match outer.first() {
+        Some(first) if tx_type == EvmTransactionType::Blob && first.as_list().is_ok() => {
+            if outer.len() != 4 {
+                return Err(field_count_error(tx_type, 4, outer.len()));
+            }
+            first
+        }
+        _ => &item,
+    };
+    let fields = body.as_list()?;
+
+    let expected = match tx_type {
+        EvmTransactionType::AccessList => 11,
+        EvmTransactionType::DynamicFee => 12,
+        _ => 14,
+    };
+    if fields.len() != expected {
+        return Err(field_count_error(tx_type, expected, fields.len()));
+    }
+
+    // Fields shared by every typed transaction, indexed from the fee fields on
+    let (gas_price, max_priority_fee_per_gas, max_fee_per_gas, rest) = match tx_type {
+        EvmTransactionType::AccessList => (Some(fields[2].as_quantity()?), None, None, 3),
+        _ => (
+            None,
+            Some(fields[2].as_quantity()?),
+            Some(fields[3].as_quantity()?),
+            4,
+        ),
+    };
+    let to = recipient(&fields[rest + 1])?;
+    let (max_fee_per_blob_gas, blob_versioned_hashes) = if tx_type == EvmTransactionType::Blob {
+        if to.is_none() {
+            return Err(Error::InvalidInput(
+                "EIP-4844 transactions cannot create contracts".to_string(),
+            ));
+        }
+        let hashes = fields[rest + 6]
+            .as_list()?
+            .iter()
+            .map(|hash| hash.as_bytes().map(hex_string))
+            .collect::<Result<_, _>>()?;
+        (Some(fields[rest + 5].as_quantity()?), hashes)
+    } else {
+        (None, Vec::new())
+    };
+
+    let parity = fields[expected - 3].as_u64()?;
+    if parity > 1 {
+        return Err(Error::InvalidInput(format!(
+            "Invalid signature y parity: {}",
+            parity
+        )));
+    }
+    let unsigned: Vec<&[u8]> = fields[..expected - 3].iter().map(|f| f.raw).collect();
+    let signing_payload = [&[type_byte][..], &rlp::encode_list(&unsigned)].concat();
+
+    Ok(EvmTransaction {
+        tx_type,
+        chain_id: Some(fields[0].as_u64()?),
+        nonce: fields[1].as_u64()?,
+        gas_price,
+        max_priority_fee_per_gas,
+        max_fee_per_gas,
+        gas_limit: fields[rest].as_u64()?,
+        to,
+        value: fields[rest + 2].as_quantity()?,
+        data: hex_string(fields[rest + 3].as_bytes()?),
+        access_list: access_list(&fields[rest + 4])?,
+        max_fee_per_blob_gas,
+        blob_versioned_hashes,
+        from: recover_sender(
+            &signing_payload,
+            parity as u8,
+            &fields[expected - 2],
+            &fields[expected - 1],
+        )?,
+        // The hash covers the transaction body, never the blob sidecar
+        hash: hex_string(&keccak256(&[&[type_byte][..], body.raw].concat())),
+    })
+}
+
+fn payload_type_byte(tx_type: EvmTransactionType) -> u8 {
+    match tx_type {
+        EvmTransactionType::Legacy => 0,
+        EvmTransactionType::AccessList => 1,
+        EvmTransactionType::DynamicFee => 2,
+        EvmTransactionType::Blob => 3,
+    }
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    format!("0x{}", ::hex::encode(bytes))
+}
+
+/// EIP-55 address of a 20-byte field
+fn address(item: &Item) -> Result<String, Error> {
+    let bytes = item.as_bytes()?;
+    if bytes.len() != 20 {
+        return Err(Error::InvalidInput(format!(
+            "Address must be 20 bytes, got {}",
+            bytes.len()
+        )));
+    }
+    eip55::normalize(&hex_string(bytes))
+}
+
+/// Recipient address, or `None` for an empty (contract creation) field
+fn recipient(item: &Item) -> Result<Option<String>, Error> {
+    if item.as_bytes()?.is_empty() {
+        Ok(None)
+    } else {
+        address(item).map(Some)
+    }
+}
+
+/// `[[address, [storageKey, ...]], ...]`
+fn access_list(item: &Item) -> Result<Vec<AccessListItem>, Error> {
+    item.as_list()?
+        .iter()
+        .map(|entry| {
+            let entry = entry.as_list()?;
+            if entry.len() != 2 {
+                return Err(Error::InvalidInput(
+                    "Access list entries have an address and storage keys".to_string(),
+                ));
+            }
+            Ok(AccessListItem {
+                address: address(&entry[0])?,
+                storage_keys: entry[1]
+                    .as_list()?
+                    .iter()
+                    .map(|key| key.as_bytes().map(hex_string))
+                    .collect::<Result<_, _>>()?,
+            })
+        })
+        .collect()
+}
+
+/// Recover the EIP-55 sender of a signing payload (`ecrecover`)
+fn recover_sender(payload: &[u8], parity: u8, r: &Item, s: &Item) -> Result<String, Error> {
+    let mut signature = [0u8; 64];
+    for (half, item) in signature.chunks_mut(32).zip([r, s]) {
+        let bytes = item.as_uint_bytes()?;
+        if bytes.len() > 32 {
+            return Err(Error::InvalidInput(
+                "Signature value is longer than 32 bytes".to_string(),
+            ));
+        }
+        half[32 - bytes.len()..].copy_from_slice(bytes);
+    }
+    let public_key = secp256k1::recover_public_key(&keccak256(payload), &signature, parity)?;
+    eip55::normalize(&hex_string(&keccak256(&public_key[1..])[12..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Transactions signed by key 0x4646...46
+    const SENDER: &str = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+    const RECIPIENT: &str = "0x3535353535353535353535353535353535353535";
+
+    #[test]
+    fn test_decode_legacy_eip155() {
+        let tx = decode_evm_transaction("0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83").unwrap();
+        assert_eq!(tx.tx_type, EvmTransactionType::Legacy);
+        assert_eq!(tx.chain_id, Some(1));
+        assert_eq!(tx.nonce, 9);
+        assert_eq!(tx.gas_price.as_deref(), Some("0x4a817c800"));
+        assert_eq!(tx.gas_limit, 21000);
+        assert_eq!(tx.to.as_deref(), Some(RECIPIENT));
+        assert_eq!(tx.value, "0xde0b6b3a7640000");
+        assert_eq!(tx.data, "0x");
+        assert_eq!(tx.from, SENDER);
+        assert_eq!(
+            tx.hash,
+            "0x33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788"
+        );
+    }
+
+    #[test]
+    fn test_decode_legacy_without_chain_id() {
+        let tx = decode_evm_transaction("f864808504a817c80082520894353535353535353535353535353535353535353501801ba00789dee922a809e21365f515c10b61bdc44f18eb5cf7e4f71d492ac8be157576a041eb57e1c0da39e83579e0d2f1d2c3df7564943491493f9dda28d95073406276").unwrap();
+        assert_eq!(tx.chain_id, None);
+        assert_eq!(tx.value, "0x1");
+        assert_eq!(tx.from, SENDER);
+    }
+
+    #[test]
+    fn test_decode_eip2930() {
+        let tx = decode_evm_transaction("0x01f86a380385012a05f20082c3509435353535353535353535353535353535353535358084a9059cbbc080a02cf6c77aa334e7f5ab15ef9be5af639f9ed725d32bfddcc1e63a3e04fd9ae1c6a06c5f9d6e83643ac10a663f71432c6d3250ba887b9b0da03964bf329556cd95da").unwrap();
+        assert_eq!(tx.tx_type, EvmTransactionType::AccessList);
+        assert_eq!(tx.chain_id, Some(56));
+        assert_eq!(tx.gas_price.as_deref(), Some("0x12a05f200"));
+        assert_eq!(tx.value, "0x0");
+        assert_eq!(tx.data, "0xa9059cbb");
+        assert_eq!(tx.from, SENDER);
+    }
+
+    #[test]
+    fn test_decode_eip1559() {
+        let tx = decode_evm_transaction("0x02f8ad818907843b9aca008506fc23ac00825208943535353535353535353535353535353535353535880de0b6b3a764000080f838f7943535353535353535353535353535353535353535e1a0000000000000000000000000000000000000000000000000000000000000000101a04e6a01e17f5f87fa802238c9183f93a9f2a1ea6a7f644337953c4681cd886e09a0619b8940fea33f2c2e927ea56c7c40794ddf9497534ccff67685e41d33bb0570").unwrap();
+        assert_eq!(tx.tx_type, EvmTransactionType::DynamicFee);
+        assert_eq!(tx.chain_id, Some(137));
+        assert_eq!(tx.nonce, 7);
+        assert_eq!(tx.max_priority_fee_per_gas.as_deref(), Some("0x3b9aca00"));
+        assert_eq!(tx.max_fee_per_gas.as_deref(), Some("0x6fc23ac00"));
+        assert_eq!(tx.gas_price, None);
+        assert_eq!(tx.access_list.len(), 1);
+        assert_eq!(tx.access_list[0].address, RECIPIENT);
+        assert_eq!(
+            tx.access_list[0].storage_keys,
+            vec![format!("0x{}01", "00".repeat(31))]
+        );
+        assert_eq!(tx.from, SENDER);
+    }
+
+    #[test]
+    fn test_decode_eip4844() {
+        let canonical = "0x03f88d0180843b9aca0084773594008252089435353535353535353535353535353535353535358080c003e1a001ababababababababababababababababababababababababababababababab80a09680241112d370b56da22eb535745d9e314380e568229e09f7241066003bc471a077cdd51d23e0a77ed8fd15d3de98d0da48f4877b7b68613f2d67b4fa7a90e82f";
+        let tx = decode_evm_transaction(canonical).unwrap();
+        assert_eq!(tx.tx_type, EvmTransactionType::Blob);
+        assert_eq!(tx.chain_id, Some(1));
+        assert_eq!(tx.max_fee_per_blob_gas.as_deref(), Some("0x3"));
+        assert_eq!(
+            tx.blob_versioned_hashes,
+            vec![format!("0x01{}", "ab".repeat(31))]
+        );
+        assert_eq!(tx.from, SENDER);
+        assert_eq!(
+            tx.hash,
+            "0x18eac8b08de010d3f5e7789dcae6e15714e2c05c9e32376eb550a874ead0f316"
+        );
+
+        // Network form with blobs, commitments and proofs has the same hash
+        let network = format!(
+            "0x03f90137{}f842b840{}f1b0{}f1b0{}",
+            &canonical[4..],
+            "00".repeat(64),
+            "11".repeat(48),
+            "22".repeat(48)
+        );
+        assert_eq!(decode_evm_transaction(&network).unwrap(), tx);
+    }
+
+    #[test]
+    fn test_decode_contract_creation() {
+        let tx = decode_evm_transaction("0x02f8538203e7800101830186a08080826080c080a0e5648161e95dbf2bfc687b72b745269fa906031e2108118050aba59524a23c40a03f473e888d91171543d2198a1924e98989f2ce235fb107a682430cd1c75a39a7").unwrap();
+        assert_eq!(tx.chain_id, Some(999));
+        assert_eq!(tx.to, None);
+        assert_eq!(tx.data, "0x6080");
+        assert_eq!(tx.from, SENDER);
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert!(decode_evm_transaction("0x04c0").is_err());
+        assert!(decode_evm_transaction("0x02c0").is_err());
+        assert!(decode_evm_transaction("").is_err());
+        assert!(decode_evm_transaction("0xzz").is_err());
+        // Legacy v of 0 is neither pre- nor post-EIP-155
+        assert!(decode_evm_transaction("0xc9808080808080808080").is_err());
+    }
+}
@@ -0,0 +1,9 @@
+//! Raw signed transactions
+//!
+//! Decoding of signed EVM transactions (legacy RLP and typed EIP-2718
+//! envelopes) with sender recovery through `ecrecover`.
+
+mod evm;
+mod rlp;
+
+pub use evm::{decode_evm_transaction, AccessListItem, EvmTransaction, EvmTransactionType};
@@ -0,0 +1,235 @@
+//! Recursive Length Prefix (RLP) decoding for Ethereum transactions
+//!
+//! Only canonical encodings are accepted: single bytes below `0x80` must be
+//! encoded as themselves and long-form lengths must be minimal.
+
+use crate::Error;
+
+/// A decoded RLP item, borrowing from the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Item<'a> {
+    /// Full encoding of the item, header included
+    pub raw: &'a [u8],
+    pub kind: ItemKind<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ItemKind<'a> {
+    Bytes(&'a [u8]),
+    List(Vec<Item<'a>>),
+}
+
+/// Nesting limit, so hostile input cannot exhaust the stack
+///
+/// The deepest transaction shape, an EIP-4844 network form with access list
+/// storage keys, nests about 4 lists.
+const MAX_DEPTH: usize = 8;
+
+fn invalid(message: &str) -> Error {
+    Error::InvalidInput(format!("Invalid RLP: {}", message))
+}
+
+/// Decode a single RLP item spanning the whole input
+pub(crate) fn decode(input: &[u8]) -> Result<Item<'_>, Error> {
+    let (item, rest) = decode_item(input, 0)?;
+    if !rest.is_empty() {
+        return Err(invalid("trailing bytes after item"));
+    }
+    Ok(item)
+}
+
+/// Decode the item at the start of `input`, returning it and the remaining bytes
+fn decode_item(input: &[u8], depth: usize) -> Result<(Item<'_>, &[u8]), Error> {
+    if depth > MAX_DEPTH {
+        return Err(invalid("lists nested too deep"));
+    }
+    let &prefix = input
+        .first()
+        .ok_or_else(|| invalid("unexpected end of input"))?;
+    let (is_list, header_len, payload_len) = match prefix {
+        0x00..=0x7f => (false, 0, 1),
+        0x80..=0xb7 => (false, 1, (prefix - 0x80) as usize),
+        0xb8..=0xbf => long_length(input, (prefix - 0xb7) as usize, false)?,
+        0xc0..=0xf7 => (true, 1, (prefix - 0xc0) as usize),
+        0xf8..=0xff => long_length(input, (prefix - 0xf7) as usize, true)?,
+    };
+    let end = header_len
+        .checked_add(payload_len)
+        .filter(|&end| end <= input.len())
+        .ok_or_else(|| invalid("item is longer than the input"))?;
+    let (raw, rest) = input.split_at(end);
+    let payload = &raw[header_len..];
+
+    let kind = if is_list {
+        let mut items = Vec::new();
+        let mut remaining = payload;
+        while !remaining.is_empty() {
+            let (item, rest) = decode_item(remaining, depth + 1)?;
+            items.push(item);
+            remaining = rest;
+        }
+        ItemKind::List(items)
+    } else {
+        if prefix == 0x81 && payload[0] < 0x80 {
+            return Err(invalid("single byte below 0x80 must not have a header"));
+        }
+        ItemKind::Bytes(payload)
+    };
+    Ok((Item { raw, kind }, rest))
+}
+
+/// Read a long-form length of `length_of_length` bytes following the prefix
+fn long_length(
+    input: &[u8],
+    length_of_length: usize,
+    is_list: bool,
+) -> Result<(bool, usize, usize), Error> {
+    let bytes = input
+        .get(1..1 + length_of_length)
+        .ok_or_else(|| invalid("unexpected end of input"))?;
+    if bytes[0] == 0 || length_of_length > 8 {
+        return Err(invalid("non-canonical length"));
+    }
+    let length = bytes.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64);
+    if length < 56 {
+        return Err(invalid("long form used for a short item"));
+    }
+    let length = usize::try_from(length).map_err(|_| invalid("length overflow"))?;
+    Ok((is_list, 1 + length_of_length, length))
+}
+
+impl<'a> Item<'a> {
+    pub fn as_bytes(&self) -> Result<&'a [u8], Error> {
+        match self.kind {
+            ItemKind::Bytes(bytes) => Ok(bytes),
+            ItemKind::List(_) => Err(invalid("expected a byte string, got a list")),
+        }
+    }
+
+    pub fn as_list(&self) -> Result<&[Item<'a>], Error> {
+        match &self.kind {
+            ItemKind::List(items) => Ok(items),
+            ItemKind::Bytes(_) => Err(invalid("expected a list, got a byte string")),
+        }
+    }
+
+    /// Big-endian unsigned integer without leading zeros
+    pub fn as_uint_bytes(&self) -> Result<&'a [u8], Error> {
+        let bytes = self.as_bytes()?;
+        if bytes.first() == Some(&0) {
+            return Err(invalid("integer has leading zeros"));
+        }
+        Ok(bytes)
+    }
+
+    pub fn as_u64(&self) -> Result<u64, Error> {
+        let bytes = self.as_uint_bytes()?;
+        if bytes.len() > 8 {
+            return Err(invalid("integer does not fit in 64 bits"));
+        }
+        Ok(bytes.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64))
+    }
+
+    /// Integer as a JSON-RPC quantity (`0x`-prefixed hex without leading zeros)
+    pub fn as_quantity(&self) -> Result<String, Error> {
+        let hex = ::hex::encode(self.as_uint_bytes()?);
+        let digits = hex.trim_start_matches('0');
+        Ok(format!(
+            "0x{}",
+            if digits.is_empty() { "0" } else { digits }
+        ))
+    }
+}
+
+/// Encode a byte string
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    if bytes.len() == 1 && bytes[0] < 0x80 {
+        return bytes.to_vec();
+    }
+    let mut encoded = header(bytes.len(), 0x80);
+    encoded.extend_from_slice(bytes);
+    encoded
+}
+
+/// Encode an unsigned integer
+pub(crate) fn encode_u64(value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    encode_bytes(&bytes[start..])
+}
+
+/// Encode a list from the encodings of its items
+pub(crate) fn encode_list(items: &[&[u8]]) -> Vec<u8> {
+    let payload = items.concat();
+    let mut encoded = header(payload.len(), 0xc0);
+    encoded.extend(payload);
+    encoded
+}
+
+fn header(length: usize, offset: u8) -> Vec<u8> {
+    if length < 56 {
+        return vec![offset + length as u8];
+    }
+    let bytes = (length as u64).to_be_bytes();
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    let mut header = vec![offset + 55 + (bytes.len() - start) as u8];
+    header.extend_from_slice(&bytes[start..]);
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        // ["cat", "dog"]
+        let encoded = [0xc8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g'];
+        let item = decode(&encoded).unwrap();
+        let list = item.as_list().unwrap();
+        assert_eq!(list[0].as_bytes().unwrap(), b"cat");
+        assert_eq!(list[1].raw, &encoded[5..]);
+
+        assert_eq!(decode(&[0x80]).unwrap().as_quantity().unwrap(), "0x0");
+        assert_eq!(decode(&[0x82, 0x04, 0x00]).unwrap().as_u64().unwrap(), 1024);
+        assert_eq!(decode(&[0x0f]).unwrap().as_quantity().unwrap(), "0xf");
+
+        // Long string
+        let mut long = vec![0xb8, 56];
+        long.extend([b'a'; 56]);
+        assert_eq!(decode(&long).unwrap().as_bytes().unwrap().len(), 56);
+    }
+
+    #[test]
+    fn test_decode_rejects_non_canonical() {
+        assert!(decode(&[0x81, 0x05]).is_err());
+        assert!(decode(&[0xb8, 0x05, 1, 2, 3, 4, 5]).is_err());
+        assert!(decode(&[0x82, 0x00, 0x01]).unwrap().as_u64().is_err());
+        assert!(decode(&[0x83, b'c', b'a']).is_err());
+        assert!(decode(&[0x80, 0x80]).is_err());
+        assert!(decode(&[]).is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_deep_nesting() {
+        let nested =
+            |depth: usize| (0..depth).fold(encode_u64(1), |inner, _| encode_list(&[&inner]));
+        assert!(decode(&nested(MAX_DEPTH)).is_ok());
+        assert!(decode(&nested(MAX_DEPTH + 1)).is_err());
+        // Deep enough to overflow the stack without the limit
+        assert!(decode(&nested(50_000)).is_err());
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode_u64(0), vec![0x80]);
+        assert_eq!(encode_u64(1), vec![0x01]);
+        assert_eq!(encode_u64(1024), vec![0x82, 0x04, 0x00]);
+        assert_eq!(
+            encode_list(&[&[0x83, b'c', b'a', b't'], &[0x80]]),
+            vec![0xc5, 0x83, b'c', b'a', b't', 0x80]
+        );
+        let long = encode_bytes(&[0xaa; 60]);
+        assert_eq!(&long[..2], &[0xb8, 60]);
+    }
+}